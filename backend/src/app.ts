@@ -320,7 +320,7 @@ export function createApp(options?: {
     if (!parsed.success) {
       return res.status(400).json({ error: "Validation failed", details: parsed.error.flatten() });
     }
-    const { mint, from, to, amount } = parsed.data;
+    const { mint, from, to, amount, caseReference } = parsed.data;
     try {
       const kp = getKeypair();
       const stable = await loadStable(mint);
@@ -337,7 +337,11 @@ export function createApp(options?: {
         false,
         TOKEN_2022_PROGRAM_ID
       );
-      const sig = await stable.compliance.seize(kp.publicKey, sourceAta, destAta);
+      const seizeAmount = BigInt(amount);
+      const sig = await stable.compliance.seize(kp.publicKey, sourceAta, destAta, {
+        amount: seizeAmount > 0n ? seizeAmount : undefined,
+        caseReference,
+      });
       addAuditEntry({
         type: "seize",
         signature: sig,
@@ -370,6 +374,7 @@ export function createApp(options?: {
         isFreezer: r.freezer ?? false,
         isBlacklister: r.blacklister ?? false,
         isSeizer: r.seizer ?? false,
+        isAllowlister: r.allowlister ?? false,
       };
       const sig = await stable.updateRoles(kp.publicKey, {
         holder: new PublicKey(holder),
@@ -27,6 +27,7 @@ export type AuditAction =
   | "authority_transfer"
   | "minter_update"
   | "supply_cap_update"
+  | "large_transfer"
   | "init";
 
 export interface AuditEntry {
@@ -39,6 +40,8 @@ export interface AuditEntry {
   targetAddress?: string;
   amount?: string;
   reason?: string;
+  reasonCode?: string;
+  expiresAt?: string;
   actor?: string;
   logs?: string[];
   err?: unknown;
@@ -168,10 +171,12 @@ export function registerComplianceRoutes(
         program as never,
         new PublicKey(mintAddr)
       );
+      const { reasonCode, expiresAt } = parsed.data;
       const sig = await stable.compliance.blacklistAdd(
         kp.publicKey,
         new PublicKey(address),
-        reason
+        reason,
+        { reasonCode, expiresAt: expiresAt === undefined ? undefined : BigInt(expiresAt) }
       );
       addToBlacklistStore(mintAddr, address, reason);
       addAuditEntry({
@@ -180,6 +185,8 @@ export function registerComplianceRoutes(
         mint: mintAddr,
         address,
         reason,
+        reasonCode: reasonCode ?? "internal",
+        expiresAt: expiresAt?.toString(),
         actor: kp.publicKey.toBase58(),
       });
       res.json({ success: true, signature: sig });
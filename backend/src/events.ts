@@ -3,6 +3,7 @@
 import type { Idl } from "@coral-xyz/anchor";
 import { BorshCoder, EventParser } from "@coral-xyz/anchor";
 import { Connection, PublicKey } from "@solana/web3.js";
+import { parseLargeTransferEvents } from "@stbr/sss-token";
 import * as fs from "fs";
 import * as path from "path";
 import { addAuditEntry } from "./compliance";
@@ -130,6 +131,8 @@ function mapEventToAudit(
           mint: undefined,
           address: toBase58(data.address as PublicKey),
           reason: (data.reason as string) ?? "",
+          reasonCode: data.reason_code ? Object.keys(data.reason_code as object)[0] : undefined,
+          expiresAt: data.expires_at && String(data.expires_at) !== "0" ? String(data.expires_at) : undefined,
           actor: toBase58(data.blacklisted_by as PublicKey),
         },
       };
@@ -270,6 +273,33 @@ export function processLogs(signature: string, logs: string[], err: unknown): vo
       postWebhookWithRetry(payload).catch(() => {});
     }
   }
+  // Emitted by the transfer hook inside the Token-2022 CPI; hooked transfers reference the
+  // sss-token program as an extra account, so they reach this subscription.
+  for (const event of parseLargeTransferEvents(logs)) {
+    addAuditEntry({
+      signature,
+      programId: PROGRAM_ID.toBase58(),
+      type: "large_transfer",
+      mint: event.mint.toBase58(),
+      address: event.sourceOwner.toBase58(),
+      targetAddress: event.destinationOwner.toBase58(),
+      amount: event.amount.toString(),
+    });
+    const payload = {
+      type: "large_transfer",
+      signature,
+      programId: PROGRAM_ID.toBase58(),
+      eventName: "LargeTransferObserved",
+      data: {
+        mint: event.mint.toBase58(),
+        sourceOwner: event.sourceOwner.toBase58(),
+        destinationOwner: event.destinationOwner.toBase58(),
+        amount: event.amount.toString(),
+        slot: event.slot.toString(),
+      },
+    };
+    postWebhookWithRetry(payload).catch(() => {});
+  }
 }
 
 export function subscribeToProgramLogs(connection: Connection): void {
@@ -32,7 +32,9 @@ export const seizeBodySchema = z.object({
   mint: z.string(),
   from: z.string(),
   to: z.string(),
+  /** 0 seizes the full balance */
   amount: amountSchema,
+  caseReference: z.string().min(1).optional(),
 });
 
 export const rolesBodySchema = z.object({
@@ -45,6 +47,7 @@ export const rolesBodySchema = z.object({
     freezer: z.boolean().optional(),
     blacklister: z.boolean().optional(),
     seizer: z.boolean().optional(),
+    allowlister: z.boolean().optional(),
   }),
 });
 
@@ -64,6 +67,9 @@ export const blacklistPostBodySchema = z.object({
   mint: z.string().optional(),
   address: z.string(),
   reason: z.string().optional(),
+  reasonCode: z.enum(["ofac", "courtOrder", "fraud", "internal"]).optional(),
+  /** Unix seconds; permanent when omitted. */
+  expiresAt: z.number().int().positive().optional(),
 });
 
 export const blacklistDeleteParamsSchema = z.object({
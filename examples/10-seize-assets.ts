@@ -93,8 +93,13 @@ async function main() {
     [authority]
   );
   await stable.thawAccount(pauser.publicKey, treasuryAta);
+  await stable.updateTreasury(authority.publicKey, treasuryAta);
 
-  await stable.compliance.seize(seizer.publicKey, sourceAta, treasuryAta);
+  // Seizure requires a blacklisted or frozen source account.
+  await stable.freezeAccount(pauser.publicKey, sourceAta);
+  await stable.compliance.seize(seizer.publicKey, sourceAta, treasuryAta, {
+    caseReference: "DEMO-COURT-ORDER-001",
+  });
   console.log("Seized to treasury");
 
   const sourceAcc = await getAccount(connection, sourceAta, "confirmed", TOKEN_2022_PROGRAM_ID);
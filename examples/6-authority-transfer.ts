@@ -32,7 +32,7 @@ async function main() {
   await stable.updateMinter(oldAuthority.publicKey, { minter: minter.publicKey, quota: BigInt(100_000_000) });
   console.log("Old authority assigned minter role");
 
-  await stable.transferAuthority(oldAuthority.publicKey, newAuthority.publicKey);
+  await stable.transferAuthority(oldAuthority.publicKey, newAuthority);
   console.log("Authority transferred to:", newAuthority.publicKey.toBase58());
 
   try {
@@ -35,7 +35,7 @@ async function main() {
   await stable.updateMinter(authority.publicKey, { minter: minter.publicKey, quota: BigInt(100_000_000) });
 
   const badActor = Keypair.generate().publicKey;
-  await stable.compliance.blacklistAdd(blacklister.publicKey, badActor, "OFAC match");
+  await stable.compliance.blacklistAdd(blacklister.publicKey, badActor, "OFAC match", { reasonCode: "ofac" });
   console.log("Added to blacklist:", badActor.toBase58().slice(0, 8) + "...");
 
   await stable.compliance.blacklistRemove(blacklister.publicKey, badActor);
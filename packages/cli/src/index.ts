@@ -19,6 +19,8 @@ import {
   validateMintAmount,
   validateBurnAmount,
   getErrorMessage,
+  type BlacklistEntryParams,
+  type BlacklistReason,
   type CreateStablecoinParams,
 } from "@stbr/sss-token";
 
@@ -40,10 +42,34 @@ interface InitOpts {
 
 interface ReasonOpts {
   reason?: string;
+  code?: string;
+  expiresAt?: string;
+}
+
+const BLACKLIST_REASON_CODES: Record<string, BlacklistReason> = {
+  ofac: "ofac",
+  "court-order": "courtOrder",
+  fraud: "fraud",
+  internal: "internal",
+};
+
+function blacklistEntryParams(opts: ReasonOpts): BlacklistEntryParams {
+  const code = opts.code ?? "internal";
+  const reasonCode = BLACKLIST_REASON_CODES[code];
+  if (!reasonCode) {
+    console.error(`Invalid --code ${code}; expected one of ${Object.keys(BLACKLIST_REASON_CODES).join(", ")}`);
+    process.exit(1);
+  }
+  return {
+    reasonCode,
+    expiresAt: opts.expiresAt === undefined ? undefined : BigInt(opts.expiresAt),
+  };
 }
 
 interface SeizeOpts {
   to: string;
+  caseRef: string;
+  amount?: string;
 }
 
 function getConnection(rpcUrl?: string): Connection {
@@ -90,6 +116,16 @@ function logTx(sig: string, label: string, rpcUrl?: string): void {
   if (url) console.log("Explorer:", url);
 }
 
+/** Wallet addresses from a file, one per line; blank lines and `#` comments are ignored. */
+function readAddressFile(path: string): PublicKey[] {
+  return fs
+    .readFileSync(path, "utf-8")
+    .split(/\r?\n/)
+    .map((line) => line.replace(/#.*/, "").trim())
+    .filter((line) => line.length > 0)
+    .map((line) => new PublicKey(line));
+}
+
 const program = new Command();
 program
   .name("sss-token")
@@ -224,6 +260,27 @@ program
     logTx(sig, "Freeze tx", globalOpts.rpcUrl);
   });
 
+program
+  .command("freeze-batch <file>")
+  .description("Freeze the token accounts of every owner listed in <file> (one address per line)")
+  .action(async (...args: unknown[]) => {
+    const [file] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const targetAtas = readAddressFile(file).map((owner) => stable.getRecipientTokenAccount(owner));
+    const sigs = await stable.freezeAccounts(keypair.publicKey, targetAtas);
+    sigs.forEach((sig) => logTx(sig, "Freeze batch tx", globalOpts.rpcUrl));
+  });
+
 program
   .command("thaw <address>")
   .description("Thaw token account")
@@ -308,11 +365,15 @@ program
           symbol: state.symbol,
           decimals: state.decimals,
           paused: state.paused,
+          version: state.version,
           totalMinted: state.total_minted.toString(),
           totalBurned: state.total_burned.toString(),
           supply: supply.toString(),
           authority: state.authority?.toBase58?.() ?? null,
           preset: stable.isSSS2() ? "SSS-2" : "SSS-1",
+          successorMint: state.successor_mint.equals(PublicKey.default) ? null : state.successor_mint.toBase58(),
+          legacyMint: state.legacy_mint.equals(PublicKey.default) ? null : state.legacy_mint.toBase58(),
+          legacyOutstanding: state.legacy_outstanding.toString(),
         })
       );
       return;
@@ -323,8 +384,123 @@ program
     console.log("Decimals:", state.decimals);
     console.log("Paused:", state.paused);
     console.log("SSS-2:", stable.isSSS2());
+    console.log("State version:", state.version);
     console.log("Total minted:", state.total_minted.toString());
     console.log("Total burned:", state.total_burned.toString());
+    if (!state.successor_mint.equals(PublicKey.default)) {
+      console.log("Successor mint:", state.successor_mint.toBase58());
+    }
+    if (!state.legacy_mint.equals(PublicKey.default)) {
+      console.log("Legacy mint:", state.legacy_mint.toBase58());
+      console.log("Legacy outstanding:", state.legacy_outstanding.toString());
+    }
+  });
+
+program
+  .command("migrate-state")
+  .description("Rewrite a stablecoin account created before the versioned header (run before upgrading the hook)")
+  .action(async () => {
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const sig = await SolanaStablecoin.migrateState(prog as never, mint);
+    logTx(sig, "Migrate state tx", globalOpts.rpcUrl);
+  });
+
+program
+  .command("migrate-account <address>")
+  .description("Upgrade a stablecoin, role, minter, blacklist or supply cap account written in an older layout")
+  .action(async (...args: unknown[]) => {
+    const [address] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const prog = loadProgram(connection, keypair);
+    const sig = await SolanaStablecoin.migrateAccount(prog as never, new PublicKey(address));
+    logTx(sig, "Migrate account tx", globalOpts.rpcUrl);
+  });
+
+program
+  .command("migrate-hook-accounts")
+  .description("Rewrite an SSS-2 mint's hook account list to the current layout (authority only)")
+  .action(async () => {
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    try {
+      const prog = loadProgram(connection, keypair);
+      const stable = await SolanaStablecoin.load(prog as never, new PublicKey(mintAddr));
+      const sig = await stable.migrateHookAccounts(keypair.publicKey);
+      logTx(sig, "Migrate hook accounts tx", globalOpts.rpcUrl);
+    } catch (e) {
+      console.error(getErrorMessage(e));
+      process.exit(1);
+    }
+  });
+
+program
+  .command("link-successor <successor-mint>")
+  .description("Link this SSS-1 mint (--mint) to a new SSS-2 mint; stops legacy minting for good")
+  .action(async (...args: unknown[]) => {
+    const [successorMint] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    try {
+      const prog = loadProgram(connection, keypair);
+      const stable = await SolanaStablecoin.load(prog as never, new PublicKey(mintAddr));
+      const sig = await stable.linkSuccessor(keypair.publicKey, new PublicKey(successorMint));
+      logTx(sig, "Link successor tx", globalOpts.rpcUrl);
+    } catch (e) {
+      console.error(getErrorMessage(e));
+      process.exit(1);
+    }
+  });
+
+program
+  .command("swap-legacy <amount>")
+  .description("Swap the signer's legacy tokens (--mint) 1:1 for the linked SSS-2 token")
+  .action(async (...args: unknown[]) => {
+    const [amount] = args as [string];
+    const err = validateBurnAmount(amount ?? "0");
+    if (err) {
+      console.error(err);
+      process.exit(1);
+    }
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    try {
+      const prog = loadProgram(connection, keypair);
+      const stable = await SolanaStablecoin.load(prog as never, new PublicKey(mintAddr));
+      const sig = await stable.swapLegacy(keypair, BigInt(amount));
+      logTx(sig, "Swap legacy tx", globalOpts.rpcUrl);
+    } catch (e) {
+      console.error(getErrorMessage(e));
+      process.exit(1);
+    }
   });
 
 program
@@ -413,6 +589,8 @@ const blacklist = new Command("blacklist").description("Blacklist management (SS
 blacklist
   .command("add <address>")
   .option("-r, --reason <reason>", "Reason", "CLI")
+  .option("--code <code>", "Reason code: ofac, court-order, fraud, internal", "internal")
+  .option("--expires-at <unix-seconds>", "Expiry timestamp (default: permanent)")
   .action(async function (this: Command, ...args: unknown[]) {
     const [address] = args as [string];
     const opts = this.opts() as ReasonOpts;
@@ -430,7 +608,8 @@ blacklist
     const sig = await stable.compliance.blacklistAdd(
       keypair.publicKey,
       new PublicKey(address),
-      opts.reason ?? "CLI"
+      opts.reason ?? "CLI",
+      blacklistEntryParams(opts)
     );
     logTx(sig, "Blacklist add tx", globalOpts.rpcUrl);
   });
@@ -455,12 +634,252 @@ blacklist
     );
     logTx(sig, "Blacklist remove tx", globalOpts.rpcUrl);
   });
+blacklist
+  .command("add-batch <file>")
+  .description("Blacklist every address listed in <file> (one per line); active entries are skipped")
+  .option("-r, --reason <reason>", "Reason", "CLI")
+  .option("--code <code>", "Reason code: ofac, court-order, fraud, internal", "internal")
+  .option("--expires-at <unix-seconds>", "Expiry timestamp (default: permanent)")
+  .action(async function (this: Command, ...args: unknown[]) {
+    const [file] = args as [string];
+    const opts = this.opts() as ReasonOpts;
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sigs = await stable.compliance.blacklistAddBatch(
+      keypair.publicKey,
+      readAddressFile(file),
+      opts.reason ?? "CLI",
+      blacklistEntryParams(opts)
+    );
+    sigs.forEach((sig) => logTx(sig, "Blacklist batch add tx", globalOpts.rpcUrl));
+  });
+blacklist
+  .command("remove-batch <file>")
+  .description("Remove every address listed in <file> (one per line) from the blacklist")
+  .action(async (...args: unknown[]) => {
+    const [file] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sigs = await stable.compliance.blacklistRemoveBatch(keypair.publicKey, readAddressFile(file));
+    sigs.forEach((sig) => logTx(sig, "Blacklist batch remove tx", globalOpts.rpcUrl));
+  });
+blacklist
+  .command("update <address>")
+  .description("Rewrite reason, reason code and expiry of an existing entry")
+  .option("-r, --reason <reason>", "Reason", "CLI")
+  .option("--code <code>", "Reason code: ofac, court-order, fraud, internal", "internal")
+  .option("--expires-at <unix-seconds>", "Expiry timestamp (default: permanent)")
+  .action(async function (this: Command, ...args: unknown[]) {
+    const [address] = args as [string];
+    const opts = this.opts() as ReasonOpts;
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.updateBlacklistEntry(
+      keypair.publicKey,
+      new PublicKey(address),
+      opts.reason ?? "CLI",
+      blacklistEntryParams(opts)
+    );
+    logTx(sig, "Blacklist update tx", globalOpts.rpcUrl);
+  });
 program.addCommand(blacklist);
 
+const allowlist = new Command("allowlist").description("Allowlist management (SSS-2 allowlist mode)");
+allowlist
+  .command("add <address>")
+  .action(async (...args: unknown[]) => {
+    const [address] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.allowlistAdd(keypair.publicKey, new PublicKey(address));
+    logTx(sig, "Allowlist add tx", globalOpts.rpcUrl);
+  });
+allowlist
+  .command("remove <address>")
+  .action(async (...args: unknown[]) => {
+    const [address] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.allowlistRemove(keypair.publicKey, new PublicKey(address));
+    logTx(sig, "Allowlist remove tx", globalOpts.rpcUrl);
+  });
+allowlist
+  .command("approve <token-account>")
+  .description("Allowlist the token account's owner and thaw the account")
+  .action(async (...args: unknown[]) => {
+    const [tokenAccount] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.approveHolder(keypair.publicKey, new PublicKey(tokenAccount));
+    logTx(sig, "Approve holder tx", globalOpts.rpcUrl);
+  });
+program.addCommand(allowlist);
+
+const velocity = new Command("velocity").description("Transfer velocity limits (SSS-2)");
+velocity
+  .command("set")
+  .description("Set per-transfer and rolling 24h limits in base units (0 disables a limit)")
+  .option("--max-per-transfer <amount>", "Largest single transfer", "0")
+  .option("--max-per-window <amount>", "Total a wallet may send in 24 hours", "0")
+  .action(async function (this: Command) {
+    const cmdOpts = this.opts() as { maxPerTransfer?: string; maxPerWindow?: string };
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.setVelocityLimits(
+      keypair.publicKey,
+      BigInt(cmdOpts.maxPerTransfer ?? "0"),
+      BigInt(cmdOpts.maxPerWindow ?? "0")
+    );
+    logTx(sig, "Velocity limits tx", globalOpts.rpcUrl);
+  });
+velocity
+  .command("exempt <address>")
+  .description("Exempt a wallet's outgoing transfers from velocity limits")
+  .action(async (...args: unknown[]) => {
+    const [address] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.velocityExemptionAdd(keypair.publicKey, new PublicKey(address));
+    logTx(sig, "Velocity exemption tx", globalOpts.rpcUrl);
+  });
+velocity
+  .command("unexempt <address>")
+  .description("Remove a wallet's velocity exemption")
+  .action(async (...args: unknown[]) => {
+    const [address] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.velocityExemptionRemove(keypair.publicKey, new PublicKey(address));
+    logTx(sig, "Velocity exemption removal tx", globalOpts.rpcUrl);
+  });
+velocity
+  .command("init-usage <owner>")
+  .description("Create a wallet's velocity usage account (its transfers count towards the window from then on)")
+  .action(async (...args: unknown[]) => {
+    const [owner] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.initializeVelocityUsage(keypair.publicKey, new PublicKey(owner));
+    logTx(sig, "Velocity usage tx", globalOpts.rpcUrl);
+  });
+program.addCommand(velocity);
+
+program
+  .command("reporting-threshold <amount>")
+  .description("Emit LargeTransferObserved from the transfer hook above this amount (base units, 0 disables)")
+  .action(async (...args: unknown[]) => {
+    const [amount] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.compliance.setReportingThreshold(keypair.publicKey, BigInt(amount));
+    logTx(sig, "Reporting threshold tx", globalOpts.rpcUrl);
+  });
+
 program
   .command("blacklist-add <address>")
   .description("(Alias) Add address to blacklist (SSS-2)")
   .option("-r, --reason <reason>", "Reason", "CLI")
+  .option("--code <code>", "Reason code: ofac, court-order, fraud, internal", "internal")
+  .option("--expires-at <unix-seconds>", "Expiry timestamp (default: permanent)")
   .action(async function (this: Command, ...args: unknown[]) {
     const [address] = args as [string];
     const opts = this.opts() as ReasonOpts;
@@ -478,7 +897,8 @@ program
     const sig = await stable.compliance.blacklistAdd(
       keypair.publicKey,
       new PublicKey(address),
-      opts.reason ?? "CLI"
+      opts.reason ?? "CLI",
+      blacklistEntryParams(opts)
     );
     logTx(sig, "Blacklist add tx", globalOpts.rpcUrl);
   });
@@ -506,10 +926,79 @@ program
     logTx(sig, "Blacklist remove tx", globalOpts.rpcUrl);
   });
 
+program
+  .command("set-treasury <token-account>")
+  .description("Register the treasury token account seizures must go to (authority only)")
+  .action(async (...args: unknown[]) => {
+    const [tokenAccount] = args as [string];
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    const mint = new PublicKey(mintAddr);
+    const prog = loadProgram(connection, keypair);
+    const stable = await SolanaStablecoin.load(prog as never, mint);
+    const sig = await stable.updateTreasury(keypair.publicKey, new PublicKey(tokenAccount));
+    logTx(sig, "Treasury tx", globalOpts.rpcUrl);
+  });
+
+program
+  .command("update-metadata")
+  .description("Update name, symbol, uri and extra fields on the mint and stablecoin state (authority only)")
+  .option("-n, --name <name>", "Token name")
+  .option("-s, --symbol <symbol>", "Token symbol")
+  .option("--uri <uri>", "Metadata URI")
+  .option(
+    "-f, --field <key=value>",
+    "Additional field, e.g. issuer=\"Acme\" (repeatable; empty value removes the key)",
+    (value: string, previous: string[]) => [...previous, value],
+    [] as string[]
+  )
+  .action(async function (this: Command) {
+    const opts = this.opts() as { name?: string; symbol?: string; uri?: string; field: string[] };
+    const fields: Record<string, string> = {};
+    for (const entry of opts.field) {
+      const eq = entry.indexOf("=");
+      if (eq <= 0) {
+        console.error(`Invalid --field "${entry}": expected key=value`);
+        process.exit(1);
+      }
+      fields[entry.slice(0, eq)] = entry.slice(eq + 1);
+    }
+    const globalOpts = getGlobalOpts();
+    const connection = getConnection(globalOpts.rpcUrl);
+    const keypair = getKeypair(globalOpts.keypair);
+    const mintAddr = globalOpts.mint;
+    if (!mintAddr) {
+      console.error("--mint required");
+      process.exit(1);
+    }
+    try {
+      const prog = loadProgram(connection, keypair);
+      const stable = await SolanaStablecoin.load(prog as never, new PublicKey(mintAddr));
+      const sig = await stable.updateMetadata(keypair.publicKey, {
+        name: opts.name,
+        symbol: opts.symbol,
+        uri: opts.uri,
+        fields,
+      });
+      logTx(sig, "Update metadata tx", globalOpts.rpcUrl);
+    } catch (e) {
+      console.error(getErrorMessage(e));
+      process.exit(1);
+    }
+  });
+
 program
   .command("seize <source-account>")
-  .description("Seize tokens to treasury (SSS-2)")
+  .description("Seize tokens from a blacklisted or frozen account to treasury (SSS-2)")
   .requiredOption("-t, --to <treasury-account>", "Destination token account (treasury ATA)")
+  .requiredOption("--case-ref <reference>", "Case reference (e.g. court order number) recorded on-chain")
+  .option("--amount <amount>", "Partial amount in base units (default: full balance)")
   .action(async function (this: Command, ...args: unknown[]) {
     const [sourceAccount] = args as [string];
     const opts = this.opts() as unknown as SeizeOpts;
@@ -527,14 +1016,18 @@ program
     const sig = await stable.compliance.seize(
       keypair.publicKey,
       new PublicKey(sourceAccount),
-      new PublicKey(opts.to)
+      new PublicKey(opts.to),
+      {
+        caseReference: opts.caseRef,
+        amount: opts.amount !== undefined ? BigInt(opts.amount) : undefined,
+      }
     );
     logTx(sig, "Seize tx", globalOpts.rpcUrl);
   });
 
 // ── Management: minters, holders, audit-log ─────────────────────────────────
 
-const MINTER_INFO_SIZE = 8 + 32 + 32 + 8 + 8 + 1; // discriminator + stablecoin + minter + quota + minted_amount + bump
+const MINTER_INFO_SIZE = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1; // discriminator + stablecoin + minter + quota + minted_amount + window_seconds + window_limit + window_start + window_minted + epoch + bump
 
 const mintersCmd = new Command("minters").description("Minter management");
 mintersCmd
@@ -641,9 +1134,10 @@ rolesCmd
   .option("--freezer", "Grant freezer role (freeze/thaw accounts)")
   .option("--blacklister", "Grant blacklister role (SSS-2)")
   .option("--seizer", "Grant seizer role (SSS-2)")
+  .option("--allowlister", "Grant allowlister role (SSS-2 allowlist mode)")
   .action(async function (this: Command, ...args: unknown[]) {
     const [address] = args as [string];
-    const opts = this.opts() as { minter?: boolean; burner?: boolean; pauser?: boolean; freezer?: boolean; blacklister?: boolean; seizer?: boolean };
+    const opts = this.opts() as { minter?: boolean; burner?: boolean; pauser?: boolean; freezer?: boolean; blacklister?: boolean; seizer?: boolean; allowlister?: boolean };
     const globalOpts = getGlobalOpts();
     const connection = getConnection(globalOpts.rpcUrl);
     const keypair = getKeypair(globalOpts.keypair);
@@ -663,6 +1157,7 @@ rolesCmd
       isFreezer: !!opts.freezer,
       isBlacklister: !!opts.blacklister,
       isSeizer: !!opts.seizer,
+      isAllowlister: !!opts.allowlister,
     };
     const sig = await stable.updateRoles(keypair.publicKey, { holder, roles });
     logTx(sig, "Roles grant tx", globalOpts.rpcUrl);
@@ -214,7 +214,7 @@ export async function seize(body: {
 export async function rolesGrant(
   mint: string,
   holder: string,
-  roles: { minter?: boolean; burner?: boolean; pauser?: boolean; freezer?: boolean; blacklister?: boolean; seizer?: boolean }
+  roles: { minter?: boolean; burner?: boolean; pauser?: boolean; freezer?: boolean; blacklister?: boolean; seizer?: boolean; allowlister?: boolean }
 ): Promise<SignatureResponse> {
   return request<SignatureResponse>("/operations/roles", {
     method: "POST",
@@ -228,6 +228,7 @@ export async function rolesGrant(
         freezer: roles.freezer ?? false,
         blacklister: roles.blacklister ?? false,
         seizer: roles.seizer ?? false,
+        allowlister: roles.allowlister ?? false,
       },
     },
   });
@@ -147,6 +147,7 @@ export async function rolesGrant(
     freezer?: boolean;
     blacklister?: boolean;
     seizer?: boolean;
+    allowlister?: boolean;
   }
 ): Promise<{ signature: string }> {
   const stable = await loadStable(mint);
@@ -160,6 +161,7 @@ export async function rolesGrant(
       isFreezer: roles.freezer ?? false,
       isBlacklister: roles.blacklister ?? false,
       isSeizer: roles.seizer ?? false,
+      isAllowlister: roles.allowlister ?? false,
     },
   });
   return { signature: sig };
@@ -207,6 +209,9 @@ export async function seize(
     false,
     TOKEN_2022_PROGRAM_ID
   );
-  const sig = await stable.compliance.seize(kp.publicKey, sourceAta, destAta);
+  const seizeAmount = BigInt(String(amount));
+  const sig = await stable.compliance.seize(kp.publicKey, sourceAta, destAta, {
+    amount: seizeAmount > 0n ? seizeAmount : undefined,
+  });
   return { signature: sig };
 }
@@ -11,8 +11,24 @@ pub const ROLE_SEED: &[u8] = b"role";
 pub const MINTER_SEED: &[u8] = b"minter";
 pub const BLACKLIST_SEED: &[u8] = b"blacklist";
 pub const SUPPLY_CAP_SEED: &[u8] = b"supply_cap";
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const QUEUED_CHANGE_SEED: &[u8] = b"queued_change";
+pub const REDEMPTION_SEED: &[u8] = b"redemption";
+pub const REDEMPTION_ESCROW_SEED: &[u8] = b"redemption_escrow";
+pub const SEIZURE_SEED: &[u8] = b"seizure";
+pub const ALLOWLIST_SEED: &[u8] = b"allowlist";
+pub const VELOCITY_CONFIG_SEED: &[u8] = b"velocity_config";
+pub const VELOCITY_EXEMPTION_SEED: &[u8] = b"velocity_exempt";
+pub const REPORTING_CONFIG_SEED: &[u8] = b"reporting_config";
 /// SSS-2 ExtraAccountMetaList PDA seed (matches sss_transfer_hook program)
 pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+/// Anchor discriminator of sss_transfer_hook::initialize_extra_account_meta_list
+/// (sha256("global:initialize_extra_account_meta_list")[..8])
+pub const HOOK_INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR: [u8; 8] = [92, 197, 174, 197, 41, 124, 19, 3];
+/// Anchor discriminator of sss_transfer_hook::update_extra_account_meta_list
+/// (sha256("global:update_extra_account_meta_list")[..8])
+pub const HOOK_UPDATE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR: [u8; 8] = [44, 125, 141, 226, 97, 179, 166, 96];
 
 /// SupplyCap account layout (manual deserialization in mint.rs)
 pub const SUPPLY_CAP_DISCRIMINATOR_SIZE: usize = 8;
@@ -21,6 +37,57 @@ pub const SUPPLY_CAP_VALUE_SIZE: usize = 8;
 pub const MIN_SUPPLY_CAP_DATA_LEN: usize = SUPPLY_CAP_VALUE_OFFSET
     .checked_add(SUPPLY_CAP_VALUE_SIZE)
     .unwrap();
+/// Mode byte follows the 1-byte bump; absent on accounts created before cap modes existed
+pub const SUPPLY_CAP_MODE_OFFSET: usize = MIN_SUPPLY_CAP_DATA_LEN.checked_add(1).unwrap();
+
+/// StablecoinState layout version written by this program (1: fixed header, 2: reserved tail,
+/// 3: SSS-1 -> SSS-2 migration links)
+pub const STABLECOIN_STATE_VERSION: u8 = 3;
+/// Layout versions of the per-address accounts. 0 is the layout before the version field,
+/// recognised by its shorter data length (as with SupplyCap's mode byte).
+pub const ROLE_ACCOUNT_VERSION: u8 = 1;
+pub const MINTER_INFO_VERSION: u8 = 1;
+pub const BLACKLIST_ENTRY_VERSION: u8 = 1;
+/// SupplyCap has no version field: an account with the mode byte is current, one without it
+/// is version 0
+pub const SUPPLY_CAP_VERSION: u8 = 1;
+/// Zeroed bytes at the end of each versioned account. New fields are carved out of them with a
+/// version bump, so existing accounts upgrade through migrate_account without growing.
+pub const STABLECOIN_STATE_RESERVED_LEN: usize = 56;
+pub const ROLE_ACCOUNT_RESERVED_LEN: usize = 32;
+pub const MINTER_INFO_RESERVED_LEN: usize = 64;
+pub const BLACKLIST_ENTRY_RESERVED_LEN: usize = 32;
+/// sha256("account:StablecoinStateV1")[..8]. The versioned layout has its own discriminator so
+/// an unversioned account can never be decoded as (or by the hook mistaken for) the header.
+pub const STABLECOIN_STATE_DISCRIMINATOR: &[u8] = &[126, 150, 54, 111, 226, 127, 234, 242];
+/// sha256("account:StablecoinState")[..8]: the unversioned layout, only accepted by
+/// migrate_stablecoin_state and migrate_account
+pub const LEGACY_STABLECOIN_STATE_DISCRIMINATOR: [u8; 8] = [107, 33, 134, 54, 129, 13, 187, 151];
+/// StablecoinState fixed header offsets (after the discriminator); read by the transfer hook
+pub const STABLECOIN_VERSION_OFFSET: usize = 8;
+pub const STABLECOIN_PAUSED_OFFSET: usize = STABLECOIN_VERSION_OFFSET + 1;
+pub const STABLECOIN_PAUSE_FLAGS_OFFSET: usize = STABLECOIN_PAUSED_OFFSET + 1;
+pub const STABLECOIN_PERMANENT_DELEGATE_OFFSET: usize = STABLECOIN_PAUSE_FLAGS_OFFSET + 1;
+pub const STABLECOIN_TRANSFER_HOOK_OFFSET: usize = STABLECOIN_PERMANENT_DELEGATE_OFFSET + 1;
+pub const STABLECOIN_ALLOWLIST_OFFSET: usize = STABLECOIN_TRANSFER_HOOK_OFFSET + 1;
+pub const STABLECOIN_PAUSED_UNTIL_OFFSET: usize = STABLECOIN_ALLOWLIST_OFFSET + 1;
+pub const STABLECOIN_HEADER_LEN: usize = STABLECOIN_PAUSED_UNTIL_OFFSET + 8;
+
+/// BlacklistEntry.expires_at offset (discriminator + stablecoin + address); read by the
+/// transfer hook
+pub const BLACKLIST_EXPIRES_AT_OFFSET: usize = 8 + 32 + 32;
+
+/// VelocityConfig limit offsets (discriminator + stablecoin); read by the transfer hook
+pub const VELOCITY_MAX_PER_TRANSFER_OFFSET: usize = 8 + 32;
+pub const VELOCITY_MAX_PER_WINDOW_OFFSET: usize = VELOCITY_MAX_PER_TRANSFER_OFFSET + 8;
+/// ExtraAccountMetaList size once it resolves the velocity accounts (10 extra metas of 35
+/// bytes after the 16-byte TLV header); smaller lists predate velocity limits
+pub const VELOCITY_EXTRA_ACCOUNT_METAS_LEN: usize = 16 + 35 * 10;
+
+/// ReportingConfig.threshold offset (discriminator + stablecoin); read by the transfer hook
+pub const REPORTING_THRESHOLD_OFFSET: usize = 8 + 32;
+/// ExtraAccountMetaList size once it resolves the reporting config (11 extra metas)
+pub const REPORTING_EXTRA_ACCOUNT_METAS_LEN: usize = 16 + 35 * 11;
 
 /// Supply cap sentinel values (update_supply_cap)
 pub const NO_SUPPLY_CAP_INDICATOR: u64 = 0;
@@ -30,4 +97,35 @@ pub const NO_SUPPLY_CAP: u64 = u64::MAX;
 pub const MAX_NAME_LEN: usize = 32;
 pub const MAX_SYMBOL_LEN: usize = 10;
 pub const MAX_URI_LEN: usize = 200;
+/// Additional on-mint metadata fields (e.g. issuer, attestation URL) per update_metadata call
+pub const MAX_METADATA_FIELDS: usize = 8;
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+pub const MAX_METADATA_VALUE_LEN: usize = 200;
 pub const MAX_REASON_LEN: usize = 100;
+pub const MAX_REDEMPTION_REF_LEN: usize = 64;
+/// Entries per batch blacklist/freeze instruction. A blacklist batch passes two accounts per
+/// entry, so 10 keeps it within the transaction size and compute limits.
+pub const MAX_BATCH_SIZE: usize = 10;
+
+/// Multisig limits (approvals are tracked in a u16 bitmask)
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+pub const MAX_PROPOSAL_TTL: i64 = 30 * 24 * 60 * 60;
+
+/// Minter allowance limits (periodic mode)
+pub const MAX_MINTER_WINDOW: i64 = 366 * 24 * 60 * 60;
+
+/// Pause scopes (StablecoinState.pause_flags bitmask). `paused` still blocks every scope.
+pub const PAUSE_MINT: u8 = 1 << 0;
+pub const PAUSE_BURN: u8 = 1 << 1;
+pub const PAUSE_TRANSFER: u8 = 1 << 2;
+pub const PAUSE_FREEZE: u8 = 1 << 3;
+pub const PAUSE_ALL_SCOPES: u8 = PAUSE_MINT | PAUSE_BURN | PAUSE_TRANSFER | PAUSE_FREEZE;
+
+/// Emergency pause limit (per trigger or extension)
+pub const MAX_EMERGENCY_PAUSE: i64 = 7 * 24 * 60 * 60;
+
+/// After an emergency pause ends, only the authority can trigger another until this has passed
+pub const EMERGENCY_PAUSE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Timelock limits
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
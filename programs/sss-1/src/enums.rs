@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 /// Bitflag roles for gas-efficient storage.
 /// Each role maps to a specific capability in the stablecoin system.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
 pub struct RoleFlags {
     pub is_minter: bool,
     pub is_burner: bool,
@@ -10,8 +10,58 @@ pub struct RoleFlags {
     pub is_freezer: bool,
     pub is_blacklister: bool,
     pub is_seizer: bool,
+    /// Manages allowlist entries and approves holders (allowlist-mode SSS-2 only)
+    pub is_allowlister: bool,
 }
 
 impl RoleFlags {
-    pub const LEN: usize = 6; // 6 booleans
+    pub const LEN: usize = 7; // 7 booleans
+}
+
+/// Metric the supply cap is enforced against. Ordered strictest first: for the same cap,
+/// each later mode leaves at least as much headroom as the one before it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
+pub enum SupplyCapMode {
+    /// stablecoin.total_minted (burns never free capacity)
+    #[default]
+    LifetimeMinted,
+    /// stablecoin.total_minted - stablecoin.total_burned
+    Circulating,
+    /// The Token-2022 mint's on-chain `supply` (also reflects burns made outside this program)
+    MintSupply,
+}
+
+impl SupplyCapMode {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(SupplyCapMode::LifetimeMinted),
+            1 => Some(SupplyCapMode::Circulating),
+            2 => Some(SupplyCapMode::MintSupply),
+            _ => None,
+        }
+    }
+}
+
+/// Structured reason for a blacklist entry, alongside the free-form `reason` text.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
+pub enum BlacklistReason {
+    /// Sanctions list match (e.g. OFAC SDN)
+    Ofac,
+    /// Court order or law-enforcement request
+    CourtOrder,
+    /// Fraud or theft
+    Fraud,
+    /// Issuer's internal policy
+    #[default]
+    Internal,
+}
+
+/// Account types upgraded by `migrate_account` (reported in `AccountMigrated`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionedAccountType {
+    StablecoinState,
+    RoleAccount,
+    MinterInfo,
+    BlacklistEntry,
+    SupplyCap,
 }
@@ -46,4 +46,145 @@ pub enum StablecoinError {
 
     #[msg("Supply cap exceeded")]
     SupplyCapExceeded,
+
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    #[msg("Invalid multisig configuration")]
+    InvalidMultisigConfig,
+
+    #[msg("Signer is not a multisig member")]
+    NotMultisigMember,
+
+    #[msg("Member has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotMet,
+
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal was created under a previous multisig configuration")]
+    ProposalStale,
+
+    #[msg("Proposal is still open")]
+    ProposalStillOpen,
+
+    #[msg("Account required by this admin action was not provided")]
+    MissingAdminAccount,
+
+    #[msg("Change must be queued through the timelock")]
+    TimelockRequired,
+
+    #[msg("Timelock is not enabled for this stablecoin")]
+    TimelockNotEnabled,
+
+    #[msg("Queued change is not ready to execute")]
+    TimelockNotReady,
+
+    #[msg("Invalid timelock configuration")]
+    InvalidTimelockConfig,
+
+    #[msg("Action cannot be queued through the timelock")]
+    NotTimelockable,
+
+    #[msg("Queued change was created by a previous authority")]
+    QueuedChangeStale,
+
+    #[msg("Minter allowance for the current window exceeded")]
+    AllowanceExceeded,
+
+    #[msg("Invalid minter allowance: window out of range or limit mismatch")]
+    InvalidAllowanceConfig,
+
+    #[msg("Minter has not been configured with update_minter")]
+    MinterNotConfigured,
+
+    #[msg("Supply cap has not been set with update_supply_cap")]
+    SupplyCapNotSet,
+
+    #[msg("Redemption reference too long")]
+    ReferenceTooLong,
+
+    #[msg("Invalid pause scope bitmask")]
+    InvalidPauseScope,
+
+    #[msg("Emergency pause duration out of range")]
+    InvalidPauseDuration,
+
+    #[msg("An emergency pause is already active")]
+    EmergencyPauseActive,
+
+    #[msg("No emergency pause is active")]
+    EmergencyPauseNotActive,
+
+    #[msg("Seizure requires a blacklisted or frozen source account")]
+    SeizureNotPermitted,
+
+    #[msg("Seizure amount exceeds the source balance")]
+    SeizureAmountExceedsBalance,
+
+    #[msg("No treasury registered for this stablecoin")]
+    TreasuryNotSet,
+
+    #[msg("Destination is not the registered treasury")]
+    InvalidTreasury,
+
+    #[msg("Allowlist mode is not enabled for this stablecoin")]
+    AllowlistNotEnabled,
+
+    #[msg("Address is already allowlisted")]
+    AlreadyAllowlisted,
+
+    #[msg("Token account does not belong to the holder for this mint")]
+    InvalidHolderAccount,
+
+    #[msg("Blacklist expiry must be in the future")]
+    InvalidBlacklistExpiry,
+
+    #[msg("Batch must contain between 1 and MAX_BATCH_SIZE entries")]
+    InvalidBatchSize,
+
+    #[msg("Batch account is not the expected PDA or token account")]
+    InvalidBatchAccount,
+
+    #[msg("Transfer hook accounts predate velocity limits; migrate the ExtraAccountMetaList first")]
+    VelocityHookAccountsOutdated,
+
+    #[msg("Wallet is already exempt from velocity limits")]
+    AlreadyVelocityExempt,
+
+    #[msg("Transfer hook accounts predate large-transfer reporting; migrate the ExtraAccountMetaList first")]
+    ReportingHookAccountsOutdated,
+
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Migration must link an SSS-1 stablecoin to a new SSS-2 stablecoin with the same authority and decimals")]
+    InvalidMigrationTarget,
+
+    #[msg("Stablecoin is already linked for migration")]
+    MigrationAlreadyLinked,
+
+    #[msg("Legacy and successor mints are not linked")]
+    MintNotLinked,
+
+    #[msg("Metadata fields: at most MAX_METADATA_FIELDS, with non-empty keys within the length limits")]
+    InvalidMetadataField,
+
+    #[msg("Mint was created without on-mint metadata")]
+    MintMetadataNotInitialized,
+
+    #[msg("Address is not allowlisted")]
+    NotAllowlisted,
+
+    #[msg("Emergency pause cooldown has not elapsed; only the authority can trigger one now")]
+    EmergencyPauseCooldown,
+
+    #[msg("Treasury must be a Token-2022 account of this stablecoin's mint")]
+    InvalidTreasuryAccount,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{AdminAction, BlacklistReason, MetadataField, SupplyCapMode, VersionedAccountType};
+
 #[event]
 pub struct StablecoinInitialized {
     pub stablecoin: Pubkey,
@@ -30,6 +32,53 @@ pub struct TokensBurned {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct RedemptionRequested {
+    pub stablecoin: Pubkey,
+    pub request: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub reference_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionFulfilled {
+    pub stablecoin: Pubkey,
+    pub request: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub reference_hash: [u8; 32],
+    pub fulfilled_by: Pubkey,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionRejected {
+    pub stablecoin: Pubkey,
+    pub request: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    pub reference_hash: [u8; 32],
+    pub rejected_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensRedeemed {
+    pub stablecoin: Pubkey,
+    /// Owner of the token account burned from
+    pub holder: Pubkey,
+    pub source_token_account: Pubkey,
+    pub amount: u64,
+    /// Off-chain redemption reference (e.g. wire or ticket id)
+    pub reference: String,
+    pub redeemed_by: Pubkey,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AccountFrozen {
     pub stablecoin: Pubkey,
@@ -38,6 +87,17 @@ pub struct AccountFrozen {
     pub timestamp: i64,
 }
 
+/// Summary of a batch_freeze_accounts; each frozen account also emits AccountFrozen.
+#[event]
+pub struct AccountsBatchFrozen {
+    pub stablecoin: Pubkey,
+    pub frozen: u32,
+    /// Token accounts that were already frozen
+    pub skipped: u32,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AccountThawed {
     pub stablecoin: Pubkey,
@@ -53,6 +113,38 @@ pub struct StablecoinPaused {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PauseScopesUpdated {
+    pub stablecoin: Pubkey,
+    /// Scopes changed by this call (PAUSE_* bitmask)
+    pub scopes: u8,
+    pub paused: bool,
+    /// Resulting StablecoinState.pause_flags
+    pub pause_flags: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyPauseTriggered {
+    pub stablecoin: Pubkey,
+    pub triggered_by: Pubkey,
+    /// Requested duration in seconds
+    pub duration: i64,
+    pub paused_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyPauseExtended {
+    pub stablecoin: Pubkey,
+    pub extended_by: Pubkey,
+    /// Seconds added to the current expiry
+    pub duration: i64,
+    pub paused_until: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct StablecoinUnpaused {
     pub stablecoin: Pubkey,
@@ -70,6 +162,7 @@ pub struct RolesUpdated {
     pub is_freezer: bool,
     pub is_blacklister: bool,
     pub is_seizer: bool,
+    pub is_allowlister: bool,
     pub updated_by: Pubkey,
     pub timestamp: i64,
 }
@@ -83,6 +176,44 @@ pub struct MinterUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct MinterRemoved {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub epoch: u64,
+    /// Lifetime total minted during this epoch
+    pub minted_amount: u64,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub stablecoin: Pubkey,
+    pub holder: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterAllowanceUpdated {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub window_seconds: i64,
+    pub window_limit: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SupplyCapModeUpdated {
+    pub stablecoin: Pubkey,
+    pub mode: SupplyCapMode,
+    pub cap: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SupplyCapUpdated {
     pub stablecoin: Pubkey,
@@ -99,6 +230,159 @@ pub struct AuthorityTransferred {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AuthorityNominated {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityAccepted {
+    pub stablecoin: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigCreated {
+    pub stablecoin: Pubkey,
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub stablecoin: Pubkey,
+    pub multisig: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub config_version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub stablecoin: Pubkey,
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub stablecoin: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub stablecoin: Pubkey,
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StablecoinStateMigrated {
+    pub stablecoin: Pubkey,
+    /// 0 = unversioned layout
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub account_type: VersionedAccountType,
+    /// 0 = layout before the version field
+    pub from_version: u8,
+    pub to_version: u8,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferHookAccountsInitialized {
+    pub stablecoin: Pubkey,
+    pub extra_account_metas: Pubkey,
+    pub initialized_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferHookAccountsUpdated {
+    pub stablecoin: Pubkey,
+    pub extra_account_metas: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimelockUpdated {
+    pub stablecoin: Pubkey,
+    pub delay: i64,
+    pub guardian: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChangeQueued {
+    pub stablecoin: Pubkey,
+    pub queued_change: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    pub eta: i64,
+    pub queued_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChangeExecuted {
+    pub stablecoin: Pubkey,
+    pub queued_change: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChangeCancelled {
+    pub stablecoin: Pubkey,
+    pub queued_change: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AddedToBlacklist {
     pub stablecoin: Pubkey,
@@ -106,6 +390,21 @@ pub struct AddedToBlacklist {
     pub reason: String,
     pub blacklisted_by: Pubkey,
     pub timestamp: i64,
+    pub reason_code: BlacklistReason,
+    /// 0 = never expires
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct BlacklistEntryUpdated {
+    pub stablecoin: Pubkey,
+    pub address: Pubkey,
+    pub reason_code: BlacklistReason,
+    pub reason: String,
+    /// 0 = never expires
+    pub expires_at: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
 }
 
 #[event]
@@ -116,6 +415,102 @@ pub struct RemovedFromBlacklist {
     pub timestamp: i64,
 }
 
+/// Summary of a batch_add_to_blacklist; each new entry also emits AddedToBlacklist.
+#[event]
+pub struct BlacklistBatchAdded {
+    pub stablecoin: Pubkey,
+    pub added: u32,
+    /// Addresses that already had an active entry
+    pub skipped: u32,
+    pub reason_code: BlacklistReason,
+    pub expires_at: i64,
+    pub blacklisted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Summary of a batch_remove_from_blacklist; each closed entry also emits RemovedFromBlacklist.
+#[event]
+pub struct BlacklistBatchRemoved {
+    pub stablecoin: Pubkey,
+    pub removed: u32,
+    /// Addresses without an entry
+    pub skipped: u32,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VelocityLimitsUpdated {
+    pub stablecoin: Pubkey,
+    /// 0 = no limit
+    pub max_per_transfer: u64,
+    /// 0 = no limit
+    pub max_per_window: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VelocityExemptionAdded {
+    pub stablecoin: Pubkey,
+    pub wallet: Pubkey,
+    pub exempted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VelocityExemptionRemoved {
+    pub stablecoin: Pubkey,
+    pub wallet: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReportingThresholdUpdated {
+    pub stablecoin: Pubkey,
+    /// 0 = reporting off
+    pub threshold: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddedToAllowlist {
+    pub stablecoin: Pubkey,
+    pub address: Pubkey,
+    pub allowlisted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RemovedFromAllowlist {
+    pub stablecoin: Pubkey,
+    pub address: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HolderApproved {
+    pub stablecoin: Pubkey,
+    pub holder: Pubkey,
+    pub token_account: Pubkey,
+    /// Whether the token account was frozen and has been thawed
+    pub thawed: bool,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub stablecoin: Pubkey,
+    pub previous: Pubkey,
+    pub treasury: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensSeized {
     pub stablecoin: Pubkey,
@@ -124,4 +519,45 @@ pub struct TokensSeized {
     pub amount: u64,
     pub seized_by: Pubkey,
     pub timestamp: i64,
+    pub seizure_record: Pubkey,
+    pub case_reference_hash: [u8; 32],
+}
+
+#[event]
+pub struct SuccessorMintLinked {
+    pub legacy_stablecoin: Pubkey,
+    pub legacy_mint: Pubkey,
+    pub successor_stablecoin: Pubkey,
+    pub successor_mint: Pubkey,
+    /// Legacy circulating supply at link time
+    pub legacy_outstanding: u64,
+    pub linked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegacyTokensSwapped {
+    pub legacy_mint: Pubkey,
+    pub successor_mint: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    /// Legacy supply still to be swapped after this swap
+    pub legacy_outstanding: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub stablecoin: Pubkey,
+    pub mint: Pubkey,
+    /// Values after the update
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    /// Additional fields set (empty value = removed)
+    pub fields: Vec<MetadataField>,
+    /// Whether the on-mint metadata was updated too (false for mints created without it)
+    pub on_mint: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
 }
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::{Account as SplAccount, AccountState},
+};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{AddedToAllowlist, HolderApproved, RemovedFromAllowlist};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AddToAllowlist<'info> {
+    #[account(mut)]
+    pub allowlister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), allowlister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = allowlister,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
+        bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: The wallet being allowlisted
+    pub address: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromAllowlist<'info> {
+    #[account(mut)]
+    pub allowlister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), allowlister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = allowlister,
+        seeds = [ALLOWLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
+        bump = allowlist_entry.bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: The wallet being removed from the allowlist
+    pub address: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveHolder<'info> {
+    #[account(mut)]
+    pub allowlister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), allowlister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Owner of `target_token_account` (verified in the handler)
+    pub holder: AccountInfo<'info>,
+
+    /// CHECK: Holder's token account to thaw; mint and owner verified in the handler
+    #[account(mut, owner = spl_token_2022::ID)]
+    pub target_token_account: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = allowlister,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_SEED, stablecoin.key().as_ref(), holder.key().as_ref()],
+        bump,
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddToAllowlist<'info> {
+    pub fn add_to_allowlist(&mut self, bumps: AddToAllowlistBumps) -> Result<()> {
+        require!(
+            self.stablecoin.is_allowlist_mode(),
+            StablecoinError::AllowlistNotEnabled
+        );
+        require!(
+            self.role.roles.is_allowlister,
+            StablecoinError::Unauthorized
+        );
+
+        // Same init_if_needed pattern as add_to_blacklist
+        if self.allowlist_entry.address != Pubkey::default() {
+            return Err(StablecoinError::AlreadyAllowlisted.into());
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        self.allowlist_entry.set_inner(AllowlistEntry {
+            stablecoin: self.stablecoin.key(),
+            address: self.address.key(),
+            allowlisted_at: now,
+            allowlisted_by: self.allowlister.key(),
+            bump: bumps.allowlist_entry,
+        });
+
+        emit!(AddedToAllowlist {
+            stablecoin: self.stablecoin.key(),
+            address: self.address.key(),
+            allowlisted_by: self.allowlister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> RemoveFromAllowlist<'info> {
+    pub fn remove_from_allowlist(&mut self) -> Result<()> {
+        require!(
+            self.stablecoin.is_allowlist_mode(),
+            StablecoinError::AllowlistNotEnabled
+        );
+        require!(
+            self.role.roles.is_allowlister,
+            StablecoinError::Unauthorized
+        );
+
+        // NOTE: The holder's token accounts stay thawed; the hook blocks their transfers.
+        emit!(RemovedFromAllowlist {
+            stablecoin: self.stablecoin.key(),
+            address: self.address.key(),
+            removed_by: self.allowlister.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> ApproveHolder<'info> {
+    /// Onboarding for allowlist mode combined with `default_account_frozen`: allowlists the
+    /// holder (if not already) and thaws their token account in one step. Idempotent, so it
+    /// can also be used to thaw further token accounts of an approved holder.
+    pub fn approve_holder(&mut self, bumps: ApproveHolderBumps) -> Result<()> {
+        require!(
+            self.stablecoin.is_allowlist_mode(),
+            StablecoinError::AllowlistNotEnabled
+        );
+        require!(
+            self.role.roles.is_allowlister,
+            StablecoinError::Unauthorized
+        );
+
+        let data = self.target_token_account.try_borrow_data()?;
+        let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+        require!(
+            account.base.mint == self.mint.key() && account.base.owner == self.holder.key(),
+            StablecoinError::InvalidHolderAccount
+        );
+        let frozen = account.base.state == AccountState::Frozen;
+        drop(data);
+
+        let now = Clock::get()?.unix_timestamp;
+        if self.allowlist_entry.address == Pubkey::default() {
+            self.allowlist_entry.set_inner(AllowlistEntry {
+                stablecoin: self.stablecoin.key(),
+                address: self.holder.key(),
+                allowlisted_at: now,
+                allowlisted_by: self.allowlister.key(),
+                bump: bumps.allowlist_entry,
+            });
+
+            emit!(AddedToAllowlist {
+                stablecoin: self.stablecoin.key(),
+                address: self.holder.key(),
+                allowlisted_by: self.allowlister.key(),
+                timestamp: now,
+            });
+        }
+
+        if frozen {
+            // Thawing is a freeze-scope operation (see thaw_account)
+            require!(!self.stablecoin.is_freeze_paused(), StablecoinError::Paused);
+
+            // CPI: thaw_account — stablecoin PDA is the freeze authority
+            let mint_key = self.mint.key();
+            let signer_seeds: &[&[u8]] =
+                &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+
+            invoke_signed(
+                &token_instruction::thaw_account(
+                    &self.token_program.key(),
+                    &self.target_token_account.key(),
+                    &self.mint.key(),
+                    &self.stablecoin.key(),
+                    &[],
+                )?,
+                &[
+                    self.target_token_account.to_account_info(),
+                    self.mint.to_account_info(),
+                    self.stablecoin.to_account_info(),
+                ],
+                &[signer_seeds],
+            )?;
+        }
+
+        emit!(HolderApproved {
+            stablecoin: self.stablecoin.key(),
+            holder: self.holder.key(),
+            token_account: self.target_token_account.key(),
+            thawed: frozen,
+            approved_by: self.allowlister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
 use crate::{
-    error::StablecoinError, AddedToBlacklist, BlacklistEntry, RemovedFromBlacklist, RoleAccount,
-    StablecoinState, BLACKLIST_SEED, MAX_REASON_LEN, ROLE_SEED, STABLECOIN_SEED,
+    error::StablecoinError, AddedToBlacklist, BlacklistBatchAdded, BlacklistBatchRemoved,
+    BlacklistEntry, BlacklistEntryUpdated, BlacklistReason, RemovedFromBlacklist, RoleAccount,
+    StablecoinState, BLACKLIST_ENTRY_RESERVED_LEN, BLACKLIST_ENTRY_VERSION, BLACKLIST_SEED,
+    MAX_BATCH_SIZE, MAX_REASON_LEN, ROLE_SEED, STABLECOIN_SEED,
 };
 
 #[derive(Accounts)]
@@ -65,8 +68,330 @@ pub struct RemoveFromBlacklist<'info> {
     pub address: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateBlacklistEntry<'info> {
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        seeds = [BLACKLIST_SEED, stablecoin.key().as_ref(), address.key().as_ref()],
+        bump = blacklist_entry.bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// CHECK: The blacklisted address
+    pub address: AccountInfo<'info>,
+}
+
+/// remaining_accounts: `[address, blacklist_entry]` pairs (entries writable), at most
+/// MAX_BATCH_SIZE pairs.
+#[derive(Accounts)]
+pub struct BatchAddToBlacklist<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts: `[address, blacklist_entry]` pairs (entries writable), at most
+/// MAX_BATCH_SIZE pairs.
+#[derive(Accounts)]
+pub struct BatchRemoveFromBlacklist<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+}
+
+/// Validate an optional expiry and convert it to the stored form (0 = never expires).
+fn stored_expiry(expires_at: Option<i64>, now: i64) -> Result<i64> {
+    match expires_at {
+        Some(expires_at) => {
+            require!(expires_at > now, StablecoinError::InvalidBlacklistExpiry);
+            Ok(expires_at)
+        }
+        None => Ok(0),
+    }
+}
+
+/// Split batch remaining_accounts into `(address, blacklist_entry)` pairs and check each entry
+/// is the blacklist PDA for its address. Returns the pairs with the entry bump.
+fn blacklist_batch<'a, 'info>(
+    stablecoin: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<(&'a AccountInfo<'info>, &'a AccountInfo<'info>, u8)>> {
+    require!(
+        !remaining_accounts.is_empty()
+            && remaining_accounts.len().is_multiple_of(2)
+            && remaining_accounts.len() / 2 <= MAX_BATCH_SIZE,
+        StablecoinError::InvalidBatchSize
+    );
+
+    remaining_accounts
+        .chunks_exact(2)
+        .map(|pair| {
+            let (address, entry) = (&pair[0], &pair[1]);
+            let (expected, bump) = Pubkey::find_program_address(
+                &[BLACKLIST_SEED, stablecoin.as_ref(), address.key.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(entry.key(), expected, StablecoinError::InvalidBatchAccount);
+            require!(entry.is_writable, StablecoinError::InvalidBatchAccount);
+            Ok((address, entry, bump))
+        })
+        .collect()
+}
+
+/// Existing blacklist entry at `entry`, or `None` if the PDA has not been created.
+fn load_blacklist_entry(entry: &AccountInfo) -> Result<Option<BlacklistEntry>> {
+    if entry.owner != &crate::ID || entry.data_is_empty() {
+        return Ok(None);
+    }
+    let data = entry.try_borrow_data()?;
+    Ok(Some(BlacklistEntry::try_deserialize(&mut &data[..])?))
+}
+
+impl<'info> BatchAddToBlacklist<'info> {
+    /// Create a blacklist PDA for `entry` the way Anchor's `init` does: `create_account` for an
+    /// empty address, or top-up + allocate + assign if someone already sent it lamports.
+    fn create_entry(&self, entry: &AccountInfo<'info>, seeds: &[&[u8]]) -> Result<()> {
+        let space = BlacklistEntry::LEN;
+        let required = Rent::get()?.minimum_balance(space);
+        let current = entry.lamports();
+        let system = self.system_program.to_account_info();
+
+        if current == 0 {
+            return system_program::create_account(
+                CpiContext::new_with_signer(
+                    system,
+                    CreateAccount {
+                        from: self.blacklister.to_account_info(),
+                        to: entry.clone(),
+                    },
+                    &[seeds],
+                ),
+                required,
+                space as u64,
+                &crate::ID,
+            );
+        }
+
+        if required > current {
+            system_program::transfer(
+                CpiContext::new(
+                    system.clone(),
+                    Transfer {
+                        from: self.blacklister.to_account_info(),
+                        to: entry.clone(),
+                    },
+                ),
+                required - current,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system.clone(),
+                Allocate {
+                    account_to_allocate: entry.clone(),
+                },
+                &[seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system,
+                Assign {
+                    account_to_assign: entry.clone(),
+                },
+                &[seeds],
+            ),
+            &crate::ID,
+        )
+    }
+
+    /// Blacklist up to MAX_BATCH_SIZE addresses with one reason. Addresses that already have an
+    /// active entry are skipped rather than failing the batch; expired entries are overwritten.
+    pub fn batch_add_to_blacklist(
+        &mut self,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // NOTE: Not gated by pause (see add_to_blacklist).
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            reason.len() <= MAX_REASON_LEN,
+            StablecoinError::ReasonTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = stored_expiry(expires_at, now)?;
+        let stablecoin_key = self.stablecoin.key();
+        let (mut added, mut skipped) = (0u32, 0u32);
+
+        for (address, entry, bump) in blacklist_batch(&stablecoin_key, remaining_accounts)? {
+            match load_blacklist_entry(entry)? {
+                Some(existing) if existing.is_active(now) => {
+                    skipped += 1;
+                    continue;
+                }
+                Some(_) => {}
+                None => self.create_entry(
+                    entry,
+                    &[BLACKLIST_SEED, stablecoin_key.as_ref(), address.key.as_ref(), &[bump]],
+                )?,
+            }
+
+            BlacklistEntry {
+                stablecoin: stablecoin_key,
+                address: address.key(),
+                expires_at,
+                reason_code,
+                reason: reason.clone(),
+                blacklisted_at: now,
+                blacklisted_by: self.blacklister.key(),
+                bump,
+                version: BLACKLIST_ENTRY_VERSION,
+                reserved: [0; BLACKLIST_ENTRY_RESERVED_LEN],
+            }
+            .try_serialize(&mut &mut entry.try_borrow_mut_data()?[..])?;
+
+            emit!(AddedToBlacklist {
+                stablecoin: stablecoin_key,
+                address: address.key(),
+                reason: reason.clone(),
+                blacklisted_by: self.blacklister.key(),
+                timestamp: now,
+                reason_code,
+                expires_at,
+            });
+            added += 1;
+        }
+
+        emit!(BlacklistBatchAdded {
+            stablecoin: stablecoin_key,
+            added,
+            skipped,
+            reason_code,
+            expires_at,
+            blacklisted_by: self.blacklister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> BatchRemoveFromBlacklist<'info> {
+    /// Close up to MAX_BATCH_SIZE blacklist entries, refunding rent to the blacklister.
+    /// Addresses without an entry are skipped.
+    pub fn batch_remove_from_blacklist(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // NOTE: Not gated by pause (see add_to_blacklist).
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let stablecoin_key = self.stablecoin.key();
+        let (mut removed, mut skipped) = (0u32, 0u32);
+
+        for (address, entry, _) in blacklist_batch(&stablecoin_key, remaining_accounts)? {
+            if load_blacklist_entry(entry)?.is_none() {
+                skipped += 1;
+                continue;
+            }
+
+            // Same as Anchor's `close = blacklister`
+            let blacklister = self.blacklister.to_account_info();
+            let refund = blacklister
+                .lamports()
+                .checked_add(entry.lamports())
+                .ok_or(StablecoinError::MathOverflow)?;
+            **blacklister.try_borrow_mut_lamports()? = refund;
+            **entry.try_borrow_mut_lamports()? = 0;
+            entry.assign(&system_program::ID);
+            entry.resize(0)?;
+
+            emit!(RemovedFromBlacklist {
+                stablecoin: stablecoin_key,
+                address: address.key(),
+                removed_by: self.blacklister.key(),
+                timestamp: now,
+            });
+            removed += 1;
+        }
+
+        emit!(BlacklistBatchRemoved {
+            stablecoin: stablecoin_key,
+            removed,
+            skipped,
+            removed_by: self.blacklister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
 impl<'info> AddToBlacklist<'info> {
-    pub fn add_to_blacklist(&mut self, reason: String, bumps: AddToBlacklistBumps) -> Result<()> {
+    pub fn add_to_blacklist(
+        &mut self,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+        bumps: AddToBlacklistBumps,
+    ) -> Result<()> {
         // Feature gate: only SSS-2 tokens support blacklisting.
         // NOTE: Compliance ops (blacklist, seize) are NOT gated by pause so emergency
         // compliance actions (e.g. blacklist OFAC match during operational pause) can proceed.
@@ -83,18 +408,27 @@ impl<'info> AddToBlacklist<'info> {
             StablecoinError::ReasonTooLong
         );
 
-        // Return custom error when address is already blacklisted (init_if_needed loads existing account)
-        if self.blacklist_entry.address != Pubkey::default() {
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = stored_expiry(expires_at, now)?;
+
+        // Return custom error when address is already blacklisted (init_if_needed loads existing
+        // account). An expired entry is overwritten.
+        if self.blacklist_entry.address != Pubkey::default() && self.blacklist_entry.is_active(now)
+        {
             return Err(StablecoinError::AlreadyBlacklisted.into());
         }
 
         self.blacklist_entry.set_inner(BlacklistEntry {
             stablecoin: self.stablecoin.key(),
             address: self.address.key(),
+            expires_at,
+            reason_code,
             reason: reason.clone(),
-            blacklisted_at: Clock::get()?.unix_timestamp,
+            blacklisted_at: now,
             blacklisted_by: self.blacklister.key(),
             bump: bumps.blacklist_entry,
+            version: BLACKLIST_ENTRY_VERSION,
+            reserved: [0; BLACKLIST_ENTRY_RESERVED_LEN],
         });
 
         emit!(AddedToBlacklist {
@@ -102,7 +436,54 @@ impl<'info> AddToBlacklist<'info> {
             address: self.address.key(),
             reason,
             blacklisted_by: self.blacklister.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
+            reason_code,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> UpdateBlacklistEntry<'info> {
+    /// Replace the reason code, reason text and expiry of an existing entry. Passing an expiry
+    /// reactivates an expired entry; `None` makes it permanent.
+    pub fn update_blacklist_entry(
+        &mut self,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        // NOTE: Not gated by pause (see add_to_blacklist).
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            reason.len() <= MAX_REASON_LEN,
+            StablecoinError::ReasonTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = stored_expiry(expires_at, now)?;
+
+        let entry = &mut self.blacklist_entry;
+        entry.reason_code = reason_code;
+        entry.reason = reason.clone();
+        entry.expires_at = expires_at;
+
+        emit!(BlacklistEntryUpdated {
+            stablecoin: self.stablecoin.key(),
+            address: self.address.key(),
+            reason_code,
+            reason,
+            expires_at,
+            updated_by: self.blacklister.key(),
+            timestamp: now,
         });
 
         Ok(())
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
-use spl_token_2022::instruction as token_instruction;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use spl_token_2022::{
+    extension::StateWithExtensions, instruction as token_instruction,
+    state::Account as SplAccount,
+};
 
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::TokensBurned;
+use crate::events::{TokensBurned, TokensRedeemed};
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -37,10 +40,45 @@ pub struct BurnTokens<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
+/// Redemption burn from any holder's token account, using the stablecoin PDA as
+/// permanent delegate. Requires enable_permanent_delegate.
+#[derive(Accounts)]
+pub struct BurnFrom<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), burner.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Holder's token account to burn from (Token-2022 validates mint and balance)
+    #[account(mut)]
+    pub source_token_account: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
 impl<'info> BurnTokens<'info> {
     pub fn burn_tokens(&mut self, amount: u64) -> Result<()> {
         require!(amount > 0, StablecoinError::ZeroAmount);
-        require!(!self.stablecoin.paused, StablecoinError::Paused);
+        require!(
+            !self.stablecoin.is_paused_for(PAUSE_BURN, Clock::get()?.unix_timestamp),
+            StablecoinError::Paused
+        );
         require!(self.role.roles.is_burner, StablecoinError::Unauthorized);
 
         // CPI: burn — burner signs as token account owner
@@ -78,3 +116,68 @@ impl<'info> BurnTokens<'info> {
         Ok(())
     }
 }
+
+impl<'info> BurnFrom<'info> {
+    pub fn burn_from(&mut self, amount: u64, reference: String) -> Result<()> {
+        require!(amount > 0, StablecoinError::ZeroAmount);
+        require!(
+            !self.stablecoin.is_paused_for(PAUSE_BURN, Clock::get()?.unix_timestamp),
+            StablecoinError::Paused
+        );
+        require!(
+            self.stablecoin.enable_permanent_delegate,
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(self.role.roles.is_burner, StablecoinError::Unauthorized);
+        require!(
+            reference.len() <= MAX_REDEMPTION_REF_LEN,
+            StablecoinError::ReferenceTooLong
+        );
+
+        // Holder recorded in the event is the token account owner, not the account address.
+        let source_data = self.source_token_account.try_borrow_data()?;
+        let holder = StateWithExtensions::<SplAccount>::unpack(&source_data)?
+            .base
+            .owner;
+        drop(source_data);
+
+        // CPI: burn — stablecoin PDA signs as permanent delegate
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+        invoke_signed(
+            &token_instruction::burn(
+                &self.token_program.key(),
+                &self.source_token_account.key(),
+                &self.mint.key(),
+                &self.stablecoin.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                self.source_token_account.to_account_info(),
+                self.mint.to_account_info(),
+                self.stablecoin.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+
+        self.stablecoin.total_burned = self
+            .stablecoin
+            .total_burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(TokensRedeemed {
+            stablecoin: self.stablecoin.key(),
+            holder,
+            source_token_account: self.source_token_account.key(),
+            amount,
+            reference,
+            redeemed_by: self.burner.key(),
+            total_burned: self.stablecoin.total_burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
 use spl_token_2022;
-use spl_token_2022::instruction as token_instruction;
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::{Account as SplAccount, AccountState},
+};
 
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::{AccountFrozen, AccountThawed};
+use crate::events::{AccountFrozen, AccountThawed, AccountsBatchFrozen};
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -68,6 +72,33 @@ pub struct ThawTokenAccount<'info> {
     pub token_program: AccountInfo<'info>,
 }
 
+/// remaining_accounts: token accounts of this mint to freeze (writable), at most MAX_BATCH_SIZE.
+#[derive(Accounts)]
+pub struct BatchFreezeTokenAccounts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = role.stablecoin == stablecoin.key(),
+        constraint = role.holder == authority.key(),
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
 impl<'info> FreezeTokenAccount<'info> {
     pub fn freeze_token_account(&mut self) -> Result<()> {
         // Pauser and freezer both have freeze authority. Pausers can freeze accounts during
@@ -77,6 +108,7 @@ impl<'info> FreezeTokenAccount<'info> {
             self.role.roles.is_pauser || self.role.roles.is_freezer,
             StablecoinError::Unauthorized
         );
+        require!(!self.stablecoin.is_freeze_paused(), StablecoinError::Paused);
 
         // CPI: freeze_account — stablecoin PDA is the freeze authority
         let mint_key = self.mint.key();
@@ -116,6 +148,7 @@ impl<'info> ThawTokenAccount<'info> {
             self.role.roles.is_pauser || self.role.roles.is_freezer,
             StablecoinError::Unauthorized
         );
+        require!(!self.stablecoin.is_freeze_paused(), StablecoinError::Paused);
 
         // CPI: thaw_account — stablecoin PDA is the freeze authority
         let mint_key = self.mint.key();
@@ -147,3 +180,80 @@ impl<'info> ThawTokenAccount<'info> {
         Ok(())
     }
 }
+
+impl<'info> BatchFreezeTokenAccounts<'info> {
+    /// Freeze up to MAX_BATCH_SIZE token accounts. Accounts that are already frozen are skipped
+    /// rather than failing the batch.
+    pub fn batch_freeze_token_accounts(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // Same roles and pause scope as freeze_token_account
+        require!(
+            self.role.roles.is_pauser || self.role.roles.is_freezer,
+            StablecoinError::Unauthorized
+        );
+        require!(!self.stablecoin.is_freeze_paused(), StablecoinError::Paused);
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.len() <= MAX_BATCH_SIZE,
+            StablecoinError::InvalidBatchSize
+        );
+
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+        let (mut frozen, mut skipped) = (0u32, 0u32);
+
+        for token_account in remaining_accounts {
+            require!(
+                token_account.owner == &spl_token_2022::ID && token_account.is_writable,
+                StablecoinError::InvalidBatchAccount
+            );
+            let data = token_account.try_borrow_data()?;
+            let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+            require_keys_eq!(account.base.mint, mint_key, StablecoinError::InvalidBatchAccount);
+            let already_frozen = account.base.state == AccountState::Frozen;
+            drop(data);
+
+            if already_frozen {
+                skipped += 1;
+                continue;
+            }
+
+            // CPI: freeze_account — stablecoin PDA is the freeze authority
+            invoke_signed(
+                &token_instruction::freeze_account(
+                    &self.token_program.key(),
+                    &token_account.key(),
+                    &mint_key,
+                    &self.stablecoin.key(),
+                    &[],
+                )?,
+                &[
+                    token_account.clone(),
+                    self.mint.to_account_info(),
+                    self.stablecoin.to_account_info(),
+                ],
+                &[signer_seeds],
+            )?;
+
+            emit!(AccountFrozen {
+                stablecoin: self.stablecoin.key(),
+                account: token_account.key(),
+                frozen_by: self.authority.key(),
+                timestamp: now,
+            });
+            frozen += 1;
+        }
+
+        emit!(AccountsBatchFrozen {
+            stablecoin: self.stablecoin.key(),
+            frozen,
+            skipped,
+            frozen_by: self.authority.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
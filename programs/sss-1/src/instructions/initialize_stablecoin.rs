@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use spl_token_2022::{
     extension::default_account_state::instruction as default_state_ix,
+    extension::metadata_pointer::instruction as metadata_pointer_ix,
     extension::transfer_hook::instruction as transfer_hook_ix, extension::ExtensionType,
     instruction as token_instruction,
 };
+use spl_token_metadata_interface::instruction as metadata_instruction;
 
 use crate::error::StablecoinError;
 use crate::events::StablecoinInitialized;
+use crate::instructions::top_up_rent;
 use crate::state::*;
 use crate::{constants::*, RoleFlags};
 
@@ -20,6 +23,9 @@ pub struct InitializeParams {
     pub enable_permanent_delegate: bool,
     pub enable_transfer_hook: bool,
     pub default_account_frozen: bool,
+    /// Allowlist (KYC-gated) mode: the transfer hook requires both parties to hold an
+    /// AllowlistEntry. SSS-2 only.
+    pub enable_allowlist: bool,
 }
 
 #[derive(Accounts)]
@@ -86,8 +92,20 @@ impl<'info> InitializeStablecoin<'info> {
             );
         }
 
-        // 3. Determine Token-2022 extensions
-        let mut extension_types = vec![ExtensionType::MintCloseAuthority];
+        // Allowlist mode is enforced by the transfer hook, so it needs the full SSS-2 preset
+        if params.enable_allowlist {
+            require!(
+                params.enable_permanent_delegate && params.enable_transfer_hook,
+                StablecoinError::ComplianceNotEnabled
+            );
+        }
+
+        // 3. Determine Token-2022 extensions. TokenMetadata is variable-length and added by
+        // Token-2022 itself when the metadata is initialized.
+        let mut extension_types = vec![
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::MetadataPointer,
+        ];
 
         if params.enable_permanent_delegate {
             extension_types.push(ExtensionType::PermanentDelegate);
@@ -134,6 +152,17 @@ impl<'info> InitializeStablecoin<'info> {
             &[self.mint.to_account_info()],
         )?;
 
+        // MetadataPointer → metadata lives on the mint itself; stablecoin PDA may repoint it
+        invoke(
+            &metadata_pointer_ix::initialize(
+                &self.token_program.key(),
+                &self.mint.key(),
+                Some(self.stablecoin.key()),
+                Some(self.mint.key()),
+            )?,
+            &[self.mint.to_account_info()],
+        )?;
+
         // PermanentDelegate → stablecoin PDA can seize tokens from any holder (SSS-2)
         if params.enable_permanent_delegate {
             invoke(
@@ -185,8 +214,39 @@ impl<'info> InitializeStablecoin<'info> {
             &[self.mint.to_account_info()],
         )?;
 
-        // 6. Populate StablecoinState PDA
+        // 7. On-mint token metadata so wallets and explorers see name/symbol/uri. Needs the
+        // initialized mint; the stablecoin PDA signs as mint authority and becomes the update
+        // authority. Token-2022 grows the mint; the authority tops up its rent afterwards.
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[bumps.stablecoin]];
+        invoke_signed(
+            &metadata_instruction::initialize(
+                &self.token_program.key(),
+                &mint_key,
+                &self.stablecoin.key(),
+                &mint_key,
+                &self.stablecoin.key(),
+                params.name.clone(),
+                params.symbol.clone(),
+                params.uri.clone(),
+            ),
+            &[
+                self.mint.to_account_info(),
+                self.stablecoin.to_account_info(),
+                self.mint.to_account_info(),
+                self.stablecoin.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        top_up_rent(
+            &self.authority.to_account_info(),
+            &self.mint.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        // 8. Populate StablecoinState PDA
         self.stablecoin.set_inner(StablecoinState {
+            version: STABLECOIN_STATE_VERSION,
             authority: self.authority.key(),
             mint: self.mint.key(),
             name: params.name.clone(),
@@ -197,12 +257,25 @@ impl<'info> InitializeStablecoin<'info> {
             enable_transfer_hook: params.enable_transfer_hook,
             default_account_frozen: params.default_account_frozen,
             paused: false,
+            pause_flags: 0,
+            paused_until: 0,
+            enable_allowlist: params.enable_allowlist,
             total_minted: 0,
             total_burned: 0,
+            pending_authority: Pubkey::default(),
+            timelock_delay: 0,
+            guardian: Pubkey::default(),
+            queued_change_count: 0,
+            minter_epoch: 0,
+            treasury: Pubkey::default(),
             bump: bumps.stablecoin,
+            successor_mint: Pubkey::default(),
+            legacy_mint: Pubkey::default(),
+            legacy_outstanding: 0,
+            reserved: [0; STABLECOIN_STATE_RESERVED_LEN],
         });
 
-        // 7. Grant all roles to the initializing authority
+        // 9. Grant all roles to the initializing authority
         self.authority_role.set_inner(RoleAccount {
             stablecoin: self.stablecoin.key(),
             holder: self.authority.key(),
@@ -213,11 +286,14 @@ impl<'info> InitializeStablecoin<'info> {
                 is_freezer: true,
                 is_blacklister: true,
                 is_seizer: true,
+                is_allowlister: true,
             },
             bump: bumps.authority_role,
+            version: ROLE_ACCOUNT_VERSION,
+            reserved: [0; ROLE_ACCOUNT_RESERVED_LEN],
         });
 
-        // 8. Emit audit event
+        // 10. Emit audit event
         emit!(StablecoinInitialized {
             stablecoin: self.stablecoin.key(),
             mint: self.mint.key(),
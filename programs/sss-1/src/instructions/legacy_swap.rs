@@ -0,0 +1,276 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use spl_token_2022::{
+    extension::StateWithExtensions, instruction as token_instruction, state::Account as SplAccount,
+};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{LegacyTokensSwapped, SuccessorMintLinked};
+use crate::state::*;
+
+/// Legacy supply not yet swapped: everything minted on the legacy side (which is frozen once
+/// linked) minus everything burned there, by swaps or otherwise.
+fn legacy_circulating(legacy: &StablecoinState) -> Result<u64> {
+    Ok(legacy
+        .total_minted
+        .checked_sub(legacy.total_burned)
+        .ok_or(StablecoinError::MathOverflow)?)
+}
+
+#[derive(Accounts)]
+pub struct LinkSuccessorMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, legacy_stablecoin.mint.as_ref()],
+        bump = legacy_stablecoin.bump,
+        constraint = legacy_stablecoin.authority == authority.key() @ StablecoinError::Unauthorized,
+    )]
+    pub legacy_stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, successor_stablecoin.mint.as_ref()],
+        bump = successor_stablecoin.bump,
+        constraint = successor_stablecoin.authority == authority.key() @ StablecoinError::Unauthorized,
+    )]
+    pub successor_stablecoin: Account<'info, StablecoinState>,
+}
+
+impl<'info> LinkSuccessorMint<'info> {
+    /// Link an SSS-1 stablecoin to a fresh SSS-2 one. Irreversible: legacy minting stays
+    /// paused from here on, and the legacy circulating supply is carried into the successor's
+    /// supply accounting until it is swapped.
+    pub fn link_successor_mint(&mut self) -> Result<()> {
+        apply_link_successor_mint(
+            &mut self.legacy_stablecoin,
+            &mut self.successor_stablecoin,
+            self.authority.key(),
+        )
+    }
+}
+
+/// Shared by the direct link_successor_mint path and admin-action execution (multisig). The
+/// caller checks that `linked_by` is the authority of both stablecoins.
+pub(crate) fn apply_link_successor_mint(
+    legacy_stablecoin: &mut Account<StablecoinState>,
+    successor_stablecoin: &mut Account<StablecoinState>,
+    linked_by: Pubkey,
+) -> Result<()> {
+    let legacy = &*legacy_stablecoin;
+    let successor = &*successor_stablecoin;
+    require!(
+        !legacy.is_sss2()
+            && successor.is_sss2()
+            && legacy.decimals == successor.decimals
+            && successor.total_minted == 0,
+        StablecoinError::InvalidMigrationTarget
+    );
+    require!(
+        !legacy.has_successor() && !successor.has_legacy_mint(),
+        StablecoinError::MigrationAlreadyLinked
+    );
+    let legacy_outstanding = legacy_circulating(legacy)?;
+
+    let legacy_mint = legacy_stablecoin.mint;
+    let successor_mint = successor_stablecoin.mint;
+    legacy_stablecoin.successor_mint = successor_mint;
+    successor_stablecoin.legacy_mint = legacy_mint;
+    successor_stablecoin.legacy_outstanding = legacy_outstanding;
+
+    emit!(SuccessorMintLinked {
+        legacy_stablecoin: legacy_stablecoin.key(),
+        legacy_mint,
+        successor_stablecoin: successor_stablecoin.key(),
+        successor_mint,
+        legacy_outstanding,
+        linked_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SwapLegacy<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, legacy_mint.key().as_ref()],
+        bump = legacy_stablecoin.bump,
+        constraint = legacy_stablecoin.successor_mint == successor_mint.key() @ StablecoinError::MintNotLinked,
+    )]
+    pub legacy_stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Legacy Token-2022 mint
+    #[account(mut)]
+    pub legacy_mint: AccountInfo<'info>,
+
+    /// CHECK: Holder's legacy token account (Token-2022 validates mint, owner and balance)
+    #[account(mut)]
+    pub legacy_token_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, successor_mint.key().as_ref()],
+        bump = successor_stablecoin.bump,
+        constraint = successor_stablecoin.legacy_mint == legacy_mint.key() @ StablecoinError::MintNotLinked,
+    )]
+    pub successor_stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Successor Token-2022 mint
+    #[account(mut)]
+    pub successor_mint: AccountInfo<'info>,
+
+    /// CHECK: Recipient token account for the successor mint (Token-2022 validates the mint)
+    #[account(mut)]
+    pub successor_token_account: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    /// CHECK: Recipient owner's successor blacklist PDA (seeds: ["blacklist", successor_stablecoin,
+    /// recipient_owner]); verified against the recipient owner in the handler
+    pub recipient_blacklist: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient owner's successor allowlist PDA (seeds: ["allowlist", successor_stablecoin,
+    /// recipient_owner]); only consulted when the successor is in allowlist mode
+    pub recipient_allowlist: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapLegacy<'info> {
+    /// Burn legacy tokens (holder signs as owner) and mint the same amount of the successor
+    /// (stablecoin PDA signs as mint authority). The successor's supply is unchanged in every
+    /// SupplyCapMode: legacy_outstanding drops by what its own supply gains, so no cap check.
+    pub fn swap_legacy(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::ZeroAmount);
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !self.legacy_stablecoin.is_paused_for(PAUSE_BURN, now),
+            StablecoinError::Paused
+        );
+        require!(
+            !self.successor_stablecoin.is_paused_for(PAUSE_MINT, now),
+            StablecoinError::Paused
+        );
+        self.check_recipient(now)?;
+
+        invoke(
+            &token_instruction::burn(
+                &self.token_program.key(),
+                &self.legacy_token_account.key(),
+                &self.legacy_mint.key(),
+                &self.holder.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                self.legacy_token_account.to_account_info(),
+                self.legacy_mint.to_account_info(),
+                self.holder.to_account_info(),
+            ],
+        )?;
+
+        let successor_mint = self.successor_mint.key();
+        let signer_seeds: &[&[u8]] = &[
+            STABLECOIN_SEED,
+            successor_mint.as_ref(),
+            &[self.successor_stablecoin.bump],
+        ];
+        invoke_signed(
+            &token_instruction::mint_to(
+                &self.token_program.key(),
+                &successor_mint,
+                &self.successor_token_account.key(),
+                &self.successor_stablecoin.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                self.successor_mint.to_account_info(),
+                self.successor_token_account.to_account_info(),
+                self.successor_stablecoin.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+
+        let legacy = &mut self.legacy_stablecoin;
+        legacy.total_burned = legacy
+            .total_burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let legacy_outstanding = legacy_circulating(legacy)?;
+
+        let successor = &mut self.successor_stablecoin;
+        successor.total_minted = successor
+            .total_minted
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        // Resynced rather than decremented so burns on the legacy side are picked up too
+        successor.legacy_outstanding = legacy_outstanding;
+
+        emit!(LegacyTokensSwapped {
+            legacy_mint: self.legacy_mint.key(),
+            successor_mint,
+            holder: self.holder.key(),
+            amount,
+            legacy_outstanding,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Minting is not a transfer, so the successor's transfer hook never sees the swap: apply
+    /// its recipient rules here instead.
+    fn check_recipient(&self, now: i64) -> Result<()> {
+        let recipient_data = self.successor_token_account.try_borrow_data()?;
+        let recipient_owner = StateWithExtensions::<SplAccount>::unpack(&recipient_data)?
+            .base
+            .owner;
+        drop(recipient_data);
+        let successor_key = self.successor_stablecoin.key();
+
+        let (expected_blacklist, _) = Pubkey::find_program_address(
+            &[BLACKLIST_SEED, successor_key.as_ref(), recipient_owner.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            self.recipient_blacklist.key(),
+            expected_blacklist,
+            StablecoinError::Unauthorized
+        );
+        let blacklisted = self.recipient_blacklist.owner == &crate::ID
+            && self.recipient_blacklist.data_len() > 0
+            && BlacklistEntry::try_deserialize(
+                &mut &self.recipient_blacklist.try_borrow_data()?[..],
+            )?
+            .is_active(now);
+        require!(!blacklisted, StablecoinError::Blacklisted);
+
+        if self.successor_stablecoin.enable_allowlist {
+            let (expected_allowlist, _) = Pubkey::find_program_address(
+                &[ALLOWLIST_SEED, successor_key.as_ref(), recipient_owner.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                self.recipient_allowlist.key(),
+                expected_allowlist,
+                StablecoinError::Unauthorized
+            );
+            let allowlisted = self.recipient_allowlist.owner == &crate::ID
+                && self.recipient_allowlist.data_len() > 0
+                && AllowlistEntry::try_deserialize(
+                    &mut &self.recipient_allowlist.try_borrow_data()?[..],
+                )
+                .is_ok();
+            require!(allowlisted, StablecoinError::NotAllowlisted);
+        }
+
+        Ok(())
+    }
+}
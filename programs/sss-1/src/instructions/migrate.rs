@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{AccountMigrated, StablecoinStateMigrated};
+use crate::state::*;
+use crate::VersionedAccountType;
+
+/// An account rewritten into its current layout, ready to be stored.
+pub struct UpgradedAccount {
+    pub account_type: VersionedAccountType,
+    pub from_version: u8,
+    pub to_version: u8,
+    /// Serialized account, discriminator included
+    pub data: Vec<u8>,
+    /// Size the account must have; never below its current size
+    pub space: usize,
+}
+
+/// Upgrades raw account data of type `T` to the current layout.
+pub fn upgrade_account<T: VersionedAccount>(data: &[u8]) -> Result<UpgradedAccount> {
+    require!(
+        data.get(..8) == Some(T::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let from_version = T::stored_version(data)?;
+    require!(
+        from_version < T::VERSION,
+        StablecoinError::AccountAlreadyMigrated
+    );
+    let mut account = T::decode_version(data, from_version)?;
+    account.upgrade(from_version);
+
+    let mut upgraded = Vec::with_capacity(T::SPACE);
+    account.try_serialize(&mut upgraded)?;
+    Ok(UpgradedAccount {
+        account_type: T::ACCOUNT_TYPE,
+        from_version,
+        to_version: T::VERSION,
+        data: upgraded,
+        space: T::SPACE.max(data.len()),
+    })
+}
+
+/// Upgrades a StablecoinState in either the unversioned layout it was deployed with (which has
+/// its own discriminator and field order) or an older versioned one.
+pub fn upgrade_stablecoin_state(data: &[u8]) -> Result<UpgradedAccount> {
+    let discriminator = data
+        .get(..8)
+        .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+    if discriminator == StablecoinState::DISCRIMINATOR {
+        return upgrade_account::<StablecoinState>(data);
+    }
+    require!(
+        discriminator == LEGACY_STABLECOIN_STATE_DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let state = StablecoinState::decode_v0(data)?;
+    let mut upgraded = Vec::with_capacity(StablecoinState::SPACE);
+    state.try_serialize(&mut upgraded)?;
+    Ok(UpgradedAccount {
+        account_type: VersionedAccountType::StablecoinState,
+        from_version: 0,
+        to_version: STABLECOIN_STATE_VERSION,
+        data: upgraded,
+        space: StablecoinState::SPACE.max(data.len()),
+    })
+}
+
+/// Grows `account` to `upgraded.space` (payer tops up rent) and stores the upgraded data.
+fn store_upgraded<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    upgraded: &UpgradedAccount,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(upgraded.space);
+    if required > account.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            required - account.lamports(),
+        )?;
+    }
+    if upgraded.space > account.data_len() {
+        account.resize(upgraded.space)?;
+    }
+    account.try_borrow_mut_data()?[..upgraded.data.len()].copy_from_slice(&upgraded.data);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateStablecoinState<'info> {
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: StablecoinState PDA in an older layout; owner and discriminator are checked in
+    /// the handler because `Account<StablecoinState>` rejects older layouts
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub stablecoin: UncheckedAccount<'info>,
+
+    /// CHECK: Token-2022 mint; only used for the stablecoin PDA seeds
+    pub mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateStablecoinState<'info> {
+    /// Rewrite an older StablecoinState into the current layout, reallocating (payer tops up
+    /// rent). Permissionless: the result is fully determined by the old account.
+    pub fn migrate_stablecoin_state(&mut self) -> Result<()> {
+        let info = self.stablecoin.to_account_info();
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+
+        let upgraded = upgrade_stablecoin_state(&info.try_borrow_data()?)?;
+        store_upgraded(&info, &self.payer, &self.system_program, &upgraded)?;
+
+        emit!(StablecoinStateMigrated {
+            stablecoin: info.key(),
+            from_version: upgraded.from_version,
+            to_version: upgraded.to_version,
+            migrated_by: self.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: StablecoinState, RoleAccount, MinterInfo, BlacklistEntry or SupplyCap owned by this
+    /// program, in an older layout; the type is taken from the discriminator in the handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    /// Upgrade any versioned account to its current layout. Permissionless: only accounts this
+    /// program wrote are accepted, and the result is fully determined by their data.
+    pub fn migrate_account(&mut self) -> Result<()> {
+        let info = self.account.to_account_info();
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+
+        let upgraded = {
+            let data = info.try_borrow_data()?;
+            let discriminator = data
+                .get(..8)
+                .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+            if discriminator == StablecoinState::DISCRIMINATOR
+                || discriminator == LEGACY_STABLECOIN_STATE_DISCRIMINATOR
+            {
+                upgrade_stablecoin_state(&data)?
+            } else if discriminator == RoleAccount::DISCRIMINATOR {
+                upgrade_account::<RoleAccount>(&data)?
+            } else if discriminator == MinterInfo::DISCRIMINATOR {
+                upgrade_account::<MinterInfo>(&data)?
+            } else if discriminator == BlacklistEntry::DISCRIMINATOR {
+                upgrade_account::<BlacklistEntry>(&data)?
+            } else if discriminator == SupplyCap::DISCRIMINATOR {
+                upgrade_account::<SupplyCap>(&data)?
+            } else {
+                return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+        };
+        store_upgraded(&info, &self.payer, &self.system_program, &upgraded)?;
+
+        emit!(AccountMigrated {
+            account: info.key(),
+            account_type: upgraded.account_type,
+            from_version: upgraded.from_version,
+            to_version: upgraded.to_version,
+            migrated_by: self.payer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
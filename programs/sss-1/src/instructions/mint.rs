@@ -5,7 +5,9 @@ use spl_token_2022::instruction as token_instruction;
 use crate::constants::*;
 use crate::error::StablecoinError;
 use crate::events::TokensMinted;
+use crate::instructions::current_supply;
 use crate::state::*;
+use crate::SupplyCapMode;
 use anchor_lang::Discriminator;
 
 #[derive(Accounts)]
@@ -44,32 +46,50 @@ pub struct MintTokens<'info> {
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
 
-    /// Optional: Supply cap PDA. Pass program_id if no cap is set (read-only sentinel).
-    /// CHECK: When not program_id, must be the SupplyCap PDA for this stablecoin.
-    /// Using UncheckedAccount to avoid mut constraint (program_id cannot be writable).
+    /// CHECK: SupplyCap PDA for this stablecoin, passed even when no cap was ever set (then it
+    /// holds no data). Read manually because `Account<SupplyCap>` rejects the missing account.
+    #[account(
+        seeds = [SUPPLY_CAP_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
     pub supply_cap: UncheckedAccount<'info>,
 }
 
 impl<'info> MintTokens<'info> {
     pub fn mint_tokens(&mut self, amount: u64) -> Result<()> {
         require!(amount > 0, StablecoinError::ZeroAmount);
-        require!(!self.stablecoin.paused, StablecoinError::Paused);
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.stablecoin.is_paused_for(PAUSE_MINT, now), StablecoinError::Paused);
         require!(self.role.roles.is_minter, StablecoinError::Unauthorized);
 
         // NOTE: Blacklist enforcement for recipients is delegated to the transfer hook
         // (SSS-2). Direct mint does not check blacklist. Tokens minted to blacklisted
         // addresses cannot be transferred out (hook blocks transfers) but mint succeeds.
 
-        // Enforce per-minter quota
+        // Enforce per-minter limit: lifetime quota, or per-window allowance in periodic mode.
+        // The lifetime counter is kept in both modes for audit.
         let minter_info = &mut self.minter_info;
         let new_minted = minter_info
             .minted_amount
             .checked_add(amount)
             .ok_or(StablecoinError::MathOverflow)?;
-        require!(
-            new_minted <= minter_info.quota,
-            StablecoinError::QuotaExceeded
-        );
+        let (window_start, new_window_minted) = if minter_info.is_periodic() {
+            let new_window_minted = minter_info
+                .minted_in_window(now)
+                .checked_add(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                new_window_minted <= minter_info.window_limit,
+                StablecoinError::AllowanceExceeded
+            );
+            (minter_info.current_window_start(now), new_window_minted)
+        } else {
+            require!(
+                new_minted <= minter_info.quota,
+                StablecoinError::QuotaExceeded
+            );
+            (minter_info.window_start, minter_info.window_minted)
+        };
 
         let stablecoin_key = self.stablecoin.key();
         let new_total_minted = self
@@ -79,17 +99,12 @@ impl<'info> MintTokens<'info> {
             .ok_or(StablecoinError::MathOverflow)?;
 
         // Validate supply cap BEFORE mint CPI (fail-fast, no state changes).
-        // Manual deserialization: supply_cap is UncheckedAccount (not writable; program_id
-        // sentinel when no cap). We read and validate without mutability. Layout:
-        // [8-byte discriminator][8-byte cap]. If SupplyCap layout changes, update here.
-        if self.supply_cap.key() != crate::ID {
-            let (expected_pda, _) =
-                Pubkey::find_program_address(&[SUPPLY_CAP_SEED, stablecoin_key.as_ref()], &crate::ID);
-            require_eq!(
-                self.supply_cap.key(),
-                expected_pda,
-                StablecoinError::Unauthorized
-            );
+        // Manual deserialization: supply_cap is an UncheckedAccount because it does not exist
+        // until a cap is first set. The address is pinned to the PDA above, so a minter cannot
+        // skip an existing cap. Layout:
+        // [8-byte discriminator][8-byte cap][1-byte bump][1-byte mode, optional].
+        // If SupplyCap layout changes, update here.
+        if !self.supply_cap.data_is_empty() {
             require_eq!(
                 self.supply_cap.owner,
                 &crate::ID,
@@ -102,7 +117,7 @@ impl<'info> MintTokens<'info> {
             );
             // Verify Anchor discriminator (defense-in-depth; Audit 4)
             require!(
-                cap_data.len() >= 8 && cap_data[0..8].eq(SupplyCap::DISCRIMINATOR),
+                cap_data[0..8].eq(SupplyCap::DISCRIMINATOR),
                 StablecoinError::Unauthorized
             );
             let cap = u64::from_le_bytes(
@@ -110,8 +125,20 @@ impl<'info> MintTokens<'info> {
                     .try_into()
                     .map_err(|_| StablecoinError::MathOverflow)?,
             );
-            if cap != NO_SUPPLY_CAP && new_total_minted > cap {
-                return Err(StablecoinError::SupplyCapExceeded.into());
+            // Caps created before modes existed have no mode byte: LifetimeMinted.
+            let mode = match cap_data.get(SUPPLY_CAP_MODE_OFFSET) {
+                Some(byte) => {
+                    SupplyCapMode::from_u8(*byte).ok_or(StablecoinError::Unauthorized)?
+                }
+                None => SupplyCapMode::LifetimeMinted,
+            };
+            drop(cap_data);
+            if cap != NO_SUPPLY_CAP {
+                let mint_info = self.mint.to_account_info();
+                let supply_after = current_supply(mode, &self.stablecoin, Some(&mint_info))?
+                    .checked_add(amount)
+                    .ok_or(StablecoinError::MathOverflow)?;
+                require!(supply_after <= cap, StablecoinError::SupplyCapExceeded);
             }
         }
 
@@ -138,6 +165,8 @@ impl<'info> MintTokens<'info> {
 
         // Update quota tracking and global stats
         minter_info.minted_amount = new_minted;
+        minter_info.window_start = window_start;
+        minter_info.window_minted = new_window_minted;
         let stablecoin = &mut self.stablecoin;
         stablecoin.total_minted = new_total_minted;
 
@@ -147,7 +176,7 @@ impl<'info> MintTokens<'info> {
             recipient: self.recipient_token_account.key(),
             amount,
             total_minted: stablecoin.total_minted,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
         });
 
         Ok(())
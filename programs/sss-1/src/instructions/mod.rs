@@ -8,7 +8,18 @@ pub mod update_minter;
 pub mod transfer_authority;
 pub mod update_supply_cap;
 pub mod blacklist;
+pub mod allowlist;
 pub mod seize;
+pub mod multisig;
+pub mod timelock;
+pub mod redemption;
+pub mod transfer_hook_accounts;
+pub mod treasury;
+pub mod velocity_limits;
+pub mod reporting_threshold;
+pub mod migrate;
+pub mod legacy_swap;
+pub mod update_metadata;
 
 pub use initialize_stablecoin::*;
 pub use mint::*;
@@ -20,4 +31,15 @@ pub use update_minter::*;
 pub use transfer_authority::*;
 pub use update_supply_cap::*;
 pub use blacklist::*;
+pub use allowlist::*;
 pub use seize::*;
+pub use multisig::*;
+pub use timelock::*;
+pub use redemption::*;
+pub use transfer_hook_accounts::*;
+pub use treasury::*;
+pub use velocity_limits::*;
+pub use reporting_threshold::*;
+pub use migrate::*;
+pub use legacy_swap::*;
+pub use update_metadata::*;
@@ -0,0 +1,644 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{
+    AdminProposalApproved, AdminProposalCreated, AdminProposalExecuted, MultisigCreated,
+    MultisigUpdated,
+};
+use crate::instructions::{
+    apply_accept_authority, apply_cancel_authority_transfer, apply_cancel_change,
+    apply_config_change, apply_extend_emergency_pause, apply_initialize_transfer_hook_accounts,
+    apply_link_successor_mint, apply_nominate_authority, apply_remove_minter, apply_revoke_role,
+    apply_update_metadata, apply_update_transfer_hook_accounts, is_sensitive_change,
+    record_queued_change,
+};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MultisigConfig::INIT_SPACE,
+        seeds = [MULTISIG_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, stablecoin.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [MULTISIG_SEED, stablecoin.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    /// Any multisig member; pays rent for role / minter / supply cap PDAs created by the action
+    /// and receives the rent of PDAs it closes
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, stablecoin.key().as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    /// Required for UpdateRoles / RevokeRole
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = RoleAccount::LEN,
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), proposal.action.target().as_ref()],
+        bump,
+    )]
+    pub role: Option<Account<'info, RoleAccount>>,
+
+    /// Required for UpdateMinter / UpdateMinterAllowance / RemoveMinter
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = MinterInfo::LEN,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), proposal.action.target().as_ref()],
+        bump,
+    )]
+    pub minter_info: Option<Account<'info, MinterInfo>>,
+
+    /// Required for UpdateSupplyCap / UpdateSupplyCapMode
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + SupplyCap::INIT_SPACE,
+        seeds = [SUPPLY_CAP_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
+    pub supply_cap: Option<Account<'info, SupplyCap>>,
+
+    /// Required when the action is sensitive and the stablecoin is timelocked
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + QueuedChange::INIT_SPACE,
+        seeds = [QUEUED_CHANGE_SEED, stablecoin.key().as_ref(), &stablecoin.queued_change_count.to_le_bytes()],
+        bump,
+    )]
+    pub queued_change: Option<Account<'info, QueuedChange>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Token-2022 mint; required for supply cap changes in MintSupply mode, metadata
+    /// updates and hook account changes (checked against stablecoin.mint where used)
+    #[account(mut)]
+    pub mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be the Token-2022 program; required for UpdateMetadata
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: Option<UncheckedAccount<'info>>,
+
+    /// Required for LinkSuccessorMint. Boxed to keep this already large context off the stack.
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, successor_stablecoin.mint.as_ref()],
+        bump = successor_stablecoin.bump,
+    )]
+    pub successor_stablecoin: Option<Box<Account<'info, StablecoinState>>>,
+
+    /// CHECK: ExtraAccountMetaList PDA; required for Initialize/UpdateTransferHookAccounts
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, stablecoin.mint.as_ref()],
+        bump,
+        seeds::program = SSS_TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_metas: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be the official SSS-2 transfer hook program; required with
+    /// extra_account_metas
+    #[account(address = SSS_TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// Required for CancelChange; must be the change the action names
+    #[account(mut)]
+    pub cancelled_change: Option<Box<Account<'info, QueuedChange>>>,
+
+    /// CHECK: Rent refund destination for CancelChange; checked against
+    /// cancelled_change.rent_payer
+    #[account(mut)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Required for UpdateTreasury to a non-default treasury; checked in
+    /// apply_update_treasury
+    pub treasury_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAdminProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.stablecoin.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+fn validate_multisig_config(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= MAX_MULTISIG_MEMBERS,
+        StablecoinError::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        StablecoinError::InvalidMultisigConfig
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            *member != Pubkey::default() && !members[..i].contains(member),
+            StablecoinError::InvalidMultisigConfig
+        );
+    }
+    Ok(())
+}
+
+impl<'info> CreateMultisig<'info> {
+    pub fn create_multisig(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        bumps: CreateMultisigBumps,
+    ) -> Result<()> {
+        validate_multisig_config(&members, threshold)?;
+
+        // NOTE: Creating the multisig does not hand it control. The current authority then
+        // nominates the multisig address and the members execute an AcceptAuthority proposal.
+        self.multisig.set_inner(MultisigConfig {
+            stablecoin: self.stablecoin.key(),
+            members: members.clone(),
+            threshold,
+            proposal_count: 0,
+            config_version: 0,
+            bump: bumps.multisig,
+        });
+
+        emit!(MultisigCreated {
+            stablecoin: self.stablecoin.key(),
+            multisig: self.multisig.key(),
+            members,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> ProposeAdminAction<'info> {
+    pub fn propose_admin_action(
+        &mut self,
+        action: AdminAction,
+        ttl_seconds: i64,
+        bumps: ProposeAdminActionBumps,
+    ) -> Result<()> {
+        let member_index = self
+            .multisig
+            .member_index(&self.proposer.key())
+            .ok_or(StablecoinError::NotMultisigMember)?;
+        require!(
+            ttl_seconds > 0 && ttl_seconds <= MAX_PROPOSAL_TTL,
+            StablecoinError::InvalidMultisigConfig
+        );
+        if let AdminAction::UpdateMultisig { members, threshold } = &action {
+            validate_multisig_config(members, *threshold)?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = now
+            .checked_add(ttl_seconds)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let id = self.multisig.proposal_count;
+
+        // Proposer's approval is implicit.
+        self.proposal.set_inner(AdminProposal {
+            multisig: self.multisig.key(),
+            id,
+            proposer: self.proposer.key(),
+            action: action.clone(),
+            approvals: 1u16 << member_index,
+            config_version: self.multisig.config_version,
+            created_at: now,
+            expires_at,
+            executed: false,
+            bump: bumps.proposal,
+        });
+
+        self.multisig.proposal_count = id.checked_add(1).ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(AdminProposalCreated {
+            stablecoin: self.stablecoin.key(),
+            multisig: self.multisig.key(),
+            proposal: self.proposal.key(),
+            id,
+            proposer: self.proposer.key(),
+            action,
+            expires_at,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+/// Shared liveness checks for approve and execute. Replay protection: a proposal executes
+/// at most once, only before expiry, and only under the member set it was created with.
+fn require_proposal_open(
+    multisig: &MultisigConfig,
+    proposal: &AdminProposal,
+    now: i64,
+) -> Result<()> {
+    require!(!proposal.executed, StablecoinError::ProposalAlreadyExecuted);
+    require!(!proposal.is_expired(now), StablecoinError::ProposalExpired);
+    require!(
+        proposal.config_version == multisig.config_version,
+        StablecoinError::ProposalStale
+    );
+    Ok(())
+}
+
+impl<'info> ApproveAdminAction<'info> {
+    pub fn approve_admin_action(&mut self) -> Result<()> {
+        let member_index = self
+            .multisig
+            .member_index(&self.member.key())
+            .ok_or(StablecoinError::NotMultisigMember)?;
+        let now = Clock::get()?.unix_timestamp;
+        require_proposal_open(&self.multisig, &self.proposal, now)?;
+
+        let bit = 1u16 << member_index;
+        require!(
+            self.proposal.approvals & bit == 0,
+            StablecoinError::AlreadyApproved
+        );
+        self.proposal.approvals |= bit;
+
+        emit!(AdminProposalApproved {
+            stablecoin: self.stablecoin.key(),
+            proposal: self.proposal.key(),
+            id: self.proposal.id,
+            approver: self.member.key(),
+            approvals: self.proposal.approval_count() as u8,
+            threshold: self.multisig.threshold,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> ExecuteAdminAction<'info> {
+    /// Mint, extra-account-metas PDA and hook program for the hook account actions.
+    fn hook_accounts(
+        &self,
+    ) -> Result<(AccountInfo<'info>, AccountInfo<'info>, AccountInfo<'info>)> {
+        let mint = self
+            .mint
+            .as_ref()
+            .ok_or(StablecoinError::MissingAdminAccount)?;
+        let extra_account_metas = self
+            .extra_account_metas
+            .as_ref()
+            .ok_or(StablecoinError::MissingAdminAccount)?;
+        let transfer_hook_program = self
+            .transfer_hook_program
+            .as_ref()
+            .ok_or(StablecoinError::MissingAdminAccount)?;
+        Ok((
+            mint.to_account_info(),
+            extra_account_metas.to_account_info(),
+            transfer_hook_program.to_account_info(),
+        ))
+    }
+
+    pub fn execute_admin_action(&mut self, bumps: &ExecuteAdminActionBumps) -> Result<()> {
+        require!(
+            self.multisig.member_index(&self.executor.key()).is_some(),
+            StablecoinError::NotMultisigMember
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require_proposal_open(&self.multisig, &self.proposal, now)?;
+        require!(
+            self.proposal.approval_count() >= self.multisig.threshold as u32,
+            StablecoinError::ThresholdNotMet
+        );
+
+        // Mark executed before applying so the action can never be replayed.
+        self.proposal.executed = true;
+
+        let multisig_key = self.multisig.key();
+        let action = self.proposal.action.clone();
+
+        // Every action except AcceptAuthority and UpdateMultisig requires the multisig
+        // to already be the stablecoin authority.
+        match &action {
+            AdminAction::AcceptAuthority | AdminAction::UpdateMultisig { .. } => {}
+            _ => require_keys_eq!(
+                self.stablecoin.authority,
+                multisig_key,
+                StablecoinError::Unauthorized
+            ),
+        }
+
+        match action.clone() {
+            AdminAction::UpdateRoles { .. }
+            | AdminAction::UpdateMinter { .. }
+            | AdminAction::UpdateSupplyCap { .. }
+            | AdminAction::UpdateTimelock { .. }
+            | AdminAction::UpdateMinterAllowance { .. }
+            | AdminAction::UpdateSupplyCapMode { .. }
+            | AdminAction::UpdateTreasury { .. } => {
+                let sensitive = is_sensitive_change(
+                    &action,
+                    &self.stablecoin,
+                    &self.role,
+                    &self.minter_info,
+                    &self.supply_cap,
+                )?;
+                if sensitive && self.stablecoin.is_timelocked() {
+                    // Threshold approval queues the change; it applies after the delay.
+                    let queued_change = self
+                        .queued_change
+                        .as_mut()
+                        .ok_or(StablecoinError::MissingAdminAccount)?;
+                    let bump = bumps
+                        .queued_change
+                        .ok_or(StablecoinError::MissingAdminAccount)?;
+                    record_queued_change(
+                        &mut self.stablecoin,
+                        queued_change,
+                        action.clone(),
+                        multisig_key,
+                        self.executor.key(),
+                        bump,
+                    )?;
+                } else {
+                    let mint = self.mint.as_ref().map(|m| m.to_account_info());
+                    let treasury_account =
+                        self.treasury_account.as_ref().map(|a| a.to_account_info());
+                    apply_config_change(
+                        &action,
+                        &mut self.stablecoin,
+                        &mut self.role,
+                        bumps.role,
+                        &mut self.minter_info,
+                        bumps.minter_info,
+                        &mut self.supply_cap,
+                        bumps.supply_cap,
+                        mint.as_ref(),
+                        treasury_account.as_ref(),
+                        multisig_key,
+                    )?;
+                }
+            }
+            AdminAction::RemoveMinter { minter } => {
+                let minter_info = self
+                    .minter_info
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                apply_remove_minter(&mut self.stablecoin, minter_info, minter, multisig_key)?;
+                minter_info.close(self.executor.to_account_info())?;
+            }
+            AdminAction::RevokeRole { holder } => {
+                let role = self
+                    .role
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                apply_revoke_role(&self.stablecoin, role, holder, multisig_key)?;
+                role.close(self.executor.to_account_info())?;
+            }
+            AdminAction::NominateAuthority { new_authority } => {
+                apply_nominate_authority(&mut self.stablecoin, new_authority)?;
+            }
+            AdminAction::AcceptAuthority => {
+                apply_accept_authority(&mut self.stablecoin, multisig_key)?;
+            }
+            AdminAction::CancelAuthorityTransfer => {
+                apply_cancel_authority_transfer(&mut self.stablecoin)?;
+            }
+            AdminAction::UpdateMetadata { params } => {
+                let mint = self
+                    .mint
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                let token_program = self
+                    .token_program
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                apply_update_metadata(
+                    &mut self.stablecoin,
+                    &mint.to_account_info(),
+                    &token_program.to_account_info(),
+                    &self.executor.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    params,
+                    multisig_key,
+                )?;
+            }
+            AdminAction::LinkSuccessorMint { successor_mint } => {
+                let successor = self
+                    .successor_stablecoin
+                    .as_mut()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                require_keys_eq!(
+                    successor.mint,
+                    successor_mint,
+                    StablecoinError::Unauthorized
+                );
+                require_keys_eq!(
+                    successor.authority,
+                    multisig_key,
+                    StablecoinError::Unauthorized
+                );
+                apply_link_successor_mint(&mut self.stablecoin, successor, multisig_key)?;
+            }
+            AdminAction::InitializeTransferHookAccounts => {
+                let (mint, extra_account_metas, transfer_hook_program) = self.hook_accounts()?;
+                apply_initialize_transfer_hook_accounts(
+                    &self.stablecoin,
+                    &mint,
+                    &extra_account_metas,
+                    &transfer_hook_program,
+                    &self.executor.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    multisig_key,
+                )?;
+            }
+            AdminAction::UpdateTransferHookAccounts => {
+                let (mint, extra_account_metas, transfer_hook_program) = self.hook_accounts()?;
+                apply_update_transfer_hook_accounts(
+                    &self.stablecoin,
+                    &mint,
+                    &extra_account_metas,
+                    &transfer_hook_program,
+                    &self.executor.to_account_info(),
+                    &self.system_program.to_account_info(),
+                    multisig_key,
+                )?;
+            }
+            AdminAction::ExtendEmergencyPause { duration } => {
+                apply_extend_emergency_pause(&mut self.stablecoin, duration, multisig_key)?;
+            }
+            AdminAction::CancelChange { change } => {
+                let queued_change = self
+                    .cancelled_change
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                let rent_payer = self
+                    .rent_payer
+                    .as_ref()
+                    .ok_or(StablecoinError::MissingAdminAccount)?;
+                require_keys_eq!(queued_change.key(), change, StablecoinError::Unauthorized);
+                apply_cancel_change(
+                    &self.stablecoin,
+                    queued_change,
+                    &rent_payer.to_account_info(),
+                    multisig_key,
+                )?;
+            }
+            AdminAction::UpdateMultisig { members, threshold } => {
+                validate_multisig_config(&members, threshold)?;
+                self.multisig.members = members.clone();
+                self.multisig.threshold = threshold;
+                // Invalidates every other outstanding proposal (approval bitmasks are
+                // indexed by member position).
+                self.multisig.config_version = self
+                    .multisig
+                    .config_version
+                    .checked_add(1)
+                    .ok_or(StablecoinError::MathOverflow)?;
+
+                emit!(MultisigUpdated {
+                    stablecoin: self.stablecoin.key(),
+                    multisig: multisig_key,
+                    members,
+                    threshold,
+                    config_version: self.multisig.config_version,
+                    timestamp: now,
+                });
+            }
+        }
+
+        emit!(AdminProposalExecuted {
+            stablecoin: self.stablecoin.key(),
+            proposal: self.proposal.key(),
+            id: self.proposal.id,
+            executor: self.executor.key(),
+            action,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> CloseAdminProposal<'info> {
+    pub fn close_admin_proposal(&mut self) -> Result<()> {
+        // Only finished proposals can be closed; open ones must expire first.
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.proposal.executed
+                || self.proposal.is_expired(now)
+                || self.proposal.config_version != self.multisig.config_version,
+            StablecoinError::ProposalStillOpen
+        );
+        Ok(())
+    }
+}
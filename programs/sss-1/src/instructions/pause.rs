@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::{StablecoinPaused, StablecoinUnpaused};
+use crate::events::{
+    EmergencyPauseExtended, EmergencyPauseTriggered, PauseScopesUpdated, StablecoinPaused,
+    StablecoinUnpaused,
+};
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -23,6 +26,39 @@ pub struct PauseUnpause<'info> {
     pub role: Account<'info, RoleAccount>,
 }
 
+#[derive(Accounts)]
+pub struct EmergencyPause<'info> {
+    /// Guardian, or any holder of the pauser role
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// Caller's role; omit (pass the program ID) when calling as guardian
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), caller.key().as_ref()],
+        bump,
+    )]
+    pub role: Option<Account<'info, RoleAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendEmergencyPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
 impl<'info> PauseUnpause<'info> {
     pub fn pause(&mut self) -> Result<()> {
         require!(self.role.roles.is_pauser, StablecoinError::Unauthorized);
@@ -42,6 +78,8 @@ impl<'info> PauseUnpause<'info> {
         require!(self.role.roles.is_pauser, StablecoinError::Unauthorized);
 
         self.stablecoin.paused = false;
+        // Also lifts an emergency pause early
+        self.stablecoin.paused_until = 0;
 
         emit!(StablecoinUnpaused {
             stablecoin: self.stablecoin.key(),
@@ -51,4 +89,120 @@ impl<'info> PauseUnpause<'info> {
 
         Ok(())
     }
+
+    /// Pauses only the given scopes (PAUSE_* bitmask), e.g. stop issuance while
+    /// redemptions and transfers continue.
+    pub fn pause_scoped(&mut self, scopes: u8) -> Result<()> {
+        self.set_scopes(scopes, true)
+    }
+
+    pub fn unpause_scoped(&mut self, scopes: u8) -> Result<()> {
+        self.set_scopes(scopes, false)
+    }
+
+    fn set_scopes(&mut self, scopes: u8, paused: bool) -> Result<()> {
+        require!(self.role.roles.is_pauser, StablecoinError::Unauthorized);
+        require!(
+            scopes != 0 && scopes & !PAUSE_ALL_SCOPES == 0,
+            StablecoinError::InvalidPauseScope
+        );
+
+        if paused {
+            self.stablecoin.pause_flags |= scopes;
+        } else {
+            self.stablecoin.pause_flags &= !scopes;
+        }
+
+        emit!(PauseScopesUpdated {
+            stablecoin: self.stablecoin.key(),
+            scopes,
+            paused,
+            pause_flags: self.stablecoin.pause_flags,
+            updated_by: self.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> EmergencyPause<'info> {
+    /// Pauses everything until `now + duration`, after which the pause lifts on its own.
+    /// Only the authority can extend it (extend_emergency_pause) or trigger a new one within
+    /// EMERGENCY_PAUSE_COOLDOWN of the last one ending, so a guardian or pauser key cannot keep
+    /// the coin paused indefinitely.
+    pub fn emergency_pause(&mut self, duration: i64) -> Result<()> {
+        let caller = self.caller.key();
+        let is_guardian =
+            self.stablecoin.guardian != Pubkey::default() && caller == self.stablecoin.guardian;
+        let is_pauser = self.role.as_ref().is_some_and(|r| r.roles.is_pauser);
+        require!(is_guardian || is_pauser, StablecoinError::Unauthorized);
+        require!(
+            duration > 0 && duration <= MAX_EMERGENCY_PAUSE,
+            StablecoinError::InvalidPauseDuration
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !self.stablecoin.is_emergency_paused(now),
+            StablecoinError::EmergencyPauseActive
+        );
+        require!(
+            caller == self.stablecoin.authority || !self.stablecoin.is_emergency_cooldown(now),
+            StablecoinError::EmergencyPauseCooldown
+        );
+
+        self.stablecoin.paused_until = now
+            .checked_add(duration)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(EmergencyPauseTriggered {
+            stablecoin: self.stablecoin.key(),
+            triggered_by: caller,
+            duration,
+            paused_until: self.stablecoin.paused_until,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> ExtendEmergencyPause<'info> {
+    pub fn extend_emergency_pause(&mut self, duration: i64) -> Result<()> {
+        apply_extend_emergency_pause(&mut self.stablecoin, duration, self.authority.key())
+    }
+}
+
+/// Shared by the direct extend_emergency_pause path and admin-action execution (multisig).
+pub(crate) fn apply_extend_emergency_pause(
+    stablecoin: &mut Account<StablecoinState>,
+    duration: i64,
+    extended_by: Pubkey,
+) -> Result<()> {
+    require!(
+        duration > 0 && duration <= MAX_EMERGENCY_PAUSE,
+        StablecoinError::InvalidPauseDuration
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        stablecoin.is_emergency_paused(now),
+        StablecoinError::EmergencyPauseNotActive
+    );
+
+    stablecoin.paused_until = stablecoin
+        .paused_until
+        .checked_add(duration)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit!(EmergencyPauseExtended {
+        stablecoin: stablecoin.key(),
+        extended_by,
+        duration,
+        paused_until: stablecoin.paused_until,
+        timestamp: now,
+    });
+
+    Ok(())
 }
@@ -0,0 +1,415 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{self, CreateAccount};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    instruction as token_instruction, onchain,
+    state::{Account as SplAccount, Mint as SplMint},
+};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{RedemptionFulfilled, RedemptionRejected, RedemptionRequested};
+use crate::state::*;
+
+/// Holder escrows tokens against an off-chain redemption reference.
+/// remaining_accounts: transfer hook accounts when the mint has a transfer hook
+/// (hook program, extra-account-metas, sss-token program, stablecoin, source / dest / authority
+/// blacklist).
+///
+/// Redemption never checks the registered treasury: the escrow is a per-request PDA owned by
+/// the stablecoin, and its balance is either burned (fulfill) or refunded to the recorded
+/// source account (reject). No path sends escrowed tokens to a caller-chosen destination.
+#[derive(Accounts)]
+#[instruction(amount: u64, reference_hash: [u8; 32])]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint (bound to stablecoin via seeds)
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Requester's token account (Token-2022 validates owner and balance)
+    #[account(mut)]
+    pub source_token_account: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + RedemptionRequest::INIT_SPACE,
+        seeds = [REDEMPTION_SEED, stablecoin.key().as_ref(), requester.key().as_ref(), reference_hash.as_ref()],
+        bump,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// CHECK: Escrow token account PDA, created and initialized in the handler.
+    /// Owned (token authority) by the stablecoin PDA.
+    #[account(
+        mut,
+        seeds = [REDEMPTION_ESCROW_SEED, redemption_request.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FulfillRedemption<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), burner.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [REDEMPTION_SEED, stablecoin.key().as_ref(), redemption_request.requester.as_ref(), redemption_request.reference_hash.as_ref()],
+        bump = redemption_request.bump,
+        has_one = stablecoin,
+        has_one = requester,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// CHECK: Escrow token account PDA
+    #[account(
+        mut,
+        seeds = [REDEMPTION_ESCROW_SEED, redemption_request.key().as_ref()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub escrow_token_account: AccountInfo<'info>,
+
+    /// CHECK: Receives request and escrow rent; validated by has_one on redemption_request
+    #[account(mut)]
+    pub requester: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Refunds the escrow to the original source token account.
+/// remaining_accounts: transfer hook accounts when the mint has a transfer hook.
+#[derive(Accounts)]
+pub struct RejectRedemption<'info> {
+    pub burner: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), burner.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    /// CHECK: Token-2022 mint
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [REDEMPTION_SEED, stablecoin.key().as_ref(), redemption_request.requester.as_ref(), redemption_request.reference_hash.as_ref()],
+        bump = redemption_request.bump,
+        has_one = stablecoin,
+        has_one = requester,
+        has_one = source_token_account,
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    /// CHECK: Escrow token account PDA
+    #[account(
+        mut,
+        seeds = [REDEMPTION_ESCROW_SEED, redemption_request.key().as_ref()],
+        bump = redemption_request.escrow_bump,
+    )]
+    pub escrow_token_account: AccountInfo<'info>,
+
+    /// CHECK: Refund destination; validated by has_one on redemption_request
+    #[account(mut)]
+    pub source_token_account: AccountInfo<'info>,
+
+    /// CHECK: Receives request and escrow rent; validated by has_one on redemption_request
+    #[account(mut)]
+    pub requester: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> RequestRedemption<'info> {
+    pub fn request_redemption(
+        &mut self,
+        amount: u64,
+        reference_hash: [u8; 32],
+        bumps: RequestRedemptionBumps,
+        hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::ZeroAmount);
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.stablecoin.is_paused_for(PAUSE_BURN, now), StablecoinError::Paused);
+
+        let stablecoin_key = self.stablecoin.key();
+        let mint_key = self.mint.key();
+        let request_key = self.redemption_request.key();
+
+        // 1. Create the escrow token account at its PDA, sized for the mint's extensions
+        let escrow_space = {
+            let mint_data = self.mint.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+            let account_extensions =
+                ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+            ExtensionType::try_calculate_account_len::<SplAccount>(&account_extensions)
+                .map_err(|_| StablecoinError::MathOverflow)?
+        };
+        let escrow_seeds: &[&[u8]] = &[
+            REDEMPTION_ESCROW_SEED,
+            request_key.as_ref(),
+            &[bumps.escrow_token_account],
+        ];
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.requester.to_account_info(),
+                    to: self.escrow_token_account.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            Rent::get()?.minimum_balance(escrow_space),
+            escrow_space as u64,
+            &self.token_program.key(),
+        )?;
+        invoke(
+            &token_instruction::initialize_account3(
+                &self.token_program.key(),
+                &self.escrow_token_account.key(),
+                &mint_key,
+                &stablecoin_key,
+            )?,
+            &[
+                self.escrow_token_account.to_account_info(),
+                self.mint.to_account_info(),
+            ],
+        )?;
+
+        // SSS-2 mints default new accounts to frozen; the escrow must accept the deposit.
+        let stablecoin_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+        if self.stablecoin.default_account_frozen {
+            invoke_signed(
+                &token_instruction::thaw_account(
+                    &self.token_program.key(),
+                    &self.escrow_token_account.key(),
+                    &mint_key,
+                    &stablecoin_key,
+                    &[],
+                )?,
+                &[
+                    self.escrow_token_account.to_account_info(),
+                    self.mint.to_account_info(),
+                    self.stablecoin.to_account_info(),
+                ],
+                &[stablecoin_seeds],
+            )?;
+        }
+
+        // 2. Move the tokens into escrow — requester signs as owner
+        onchain::invoke_transfer_checked(
+            &self.token_program.key(),
+            self.source_token_account.to_account_info(),
+            self.mint.to_account_info(),
+            self.escrow_token_account.to_account_info(),
+            self.requester.to_account_info(),
+            hook_accounts,
+            amount,
+            self.stablecoin.decimals,
+            &[],
+        )?;
+
+        // 3. Record the request
+        self.redemption_request.set_inner(RedemptionRequest {
+            stablecoin: stablecoin_key,
+            requester: self.requester.key(),
+            source_token_account: self.source_token_account.key(),
+            amount,
+            reference_hash,
+            requested_at: now,
+            bump: bumps.redemption_request,
+            escrow_bump: bumps.escrow_token_account,
+        });
+
+        emit!(RedemptionRequested {
+            stablecoin: stablecoin_key,
+            request: request_key,
+            requester: self.requester.key(),
+            amount,
+            reference_hash,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> FulfillRedemption<'info> {
+    pub fn fulfill_redemption(&mut self) -> Result<()> {
+        require!(
+            !self.stablecoin.is_paused_for(PAUSE_BURN, Clock::get()?.unix_timestamp),
+            StablecoinError::Paused
+        );
+        require!(self.role.roles.is_burner, StablecoinError::Unauthorized);
+
+        // Anyone can transfer into the escrow, so burn its whole balance: the account must be
+        // empty to close.
+        let amount = escrow_balance(&self.escrow_token_account)?;
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+
+        // CPI: burn escrow — stablecoin PDA signs as escrow owner
+        invoke_signed(
+            &token_instruction::burn(
+                &self.token_program.key(),
+                &self.escrow_token_account.key(),
+                &mint_key,
+                &self.stablecoin.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                self.escrow_token_account.to_account_info(),
+                self.mint.to_account_info(),
+                self.stablecoin.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        close_escrow(
+            &self.token_program,
+            &self.escrow_token_account,
+            &self.requester,
+            &self.stablecoin.to_account_info(),
+            signer_seeds,
+        )?;
+
+        self.stablecoin.total_burned = self
+            .stablecoin
+            .total_burned
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(RedemptionFulfilled {
+            stablecoin: self.stablecoin.key(),
+            request: self.redemption_request.key(),
+            requester: self.requester.key(),
+            amount,
+            reference_hash: self.redemption_request.reference_hash,
+            fulfilled_by: self.burner.key(),
+            total_burned: self.stablecoin.total_burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> RejectRedemption<'info> {
+    pub fn reject_redemption(&mut self, hook_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        // NOTE: Not gated by pause in this program; on SSS-2 mints the transfer hook still
+        // blocks the refund while transfers are paused or if the requester has since been
+        // blacklisted.
+        require!(self.role.roles.is_burner, StablecoinError::Unauthorized);
+
+        // Refund whatever the escrow holds, including anything sent to it after the request,
+        // so it can be closed.
+        let amount = escrow_balance(&self.escrow_token_account)?;
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[self.stablecoin.bump]];
+
+        // CPI: refund escrow to the source account — stablecoin PDA signs as escrow owner
+        onchain::invoke_transfer_checked(
+            &self.token_program.key(),
+            self.escrow_token_account.to_account_info(),
+            self.mint.to_account_info(),
+            self.source_token_account.to_account_info(),
+            self.stablecoin.to_account_info(),
+            hook_accounts,
+            amount,
+            self.stablecoin.decimals,
+            &[signer_seeds],
+        )?;
+        close_escrow(
+            &self.token_program,
+            &self.escrow_token_account,
+            &self.requester,
+            &self.stablecoin.to_account_info(),
+            signer_seeds,
+        )?;
+
+        emit!(RedemptionRejected {
+            stablecoin: self.stablecoin.key(),
+            request: self.redemption_request.key(),
+            requester: self.requester.key(),
+            amount,
+            reference_hash: self.redemption_request.reference_hash,
+            rejected_by: self.burner.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+/// Current token balance of the escrow, which may exceed the requested amount.
+fn escrow_balance(escrow: &AccountInfo) -> Result<u64> {
+    let data = escrow.try_borrow_data()?;
+    Ok(StateWithExtensions::<SplAccount>::unpack(&data)?.base.amount)
+}
+
+/// Closes the (now empty) escrow token account, returning its rent to the requester.
+fn close_escrow<'info>(
+    token_program: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    requester: &AccountInfo<'info>,
+    stablecoin: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    invoke_signed(
+        &token_instruction::close_account(
+            &token_program.key(),
+            &escrow.key(),
+            &requester.key(),
+            &stablecoin.key(),
+            &[],
+        )?,
+        &[escrow.clone(), requester.clone(), stablecoin.clone()],
+        &[signer_seeds],
+    )?;
+    Ok(())
+}
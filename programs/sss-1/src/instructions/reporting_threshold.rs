@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::ReportingThresholdUpdated;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetReportingThreshold<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = blacklister,
+        space = 8 + ReportingConfig::INIT_SPACE,
+        seeds = [REPORTING_CONFIG_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
+    pub reporting_config: Account<'info, ReportingConfig>,
+
+    /// CHECK: ExtraAccountMetaList PDA; its size shows whether the hook resolves the
+    /// reporting config yet
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, stablecoin.mint.as_ref()],
+        bump,
+        seeds::program = SSS_TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_metas: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetReportingThreshold<'info> {
+    /// Set the amount above which the hook emits LargeTransferObserved (0 turns reporting
+    /// off). Travel Rule / CTR reporting is a compliance control, like velocity limits.
+    pub fn set_reporting_threshold(
+        &mut self,
+        threshold: u64,
+        bumps: SetReportingThresholdBumps,
+    ) -> Result<()> {
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+        // A list without the reporting meta would never emit the event
+        require!(
+            self.extra_account_metas.data_len() >= REPORTING_EXTRA_ACCOUNT_METAS_LEN,
+            StablecoinError::ReportingHookAccountsOutdated
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.reporting_config.set_inner(ReportingConfig {
+            stablecoin: self.stablecoin.key(),
+            threshold,
+            updated_by: self.blacklister.key(),
+            updated_at: now,
+            bump: bumps.reporting_config,
+        });
+
+        emit!(ReportingThresholdUpdated {
+            stablecoin: self.stablecoin.key(),
+            threshold,
+            updated_by: self.blacklister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
 use spl_token_2022::{
     extension::StateWithExtensions,
+    instruction as token_instruction,
     onchain,
-    state::Account as SplAccount,
+    state::{Account as SplAccount, AccountState},
 };
 
 use crate::constants::*;
@@ -11,7 +13,9 @@ use crate::events::TokensSeized;
 use crate::state::*;
 
 #[derive(Accounts)]
+#[instruction(amount: Option<u64>, case_reference_hash: [u8; 32])]
 pub struct Seize<'info> {
+    #[account(mut)]
     pub seizer: Signer<'info>,
 
     #[account(
@@ -33,7 +37,7 @@ pub struct Seize<'info> {
     #[account(mut)]
     pub source_token_account: AccountInfo<'info>,
 
-    /// CHECK: Destination treasury token account
+    /// CHECK: Destination token account; must be stablecoin.treasury
     #[account(mut)]
     pub destination_token_account: AccountInfo<'info>,
 
@@ -47,7 +51,8 @@ pub struct Seize<'info> {
     #[account(address = crate::ID)]
     pub sss_token_program: AccountInfo<'info>,
 
-    /// CHECK: Source blacklist PDA (seeds: ["blacklist", stablecoin, source_owner])
+    /// CHECK: Source blacklist PDA (seeds: ["blacklist", stablecoin, source_owner]); verified
+    /// against the source owner in the handler
     pub source_blacklist: AccountInfo<'info>,
 
     /// CHECK: Destination blacklist PDA (seeds: ["blacklist", stablecoin, dest_owner])
@@ -56,15 +61,61 @@ pub struct Seize<'info> {
     /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
     #[account(address = spl_token_2022::ID)]
     pub token_program: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = seizer,
+        space = 8 + SeizureRecord::INIT_SPACE,
+        seeds = [
+            SEIZURE_SEED,
+            stablecoin.key().as_ref(),
+            source_token_account.key().as_ref(),
+            case_reference_hash.as_ref(),
+        ],
+        bump,
+    )]
+    pub seizure_record: Account<'info, SeizureRecord>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Authority blacklist PDA (seeds: ["blacklist", stablecoin, stablecoin]); required once
+    /// the mint's ExtraAccountMetaList has been migrated to include the authority check
+    pub authority_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Source owner allowlist PDA (seeds: ["allowlist", stablecoin, source_owner]);
+    /// required once the ExtraAccountMetaList includes the allowlist metas
+    pub source_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Destination owner allowlist PDA (seeds: ["allowlist", stablecoin, dest_owner])
+    pub dest_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Velocity config PDA (seeds: ["velocity_config", stablecoin]); required once the
+    /// ExtraAccountMetaList includes the velocity metas. Seizures are exempt from the limits.
+    pub velocity_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Source owner velocity exemption PDA (seeds: ["velocity_exempt", stablecoin, source_owner])
+    pub source_velocity_exemption: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Source owner velocity usage PDA (seeds: ["velocity_usage", mint, source_owner],
+    /// program: transfer hook); writable because the hook meta is
+    #[account(mut)]
+    pub source_velocity_usage: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Reporting config PDA (seeds: ["reporting_config", stablecoin]); required once the
+    /// ExtraAccountMetaList includes it
+    pub reporting_config: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> Seize<'info> {
-    pub fn seize(&mut self) -> Result<()> {
-        // NOTE: Seizure uses the transfer hook CPI (invoke_transfer_checked). If the SSS transfer
-        // hook enforces blacklist checks on source or destination, and either account is blacklisted,
-        // the hook may block the seizure. Seizures are intended to move tokens from a seized account
-        // to the treasury; ensure the transfer hook is configured to allow seizure flows, or that
-        // source/dest are not blacklisted when invoking this instruction.
+    pub fn seize(
+        &mut self,
+        amount: Option<u64>,
+        case_reference_hash: [u8; 32],
+        bumps: SeizeBumps,
+    ) -> Result<()> {
+        // NOTE: Seizure uses the transfer hook CPI (invoke_transfer_checked). The hook exempts
+        // transfers signed by the stablecoin PDA as permanent delegate from pause and the source
+        // owner blacklist/allowlist, but still enforces them for the destination.
 
         // Feature gate: only SSS-2 tokens support seizure (both permanent_delegate AND transfer_hook).
         // NOTE: Seize is NOT gated by pause so emergency compliance actions can proceed.
@@ -74,6 +125,15 @@ impl<'info> Seize<'info> {
         );
         require!(self.role.roles.is_seizer, StablecoinError::Unauthorized);
 
+        // Seized funds may only go to the registered treasury, so a compromised seizer key
+        // cannot redirect them.
+        require!(self.stablecoin.has_treasury(), StablecoinError::TreasuryNotSet);
+        require_keys_eq!(
+            self.destination_token_account.key(),
+            self.stablecoin.treasury,
+            StablecoinError::InvalidTreasury
+        );
+
         // Validate transfer hook program and extra_account_metas match expected SSS-2 hook.
         require_eq!(
             self.transfer_hook_program.key(),
@@ -90,18 +150,43 @@ impl<'info> Seize<'info> {
             StablecoinError::Unauthorized
         );
 
-        // Read full balance from source token account.
+        // Read balance, owner and state from source token account.
         // Must use StateWithExtensions (not Pack::unpack) because Token-2022 accounts
         // carry TLV extension data beyond the base 165-byte layout, and Pack::unpack
         // enforces a strict length == 165 check that always fails on Token-2022 accounts.
         let source_data = self.source_token_account.try_borrow_data()?;
         let source_account = StateWithExtensions::<SplAccount>::unpack(&source_data)?;
-        let amount = source_account.base.amount;
+        let balance = source_account.base.amount;
+        let source_owner = source_account.base.owner;
+        let source_frozen = source_account.base.state == AccountState::Frozen;
         drop(source_data);
 
+        // Seize policy: the source owner must be blacklisted, or the account frozen.
+        let (expected_blacklist, _) = Pubkey::find_program_address(
+            &[BLACKLIST_SEED, self.stablecoin.key().as_ref(), source_owner.as_ref()],
+            &crate::ID,
+        );
+        require_eq!(
+            self.source_blacklist.key(),
+            expected_blacklist,
+            StablecoinError::Unauthorized
+        );
+        // Expired entries no longer count (same rule as the transfer hook).
+        let now = Clock::get()?.unix_timestamp;
+        let source_blacklisted = self.source_blacklist.owner == &crate::ID
+            && self.source_blacklist.data_len() > 0
+            && BlacklistEntry::try_deserialize(&mut &self.source_blacklist.try_borrow_data()?[..])?
+                .is_active(now);
+        require!(
+            source_blacklisted || source_frozen,
+            StablecoinError::SeizureNotPermitted
+        );
+
+        // Partial seizures (e.g. court-ordered amounts); full balance when unspecified.
+        let amount = amount.unwrap_or(balance);
         require!(amount > 0, StablecoinError::ZeroAmount);
+        require!(amount <= balance, StablecoinError::SeizureAmountExceedsBalance);
 
-        // CPI: transfer_checked using permanent delegate authority (stablecoin PDA)
         let mint_key = self.mint.key();
         let signer_seeds: &[&[u8]] = &[
             STABLECOIN_SEED,
@@ -109,10 +194,18 @@ impl<'info> Seize<'info> {
             &[self.stablecoin.bump],
         ];
 
+        // Token-2022 rejects transfers out of frozen accounts, even by the permanent
+        // delegate: thaw around the transfer and refreeze afterwards.
+        if source_frozen {
+            self.set_source_frozen(false, signer_seeds)?;
+        }
+
+        // CPI: transfer_checked using permanent delegate authority (stablecoin PDA)
+
         // When the mint has a transfer hook, Token-2022 CPIs to the hook; it must be in the tx.
         // Pass hook program, extra_account_metas, and hook-required accounts so invoke_transfer_checked
         // can build the full CPI instruction.
-        let additional_accounts: &[AccountInfo] = &[
+        let mut additional_accounts = vec![
             self.transfer_hook_program.to_account_info(),
             self.extra_account_metas.to_account_info(),
             self.sss_token_program.to_account_info(),
@@ -120,27 +213,85 @@ impl<'info> Seize<'info> {
             self.source_blacklist.to_account_info(),
             self.dest_blacklist.to_account_info(),
         ];
+        for hook_account in [
+            &self.authority_blacklist,
+            &self.source_allowlist,
+            &self.dest_allowlist,
+            &self.velocity_config,
+            &self.source_velocity_exemption,
+            &self.source_velocity_usage,
+            &self.reporting_config,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            additional_accounts.push(hook_account.to_account_info());
+        }
         onchain::invoke_transfer_checked(
             &self.token_program.key(),
             self.source_token_account.to_account_info(),
             self.mint.to_account_info(),
             self.destination_token_account.to_account_info(),
             self.stablecoin.to_account_info(),
-            additional_accounts,
+            &additional_accounts,
             amount,
             self.stablecoin.decimals,
             &[signer_seeds],
         )?;
 
+        if source_frozen {
+            self.set_source_frozen(true, signer_seeds)?;
+        }
+
+        self.seizure_record.set_inner(SeizureRecord {
+            stablecoin: self.stablecoin.key(),
+            source_token_account: self.source_token_account.key(),
+            source_owner,
+            destination_token_account: self.destination_token_account.key(),
+            amount,
+            case_reference_hash,
+            source_blacklisted,
+            seized_by: self.seizer.key(),
+            seized_at: now,
+            bump: bumps.seizure_record,
+        });
+
         emit!(TokensSeized {
             stablecoin: self.stablecoin.key(),
             from: self.source_token_account.key(),
             to: self.destination_token_account.key(),
             amount,
             seized_by: self.seizer.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
+            seizure_record: self.seizure_record.key(),
+            case_reference_hash,
         });
 
         Ok(())
     }
+
+    /// CPI: freeze_account / thaw_account on the source — stablecoin PDA is the freeze authority
+    fn set_source_frozen(&self, frozen: bool, signer_seeds: &[&[u8]]) -> Result<()> {
+        let build = if frozen {
+            token_instruction::freeze_account
+        } else {
+            token_instruction::thaw_account
+        };
+        invoke_signed(
+            &build(
+                &self.token_program.key(),
+                &self.source_token_account.key(),
+                &self.mint.key(),
+                &self.stablecoin.key(),
+                &[],
+            )?,
+            &[
+                self.source_token_account.to_account_info(),
+                self.mint.to_account_info(),
+                self.stablecoin.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        Ok(())
+    }
 }
@@ -0,0 +1,536 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{ChangeCancelled, ChangeExecuted, ChangeQueued, TimelockUpdated};
+use crate::instructions::{
+    apply_update_minter, apply_update_minter_allowance, apply_update_roles,
+    apply_update_supply_cap, apply_update_supply_cap_mode, apply_update_treasury,
+};
+use crate::state::*;
+use crate::{RoleFlags, SupplyCapMode};
+
+#[derive(Accounts)]
+pub struct UpdateTimelock<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct QueueChange<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + QueuedChange::INIT_SPACE,
+        seeds = [QUEUED_CHANGE_SEED, stablecoin.key().as_ref(), &stablecoin.queued_change_count.to_le_bytes()],
+        bump,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteChange<'info> {
+    /// Anyone may execute a ready change; pays rent for PDAs the change creates
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [QUEUED_CHANGE_SEED, stablecoin.key().as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump,
+        has_one = stablecoin,
+        has_one = rent_payer,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// CHECK: Rent refund destination, validated by has_one on queued_change
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Required for UpdateRoles
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = RoleAccount::LEN,
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), queued_change.action.target().as_ref()],
+        bump,
+    )]
+    pub role: Option<Account<'info, RoleAccount>>,
+
+    /// Required for UpdateMinter / UpdateMinterAllowance
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = MinterInfo::LEN,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), queued_change.action.target().as_ref()],
+        bump,
+    )]
+    pub minter_info: Option<Account<'info, MinterInfo>>,
+
+    /// Required for UpdateSupplyCap / UpdateSupplyCapMode
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + SupplyCap::INIT_SPACE,
+        seeds = [SUPPLY_CAP_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
+    pub supply_cap: Option<Account<'info, SupplyCap>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Token-2022 mint; required for supply cap changes in MintSupply mode
+    pub mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Required for UpdateTreasury to a non-default treasury; checked in
+    /// apply_update_treasury
+    pub treasury_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelChange<'info> {
+    /// Stablecoin authority or guardian
+    pub canceller: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// Closed to rent_payer by apply_cancel_change
+    #[account(
+        mut,
+        seeds = [QUEUED_CHANGE_SEED, stablecoin.key().as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump,
+        has_one = stablecoin,
+        has_one = rent_payer,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// CHECK: Rent refund destination, validated by has_one on queued_change
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+// === Sensitivity rules ===
+// Changes that widen issuance or seizure power are delayed; tightening takes effect at once.
+
+/// Granting is_minter or is_seizer to a holder that did not already have it.
+pub(crate) fn roles_change_is_sensitive(current: &RoleFlags, new: &RoleFlags) -> bool {
+    (new.is_minter && !current.is_minter) || (new.is_seizer && !current.is_seizer)
+}
+
+/// Raising a minter quota.
+pub(crate) fn quota_change_is_sensitive(current_quota: u64, new_quota: u64) -> bool {
+    new_quota > current_quota
+}
+
+/// Moving to periodic mode, raising the per-window limit or shortening the window. Reverting a
+/// periodic minter to lifetime mode re-opens its quota headroom, so that is sensitive too.
+pub(crate) fn allowance_change_is_sensitive(
+    current: &MinterInfo,
+    window_seconds: i64,
+    window_limit: u64,
+) -> bool {
+    if window_seconds == 0 {
+        return current.is_periodic();
+    }
+    !current.is_periodic()
+        || window_limit > current.window_limit
+        || window_seconds < current.window_seconds
+}
+
+/// Raising or removing the supply cap. A stored cap of 0 means no cap has been set yet.
+pub(crate) fn supply_cap_change_is_sensitive(current_cap: u64, new_cap: u64) -> bool {
+    let normalize = |cap: u64| {
+        if cap == NO_SUPPLY_CAP_INDICATOR {
+            NO_SUPPLY_CAP
+        } else {
+            cap
+        }
+    };
+    normalize(new_cap) > normalize(current_cap)
+}
+
+/// Switching to a mode that measures less of the supply (LifetimeMinted -> Circulating ->
+/// MintSupply), which frees capacity under the same cap.
+pub(crate) fn supply_cap_mode_change_is_sensitive(
+    current: SupplyCapMode,
+    new: SupplyCapMode,
+) -> bool {
+    (new as u8) > (current as u8)
+}
+
+/// Redirecting or clearing a registered treasury. Registering the first one only narrows
+/// where seized funds can go.
+pub(crate) fn treasury_change_is_sensitive(current: Pubkey, new: Pubkey) -> bool {
+    current != Pubkey::default() && new != current
+}
+
+/// Shortening the delay, or replacing / removing an existing guardian.
+pub(crate) fn timelock_change_is_sensitive(
+    stablecoin: &StablecoinState,
+    delay: i64,
+    guardian: Pubkey,
+) -> bool {
+    delay < stablecoin.timelock_delay
+        || (stablecoin.guardian != Pubkey::default() && guardian != stablecoin.guardian)
+}
+
+pub(crate) fn require_not_timelocked(stablecoin: &StablecoinState, sensitive: bool) -> Result<()> {
+    require!(
+        !(sensitive && stablecoin.is_timelocked()),
+        StablecoinError::TimelockRequired
+    );
+    Ok(())
+}
+
+/// Whether `action` must go through the timelock, given the current state of its target
+/// account. Only config changes (roles, minter quota / allowance, supply cap, timelock,
+/// treasury) are timelockable.
+pub(crate) fn is_sensitive_change(
+    action: &AdminAction,
+    stablecoin: &StablecoinState,
+    role: &Option<Account<RoleAccount>>,
+    minter_info: &Option<Account<MinterInfo>>,
+    supply_cap: &Option<Account<SupplyCap>>,
+) -> Result<bool> {
+    Ok(match action {
+        AdminAction::UpdateRoles { roles, .. } => {
+            let role = role.as_ref().ok_or(StablecoinError::MissingAdminAccount)?;
+            roles_change_is_sensitive(&role.roles, roles)
+        }
+        AdminAction::UpdateMinter { quota, .. } => {
+            let minter_info = minter_info
+                .as_ref()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            quota_change_is_sensitive(minter_info.quota, *quota)
+        }
+        AdminAction::UpdateMinterAllowance {
+            window_seconds,
+            window_limit,
+            ..
+        } => {
+            let minter_info = minter_info
+                .as_ref()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            allowance_change_is_sensitive(minter_info, *window_seconds, *window_limit)
+        }
+        AdminAction::UpdateSupplyCap { cap } => {
+            let supply_cap = supply_cap
+                .as_ref()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            supply_cap_change_is_sensitive(supply_cap.cap, *cap)
+        }
+        AdminAction::UpdateSupplyCapMode { mode } => {
+            let supply_cap = supply_cap
+                .as_ref()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            supply_cap_mode_change_is_sensitive(supply_cap.mode, *mode)
+        }
+        AdminAction::UpdateTimelock { delay, guardian } => {
+            timelock_change_is_sensitive(stablecoin, *delay, *guardian)
+        }
+        AdminAction::UpdateTreasury { treasury } => {
+            treasury_change_is_sensitive(stablecoin.treasury, *treasury)
+        }
+        _ => false,
+    })
+}
+
+pub(crate) fn is_timelockable(action: &AdminAction) -> bool {
+    matches!(
+        action,
+        AdminAction::UpdateRoles { .. }
+            | AdminAction::UpdateMinter { .. }
+            | AdminAction::UpdateSupplyCap { .. }
+            | AdminAction::UpdateTimelock { .. }
+            | AdminAction::UpdateMinterAllowance { .. }
+            | AdminAction::UpdateSupplyCapMode { .. }
+            | AdminAction::UpdateTreasury { .. }
+    )
+}
+
+pub(crate) fn apply_update_timelock(
+    stablecoin: &mut Account<StablecoinState>,
+    delay: i64,
+    guardian: Pubkey,
+    updated_by: Pubkey,
+) -> Result<()> {
+    require!(
+        (0..=MAX_TIMELOCK_DELAY).contains(&delay),
+        StablecoinError::InvalidTimelockConfig
+    );
+
+    stablecoin.timelock_delay = delay;
+    stablecoin.guardian = guardian;
+
+    emit!(TimelockUpdated {
+        stablecoin: stablecoin.key(),
+        delay,
+        guardian,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Applies a timelockable config change. Shared by execute_change and multisig execution.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_config_change<'info>(
+    action: &AdminAction,
+    stablecoin: &mut Account<'info, StablecoinState>,
+    role: &mut Option<Account<'info, RoleAccount>>,
+    role_bump: Option<u8>,
+    minter_info: &mut Option<Account<'info, MinterInfo>>,
+    minter_info_bump: Option<u8>,
+    supply_cap: &mut Option<Account<'info, SupplyCap>>,
+    supply_cap_bump: Option<u8>,
+    mint: Option<&AccountInfo<'info>>,
+    treasury_account: Option<&AccountInfo<'info>>,
+    updated_by: Pubkey,
+) -> Result<()> {
+    match action.clone() {
+        AdminAction::UpdateRoles { holder, roles } => {
+            let role = role.as_mut().ok_or(StablecoinError::MissingAdminAccount)?;
+            let bump = role_bump.ok_or(StablecoinError::MissingAdminAccount)?;
+            apply_update_roles(stablecoin, role, holder, roles, bump, updated_by)
+        }
+        AdminAction::UpdateMinter { minter, quota } => {
+            let minter_info = minter_info
+                .as_mut()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            let bump = minter_info_bump.ok_or(StablecoinError::MissingAdminAccount)?;
+            apply_update_minter(stablecoin, minter_info, minter, quota, bump, updated_by)
+        }
+        AdminAction::UpdateMinterAllowance {
+            minter,
+            window_seconds,
+            window_limit,
+        } => {
+            let minter_info = minter_info
+                .as_mut()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            apply_update_minter_allowance(
+                stablecoin,
+                minter_info,
+                minter,
+                window_seconds,
+                window_limit,
+                updated_by,
+            )
+        }
+        AdminAction::UpdateSupplyCap { cap } => {
+            let supply_cap = supply_cap
+                .as_mut()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            let bump = supply_cap_bump.ok_or(StablecoinError::MissingAdminAccount)?;
+            apply_update_supply_cap(stablecoin, supply_cap, cap, bump, mint, updated_by)
+        }
+        AdminAction::UpdateSupplyCapMode { mode } => {
+            let supply_cap = supply_cap
+                .as_mut()
+                .ok_or(StablecoinError::MissingAdminAccount)?;
+            apply_update_supply_cap_mode(stablecoin, supply_cap, mode, mint, updated_by)
+        }
+        AdminAction::UpdateTimelock { delay, guardian } => {
+            apply_update_timelock(stablecoin, delay, guardian, updated_by)
+        }
+        AdminAction::UpdateTreasury { treasury } => {
+            apply_update_treasury(stablecoin, treasury, treasury_account, updated_by)
+        }
+        _ => Err(StablecoinError::NotTimelockable.into()),
+    }
+}
+
+/// Records a queued change with eta = now + timelock_delay. Shared by queue_change and
+/// multisig execution of a sensitive action.
+pub(crate) fn record_queued_change(
+    stablecoin: &mut Account<StablecoinState>,
+    queued_change: &mut Account<QueuedChange>,
+    action: AdminAction,
+    queued_by: Pubkey,
+    rent_payer: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    require!(stablecoin.is_timelocked(), StablecoinError::TimelockNotEnabled);
+    require!(is_timelockable(&action), StablecoinError::NotTimelockable);
+
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(stablecoin.timelock_delay)
+        .ok_or(StablecoinError::MathOverflow)?;
+    let id = stablecoin.queued_change_count;
+
+    queued_change.set_inner(QueuedChange {
+        stablecoin: stablecoin.key(),
+        id,
+        action: action.clone(),
+        eta,
+        queued_by,
+        rent_payer,
+        bump,
+    });
+    stablecoin.queued_change_count = id.checked_add(1).ok_or(StablecoinError::MathOverflow)?;
+
+    emit!(ChangeQueued {
+        stablecoin: stablecoin.key(),
+        queued_change: queued_change.key(),
+        id,
+        action,
+        eta,
+        queued_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+impl<'info> UpdateTimelock<'info> {
+    pub fn update_timelock(&mut self, delay: i64, guardian: Pubkey) -> Result<()> {
+        // Enabling or lengthening the delay is immediate; weakening it must be queued.
+        require_not_timelocked(
+            &self.stablecoin,
+            timelock_change_is_sensitive(&self.stablecoin, delay, guardian),
+        )?;
+        apply_update_timelock(&mut self.stablecoin, delay, guardian, self.authority.key())
+    }
+}
+
+impl<'info> QueueChange<'info> {
+    pub fn queue_change(&mut self, action: AdminAction, bumps: QueueChangeBumps) -> Result<()> {
+        record_queued_change(
+            &mut self.stablecoin,
+            &mut self.queued_change,
+            action,
+            self.authority.key(),
+            self.authority.key(),
+            bumps.queued_change,
+        )
+    }
+}
+
+impl<'info> ExecuteChange<'info> {
+    pub fn execute_change(&mut self, bumps: &ExecuteChangeBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.queued_change.is_ready(now),
+            StablecoinError::TimelockNotReady
+        );
+        // An authority handoff invalidates changes queued by the previous authority.
+        require_keys_eq!(
+            self.queued_change.queued_by,
+            self.stablecoin.authority,
+            StablecoinError::QueuedChangeStale
+        );
+
+        let action = self.queued_change.action.clone();
+        let queued_by = self.queued_change.queued_by;
+        let mint = self.mint.as_ref().map(|m| m.to_account_info());
+        let treasury_account = self.treasury_account.as_ref().map(|a| a.to_account_info());
+        apply_config_change(
+            &action,
+            &mut self.stablecoin,
+            &mut self.role,
+            bumps.role,
+            &mut self.minter_info,
+            bumps.minter_info,
+            &mut self.supply_cap,
+            bumps.supply_cap,
+            mint.as_ref(),
+            treasury_account.as_ref(),
+            queued_by,
+        )?;
+
+        emit!(ChangeExecuted {
+            stablecoin: self.stablecoin.key(),
+            queued_change: self.queued_change.key(),
+            id: self.queued_change.id,
+            action,
+            executor: self.executor.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> CancelChange<'info> {
+    pub fn cancel_change(&mut self) -> Result<()> {
+        let canceller = self.canceller.key();
+        require!(
+            canceller == self.stablecoin.authority
+                || (self.stablecoin.guardian != Pubkey::default()
+                    && canceller == self.stablecoin.guardian),
+            StablecoinError::Unauthorized
+        );
+        apply_cancel_change(
+            &self.stablecoin,
+            &self.queued_change,
+            &self.rent_payer.to_account_info(),
+            canceller,
+        )
+    }
+}
+
+/// Shared by the direct cancel_change path and admin-action execution (multisig). Closes the
+/// queued change, refunding its rent payer. The caller checks that `cancelled_by` is the
+/// authority or guardian.
+pub(crate) fn apply_cancel_change<'info>(
+    stablecoin: &Account<'info, StablecoinState>,
+    queued_change: &Account<'info, QueuedChange>,
+    rent_payer: &AccountInfo<'info>,
+    cancelled_by: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        queued_change.stablecoin,
+        stablecoin.key(),
+        StablecoinError::Unauthorized
+    );
+    require_keys_eq!(
+        rent_payer.key(),
+        queued_change.rent_payer,
+        StablecoinError::Unauthorized
+    );
+
+    emit!(ChangeCancelled {
+        stablecoin: stablecoin.key(),
+        queued_change: queued_change.key(),
+        id: queued_change.id,
+        action: queued_change.action.clone(),
+        cancelled_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    queued_change.close(rent_payer.clone())
+}
@@ -2,9 +2,14 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::error::StablecoinError;
-use crate::events::AuthorityTransferred;
+use crate::events::{
+    AuthorityAccepted, AuthorityNominated, AuthorityTransferCancelled, AuthorityTransferred,
+};
 use crate::state::*;
 
+/// Single-step authority transfer. Both the current and the new authority sign, so a
+/// mistyped key cannot take control. Keys that cannot sign use nominate_authority +
+/// accept_authority.
 #[derive(Accounts)]
 pub struct TransferAuthority<'info> {
     pub authority: Signer<'info>,
@@ -17,34 +22,180 @@ pub struct TransferAuthority<'info> {
     )]
     pub stablecoin: Account<'info, StablecoinState>,
 
-    /// CHECK: The new authority. Does not require signer to allow programmatic/multisig
-    /// handoff and cold storage transfers. Caller must ensure address is correct.
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct NominateAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: The nominee. Must sign accept_authority before it becomes the authority.
     pub new_authority: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The nominee recorded in stablecoin.pending_authority
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+}
+
 impl<'info> TransferAuthority<'info> {
     pub fn transfer_authority(&mut self) -> Result<()> {
-        // NOTE: New authority does not auto-receive roles. Authority must call update_roles
-        // for the new authority before or after transfer; or use a two-tx flow.
-        require!(
-            self.new_authority.key() != Pubkey::default(),
-            StablecoinError::Unauthorized
-        );
-        require!(
-            self.new_authority.key() != self.stablecoin.authority,
-            StablecoinError::InvalidRoleConfig
-        );
-
-        let previous_authority = self.stablecoin.authority;
-        self.stablecoin.authority = self.new_authority.key();
-
-        emit!(AuthorityTransferred {
-            stablecoin: self.stablecoin.key(),
-            previous_authority,
-            new_authority: self.new_authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-
-        Ok(())
+        apply_transfer_authority(&mut self.stablecoin, self.new_authority.key())
+    }
+}
+
+impl<'info> NominateAuthority<'info> {
+    pub fn nominate_authority(&mut self) -> Result<()> {
+        apply_nominate_authority(&mut self.stablecoin, self.new_authority.key())
+    }
+}
+
+impl<'info> AcceptAuthority<'info> {
+    pub fn accept_authority(&mut self) -> Result<()> {
+        apply_accept_authority(&mut self.stablecoin, self.new_authority.key())
     }
 }
+
+impl<'info> CancelAuthorityTransfer<'info> {
+    pub fn cancel_authority_transfer(&mut self) -> Result<()> {
+        apply_cancel_authority_transfer(&mut self.stablecoin)
+    }
+}
+
+// Shared by the direct instructions above and, except for transfer, admin-action execution
+// (multisig). Callers are responsible for checking that the current authority (or, for accept,
+// the nominee) authorized the change.
+
+pub(crate) fn apply_transfer_authority(
+    stablecoin: &mut Account<StablecoinState>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    // NOTE: New authority does not auto-receive roles. Authority must call update_roles
+    // for the new authority before or after transfer; or use a two-tx flow.
+    require!(
+        new_authority != Pubkey::default(),
+        StablecoinError::Unauthorized
+    );
+    require!(
+        new_authority != stablecoin.authority,
+        StablecoinError::InvalidRoleConfig
+    );
+
+    let previous_authority = stablecoin.authority;
+    stablecoin.authority = new_authority;
+    // A direct transfer supersedes any outstanding nomination.
+    stablecoin.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        stablecoin: stablecoin.key(),
+        previous_authority,
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_nominate_authority(
+    stablecoin: &mut Account<StablecoinState>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default(),
+        StablecoinError::Unauthorized
+    );
+    require!(
+        new_authority != stablecoin.authority,
+        StablecoinError::InvalidRoleConfig
+    );
+
+    // Re-nominating overwrites the previous nominee.
+    stablecoin.pending_authority = new_authority;
+
+    emit!(AuthorityNominated {
+        stablecoin: stablecoin.key(),
+        authority: stablecoin.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_accept_authority(
+    stablecoin: &mut Account<StablecoinState>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require!(
+        stablecoin.has_pending_authority(),
+        StablecoinError::NoPendingAuthority
+    );
+    require_keys_eq!(
+        new_authority,
+        stablecoin.pending_authority,
+        StablecoinError::Unauthorized
+    );
+
+    let previous_authority = stablecoin.authority;
+    stablecoin.authority = new_authority;
+    stablecoin.pending_authority = Pubkey::default();
+
+    emit!(AuthorityAccepted {
+        stablecoin: stablecoin.key(),
+        previous_authority,
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_cancel_authority_transfer(
+    stablecoin: &mut Account<StablecoinState>,
+) -> Result<()> {
+    require!(
+        stablecoin.has_pending_authority(),
+        StablecoinError::NoPendingAuthority
+    );
+
+    let cancelled_authority = stablecoin.pending_authority;
+    stablecoin.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferCancelled {
+        stablecoin: stablecoin.key(),
+        authority: stablecoin.authority,
+        cancelled_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
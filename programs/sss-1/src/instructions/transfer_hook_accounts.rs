@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{TransferHookAccountsInitialized, TransferHookAccountsUpdated};
+use crate::state::*;
+
+/// Creates the mint's ExtraAccountMetaList. The hook only accepts the stablecoin PDA
+/// (the mint's TransferHook authority) as signer, so this CPI is the sole init path.
+#[derive(Accounts)]
+pub struct InitializeTransferHookAccounts<'info> {
+    /// Pays rent for the list
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint (bound to stablecoin via seeds)
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA (seeds: ["extra-account-metas", mint], program: transfer_hook_program)
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+        seeds::program = SSS_TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_metas: AccountInfo<'info>,
+
+    /// CHECK: Must be the official SSS-2 transfer hook program
+    #[account(address = SSS_TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the mint's ExtraAccountMetaList to the hook program's current layout.
+/// The stablecoin PDA (the mint's TransferHook authority) signs the hook CPI.
+#[derive(Accounts)]
+pub struct UpdateTransferHookAccounts<'info> {
+    /// Pays any additional rent for the larger list
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint (bound to stablecoin via seeds)
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA (seeds: ["extra-account-metas", mint], program: transfer_hook_program)
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+        seeds::program = SSS_TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_metas: AccountInfo<'info>,
+
+    /// CHECK: Must be the official SSS-2 transfer hook program
+    #[account(address = SSS_TRANSFER_HOOK_PROGRAM_ID)]
+    pub transfer_hook_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeTransferHookAccounts<'info> {
+    pub fn initialize_transfer_hook_accounts(&mut self) -> Result<()> {
+        apply_initialize_transfer_hook_accounts(
+            &self.stablecoin,
+            &self.mint,
+            &self.extra_account_metas,
+            &self.transfer_hook_program,
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.authority.key(),
+        )
+    }
+}
+
+impl<'info> UpdateTransferHookAccounts<'info> {
+    pub fn update_transfer_hook_accounts(&mut self) -> Result<()> {
+        apply_update_transfer_hook_accounts(
+            &self.stablecoin,
+            &self.mint,
+            &self.extra_account_metas,
+            &self.transfer_hook_program,
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            self.authority.key(),
+        )
+    }
+}
+
+/// Shared by the direct initialize_transfer_hook_accounts path and admin-action execution
+/// (multisig). `payer` funds the list.
+pub(crate) fn apply_initialize_transfer_hook_accounts<'info>(
+    stablecoin: &Account<'info, StablecoinState>,
+    mint: &AccountInfo<'info>,
+    extra_account_metas: &AccountInfo<'info>,
+    transfer_hook_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    initialized_by: Pubkey,
+) -> Result<()> {
+    require!(
+        stablecoin.enable_transfer_hook,
+        StablecoinError::ComplianceNotEnabled
+    );
+    require_keys_eq!(mint.key(), stablecoin.mint, StablecoinError::Unauthorized);
+
+    // CPI: sss_transfer_hook::initialize_extra_account_meta_list(crate::ID)
+    // Accounts: payer, extra_account_meta_list, mint, system_program, transfer_hook_authority
+    let mint_key = mint.key();
+    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[stablecoin.bump]];
+    let mut data = HOOK_INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(crate::ID.as_ref());
+    invoke_signed(
+        &Instruction {
+            program_id: SSS_TRANSFER_HOOK_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(payer.key(), true),
+                AccountMeta::new(extra_account_metas.key(), false),
+                AccountMeta::new_readonly(mint_key, false),
+                AccountMeta::new_readonly(system_program.key(), false),
+                AccountMeta::new_readonly(stablecoin.key(), true),
+            ],
+            data,
+        },
+        &[
+            payer.clone(),
+            extra_account_metas.clone(),
+            mint.clone(),
+            system_program.clone(),
+            stablecoin.to_account_info(),
+            transfer_hook_program.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    emit!(TransferHookAccountsInitialized {
+        stablecoin: stablecoin.key(),
+        extra_account_metas: extra_account_metas.key(),
+        initialized_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Shared by the direct update_transfer_hook_accounts path and admin-action execution
+/// (multisig). `payer` funds any growth of the list.
+pub(crate) fn apply_update_transfer_hook_accounts<'info>(
+    stablecoin: &Account<'info, StablecoinState>,
+    mint: &AccountInfo<'info>,
+    extra_account_metas: &AccountInfo<'info>,
+    transfer_hook_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    updated_by: Pubkey,
+) -> Result<()> {
+    require!(
+        stablecoin.enable_transfer_hook,
+        StablecoinError::ComplianceNotEnabled
+    );
+    require_keys_eq!(mint.key(), stablecoin.mint, StablecoinError::Unauthorized);
+
+    // CPI: sss_transfer_hook::update_extra_account_meta_list
+    // Accounts: authority (stablecoin PDA), payer, extra_account_meta_list, mint, system_program
+    let mint_key = mint.key();
+    let signer_seeds: &[&[u8]] = &[STABLECOIN_SEED, mint_key.as_ref(), &[stablecoin.bump]];
+    invoke_signed(
+        &Instruction {
+            program_id: SSS_TRANSFER_HOOK_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(stablecoin.key(), true),
+                AccountMeta::new(payer.key(), true),
+                AccountMeta::new(extra_account_metas.key(), false),
+                AccountMeta::new_readonly(mint_key, false),
+                AccountMeta::new_readonly(system_program.key(), false),
+            ],
+            data: HOOK_UPDATE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR.to_vec(),
+        },
+        &[
+            stablecoin.to_account_info(),
+            payer.clone(),
+            extra_account_metas.clone(),
+            mint.clone(),
+            system_program.clone(),
+            transfer_hook_program.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    emit!(TransferHookAccountsUpdated {
+        stablecoin: stablecoin.key(),
+        extra_account_metas: extra_account_metas.key(),
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use spl_token_2022::{extension::StateWithExtensions, state::Account as SplAccount};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::TreasuryUpdated;
+use crate::instructions::timelock::{require_not_timelocked, treasury_change_is_sensitive};
+use crate::state::*;
+
+/// Registers the treasury token account that seize must send to.
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: The new treasury; must be a Token-2022 account of this mint (checked in
+    /// apply_update_treasury). Omitted when clearing the treasury
+    pub treasury_account: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> UpdateTreasury<'info> {
+    pub fn update_treasury(&mut self, treasury: Pubkey) -> Result<()> {
+        // Registering the first treasury only narrows seize destinations; redirecting or
+        // clearing an existing one must be queued.
+        require_not_timelocked(
+            &self.stablecoin,
+            treasury_change_is_sensitive(self.stablecoin.treasury, treasury),
+        )?;
+        let treasury_account = self.treasury_account.as_ref().map(|a| a.to_account_info());
+        apply_update_treasury(
+            &mut self.stablecoin,
+            treasury,
+            treasury_account.as_ref(),
+            self.authority.key(),
+        )
+    }
+}
+
+/// Shared by the direct update_treasury path and admin-action execution. A non-default
+/// `treasury` must come with its account, which must hold this stablecoin's mint.
+pub(crate) fn apply_update_treasury(
+    stablecoin: &mut Account<StablecoinState>,
+    treasury: Pubkey,
+    treasury_account: Option<&AccountInfo>,
+    updated_by: Pubkey,
+) -> Result<()> {
+    if treasury != Pubkey::default() {
+        let account = treasury_account.ok_or(StablecoinError::MissingAdminAccount)?;
+        require_keys_eq!(
+            account.key(),
+            treasury,
+            StablecoinError::InvalidTreasuryAccount
+        );
+        require_keys_eq!(
+            *account.owner,
+            spl_token_2022::ID,
+            StablecoinError::InvalidTreasuryAccount
+        );
+        let data = account.try_borrow_data()?;
+        let token_account = StateWithExtensions::<SplAccount>::unpack(&data)
+            .map_err(|_| StablecoinError::InvalidTreasuryAccount)?;
+        require_keys_eq!(
+            token_account.base.mint,
+            stablecoin.mint,
+            StablecoinError::InvalidTreasuryAccount
+        );
+    }
+
+    let previous = stablecoin.treasury;
+    stablecoin.treasury = treasury;
+
+    emit!(TreasuryUpdated {
+        stablecoin: stablecoin.key(),
+        previous,
+        treasury,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::{self, Transfer};
+use spl_token_2022::{
+    extension::{metadata_pointer::MetadataPointer, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as SplMint,
+};
+use spl_token_metadata_interface::{instruction as metadata_instruction, state::Field};
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::MetadataUpdated;
+use crate::state::*;
+
+/// Additional on-mint metadata entry. An empty value removes the key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct MetadataField {
+    #[max_len(MAX_METADATA_KEY_LEN)]
+    pub key: String,
+    #[max_len(MAX_METADATA_VALUE_LEN)]
+    pub value: String,
+}
+
+/// Also stored in `AdminAction::UpdateMetadata`, hence the length bounds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct UpdateMetadataParams {
+    /// None leaves the field unchanged
+    #[max_len(MAX_NAME_LEN)]
+    pub name: Option<String>,
+    #[max_len(MAX_SYMBOL_LEN)]
+    pub symbol: Option<String>,
+    #[max_len(MAX_URI_LEN)]
+    pub uri: Option<String>,
+    /// At most MAX_METADATA_FIELDS; requires a mint created with on-mint metadata
+    #[max_len(MAX_METADATA_FIELDS)]
+    pub fields: Vec<MetadataField>,
+}
+
+/// Tops `account` up to the rent-exempt minimum for its current size. Token-2022 reallocs
+/// the mint when metadata grows but leaves the rent to the caller.
+pub(crate) fn top_up_rent<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(account.data_len());
+    if required > account.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - account.lamports(),
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    /// Pays rent when the on-mint metadata grows
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, mint.key().as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key() @ StablecoinError::Unauthorized,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// CHECK: Token-2022 mint; holds the metadata when created with the MetadataPointer extension
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Must be the Token-2022 program — prevents CPI redirection attacks
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateMetadata<'info> {
+    /// Update name, symbol, uri and additional fields in both StablecoinState and the on-mint
+    /// metadata. Mints created before on-mint metadata only get the StablecoinState update and
+    /// cannot take additional fields.
+    pub fn update_metadata(&mut self, params: UpdateMetadataParams) -> Result<()> {
+        let authority = self.authority.key();
+        apply_update_metadata(
+            &mut self.stablecoin,
+            &self.mint,
+            &self.token_program,
+            &self.authority.to_account_info(),
+            &self.system_program.to_account_info(),
+            params,
+            authority,
+        )
+    }
+}
+
+/// Shared by the direct update_metadata path and admin-action execution (multisig). `payer`
+/// covers the rent when the on-mint metadata grows.
+pub(crate) fn apply_update_metadata<'info>(
+    stablecoin: &mut Account<'info, StablecoinState>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    params: UpdateMetadataParams,
+    updated_by: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(mint.key(), stablecoin.mint, StablecoinError::Unauthorized);
+    if let Some(name) = &params.name {
+        require!(name.len() <= MAX_NAME_LEN, StablecoinError::NameTooLong);
+    }
+    if let Some(symbol) = &params.symbol {
+        require!(symbol.len() <= MAX_SYMBOL_LEN, StablecoinError::SymbolTooLong);
+    }
+    if let Some(uri) = &params.uri {
+        require!(uri.len() <= MAX_URI_LEN, StablecoinError::UriTooLong);
+    }
+    require!(
+        params.fields.len() <= MAX_METADATA_FIELDS,
+        StablecoinError::InvalidMetadataField
+    );
+    for field in &params.fields {
+        require!(
+            !field.key.is_empty()
+                && field.key.len() <= MAX_METADATA_KEY_LEN
+                && field.value.len() <= MAX_METADATA_VALUE_LEN,
+            StablecoinError::InvalidMetadataField
+        );
+    }
+
+    let has_mint_metadata = {
+        let data = mint.try_borrow_data()?;
+        StateWithExtensions::<SplMint>::unpack(&data)?
+            .get_extension::<MetadataPointer>()
+            .is_ok()
+    };
+    require!(
+        has_mint_metadata || params.fields.is_empty(),
+        StablecoinError::MintMetadataNotInitialized
+    );
+
+    if has_mint_metadata {
+        let mut updates = Vec::new();
+        if let Some(name) = &params.name {
+            updates.push((Field::Name, name.clone()));
+        }
+        if let Some(symbol) = &params.symbol {
+            updates.push((Field::Symbol, symbol.clone()));
+        }
+        if let Some(uri) = &params.uri {
+            updates.push((Field::Uri, uri.clone()));
+        }
+
+        let mint_key = mint.key();
+        let signer_seeds: &[&[u8]] =
+            &[STABLECOIN_SEED, mint_key.as_ref(), &[stablecoin.bump]];
+        let accounts = [mint.clone(), stablecoin.to_account_info()];
+        for (field, value) in updates {
+            invoke_signed(
+                &metadata_instruction::update_field(
+                    &token_program.key(),
+                    &mint_key,
+                    &stablecoin.key(),
+                    field,
+                    value,
+                ),
+                &accounts,
+                &[signer_seeds],
+            )?;
+        }
+        for field in &params.fields {
+            let ix = if field.value.is_empty() {
+                metadata_instruction::remove_key(
+                    &token_program.key(),
+                    &mint_key,
+                    &stablecoin.key(),
+                    field.key.clone(),
+                    true,
+                )
+            } else {
+                metadata_instruction::update_field(
+                    &token_program.key(),
+                    &mint_key,
+                    &stablecoin.key(),
+                    Field::Key(field.key.clone()),
+                    field.value.clone(),
+                )
+            };
+            invoke_signed(&ix, &accounts, &[signer_seeds])?;
+        }
+        top_up_rent(payer, mint, system_program)?;
+    }
+
+    if let Some(name) = params.name {
+        stablecoin.name = name;
+    }
+    if let Some(symbol) = params.symbol {
+        stablecoin.symbol = symbol;
+    }
+    if let Some(uri) = params.uri {
+        stablecoin.uri = uri;
+    }
+
+    emit!(MetadataUpdated {
+        stablecoin: stablecoin.key(),
+        mint: stablecoin.mint,
+        name: stablecoin.name.clone(),
+        symbol: stablecoin.symbol.clone(),
+        uri: stablecoin.uri.clone(),
+        fields: params.fields,
+        on_mint: has_mint_metadata,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
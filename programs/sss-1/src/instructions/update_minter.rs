@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::error::StablecoinError;
+use crate::events::{MinterAllowanceUpdated, MinterRemoved, MinterUpdated};
+use crate::instructions::timelock::{
+    allowance_change_is_sensitive, quota_change_is_sensitive, require_not_timelocked,
+};
 use crate::state::*;
-use crate::MinterUpdated;
 
 #[derive(Accounts)]
 pub struct UpdateMinter<'info> {
@@ -32,31 +35,210 @@ pub struct UpdateMinter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateMinterAllowance<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// Must already exist (created by update_minter)
+    #[account(
+        mut,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), minter_info.minter.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    /// Receives the MinterInfo rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [MINTER_SEED, stablecoin.key().as_ref(), minter_info.minter.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+}
+
 impl<'info> UpdateMinter<'info> {
     pub fn update_minter(&mut self, quota: u64, bumps: &UpdateMinterBumps) -> Result<()> {
-        // Enforce quota >= already minted. Prevents inconsistent state; quotas can only increase
-        // or stay equal. To remove a minter entirely, use a different minter key.
+        require_not_timelocked(
+            &self.stablecoin,
+            quota_change_is_sensitive(self.minter_info.quota, quota),
+        )?;
+        apply_update_minter(
+            &self.stablecoin,
+            &mut self.minter_info,
+            self.minter.key(),
+            quota,
+            bumps.minter_info,
+            self.authority.key(),
+        )
+    }
+}
+
+/// Shared by the direct update_minter path and admin-action execution (multisig).
+pub(crate) fn apply_update_minter(
+    stablecoin: &Account<StablecoinState>,
+    minter_info: &mut Account<MinterInfo>,
+    minter: Pubkey,
+    quota: u64,
+    bump: u8,
+    updated_by: Pubkey,
+) -> Result<()> {
+    // Lifetime mode: enforce quota >= already minted. Prevents inconsistent state; quotas can
+    // only increase or stay equal. Periodic minters are limited per window instead.
+    if !minter_info.is_periodic() {
         require!(
-            quota >= self.minter_info.minted_amount,
+            quota >= minter_info.minted_amount,
             StablecoinError::QuotaExceeded
         );
+    }
 
-        self.minter_info.set_inner(MinterInfo {
-            stablecoin: self.stablecoin.key(),
-            minter: self.minter.key(),
-            quota,
-            minted_amount: self.minter_info.minted_amount, // Preserve existing minted_amount (don't reset on quota update)
-            bump: bumps.minter_info,
-        });
-
-        emit!(MinterUpdated {
-            stablecoin: self.stablecoin.key(),
-            minter: self.minter.key(),
-            new_quota: quota,
-            updated_by: self.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-
-        Ok(())
+    if minter_info.stablecoin == Pubkey::default() {
+        // Freshly created (or re-created after remove_minter): join the current epoch.
+        minter_info.epoch = stablecoin.minter_epoch;
+        minter_info.version = MINTER_INFO_VERSION;
+    }
+    minter_info.stablecoin = stablecoin.key();
+    minter_info.minter = minter;
+    minter_info.quota = quota;
+    minter_info.bump = bump;
+    // minted_amount and allowance settings are preserved across quota updates
+
+    emit!(MinterUpdated {
+        stablecoin: stablecoin.key(),
+        minter,
+        new_quota: quota,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+impl<'info> UpdateMinterAllowance<'info> {
+    pub fn update_minter_allowance(&mut self, window_seconds: i64, window_limit: u64) -> Result<()> {
+        require_not_timelocked(
+            &self.stablecoin,
+            allowance_change_is_sensitive(&self.minter_info, window_seconds, window_limit),
+        )?;
+        let minter = self.minter_info.minter;
+        apply_update_minter_allowance(
+            &self.stablecoin,
+            &mut self.minter_info,
+            minter,
+            window_seconds,
+            window_limit,
+            self.authority.key(),
+        )
     }
 }
+
+/// Switches a minter between lifetime quota (window_seconds = 0) and periodic allowance mode.
+/// Shared by the direct path and admin-action execution (multisig / timelock).
+pub(crate) fn apply_update_minter_allowance(
+    stablecoin: &Account<StablecoinState>,
+    minter_info: &mut Account<MinterInfo>,
+    minter: Pubkey,
+    window_seconds: i64,
+    window_limit: u64,
+    updated_by: Pubkey,
+) -> Result<()> {
+    // Admin execution may pass a freshly created (zeroed) PDA; only configured minters qualify.
+    require!(
+        minter_info.stablecoin == stablecoin.key() && minter_info.minter == minter,
+        StablecoinError::MinterNotConfigured
+    );
+    require!(
+        (0..=MAX_MINTER_WINDOW).contains(&window_seconds)
+            && ((window_seconds == 0) == (window_limit == 0)),
+        StablecoinError::InvalidAllowanceConfig
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    // Reconfiguring starts a new window now but carries over usage from the current window,
+    // so lowering the limit cannot be used to reset it.
+    let carried = if minter_info.is_periodic() {
+        minter_info.minted_in_window(now)
+    } else {
+        0
+    };
+    minter_info.window_seconds = window_seconds;
+    minter_info.window_limit = window_limit;
+    minter_info.window_start = now;
+    minter_info.window_minted = if window_seconds > 0 { carried } else { 0 };
+
+    emit!(MinterAllowanceUpdated {
+        stablecoin: stablecoin.key(),
+        minter,
+        window_seconds,
+        window_limit,
+        updated_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+impl<'info> RemoveMinter<'info> {
+    pub fn remove_minter(&mut self) -> Result<()> {
+        let minter = self.minter_info.minter;
+        apply_remove_minter(
+            &mut self.stablecoin,
+            &self.minter_info,
+            minter,
+            self.authority.key(),
+        )
+    }
+}
+
+/// Validates and records a minter removal. The caller closes the MinterInfo account.
+/// Shared by the direct path and admin-action execution (multisig).
+pub(crate) fn apply_remove_minter(
+    stablecoin: &mut Account<StablecoinState>,
+    minter_info: &Account<MinterInfo>,
+    minter: Pubkey,
+    removed_by: Pubkey,
+) -> Result<()> {
+    require!(
+        minter_info.stablecoin == stablecoin.key() && minter_info.minter == minter,
+        StablecoinError::MinterNotConfigured
+    );
+
+    // A later update_minter for the same key re-creates the PDA in the next epoch with
+    // zeroed counters; nothing from this epoch carries over.
+    stablecoin.minter_epoch = stablecoin
+        .minter_epoch
+        .checked_add(1)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit!(MinterRemoved {
+        stablecoin: stablecoin.key(),
+        minter,
+        epoch: minter_info.epoch,
+        minted_amount: minter_info.minted_amount,
+        removed_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
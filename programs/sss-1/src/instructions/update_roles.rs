@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
 use crate::error::StablecoinError;
-use crate::events::RolesUpdated;
+use crate::events::{RoleRevoked, RolesUpdated};
+use crate::instructions::timelock::{require_not_timelocked, roles_change_is_sensitive};
 use crate::state::*;
 use crate::{constants::*, RoleFlags};
 
@@ -32,34 +33,112 @@ pub struct UpdateRoles<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RevokeRoleAccount<'info> {
+    /// Receives the RoleAccount rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), role.holder.as_ref()],
+        bump = role.bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+}
+
 impl<'info> UpdateRoles<'info> {
-    pub fn update_roles(&mut self, roles: RoleFlags) -> Result<()> {
-        require!(
-            self.holder.key() != Pubkey::default(),
-            StablecoinError::InvalidRoleConfig
-        );
-        // NOTE: Role combination is unrestricted. Deployers should implement separation
-        // of duties at the organizational level (e.g. separate minter/burner, blacklister).
-        self.role.set_inner(RoleAccount {
-            stablecoin: self.stablecoin.key(),
-            holder: self.holder.key(),
+    pub fn update_roles(&mut self, roles: RoleFlags, bumps: &UpdateRolesBumps) -> Result<()> {
+        require_not_timelocked(
+            &self.stablecoin,
+            roles_change_is_sensitive(&self.role.roles, &roles),
+        )?;
+        apply_update_roles(
+            &self.stablecoin,
+            &mut self.role,
+            self.holder.key(),
             roles,
-            bump: self.role.bump,
-        });
-
-        emit!(RolesUpdated {
-            stablecoin: self.stablecoin.key(),
-            holder: self.holder.key(),
-            is_minter: roles.is_minter,
-            is_burner: roles.is_burner,
-            is_pauser: roles.is_pauser,
-            is_freezer: roles.is_freezer,
-            is_blacklister: roles.is_blacklister,
-            is_seizer: roles.is_seizer,
-            updated_by: self.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-
-        Ok(())
+            bumps.role,
+            self.authority.key(),
+        )
+    }
+}
+
+/// Shared by the direct update_roles path and admin-action execution (multisig).
+pub(crate) fn apply_update_roles(
+    stablecoin: &Account<StablecoinState>,
+    role: &mut Account<RoleAccount>,
+    holder: Pubkey,
+    roles: RoleFlags,
+    bump: u8,
+    updated_by: Pubkey,
+) -> Result<()> {
+    require!(
+        holder != Pubkey::default(),
+        StablecoinError::InvalidRoleConfig
+    );
+    // NOTE: Role combination is unrestricted. Deployers should implement separation
+    // of duties at the organizational level (e.g. separate minter/burner, blacklister).
+    role.set_inner(RoleAccount {
+        stablecoin: stablecoin.key(),
+        holder,
+        roles,
+        bump,
+        version: ROLE_ACCOUNT_VERSION,
+        reserved: [0; ROLE_ACCOUNT_RESERVED_LEN],
+    });
+
+    emit!(RolesUpdated {
+        stablecoin: stablecoin.key(),
+        holder,
+        is_minter: roles.is_minter,
+        is_burner: roles.is_burner,
+        is_pauser: roles.is_pauser,
+        is_freezer: roles.is_freezer,
+        is_blacklister: roles.is_blacklister,
+        is_seizer: roles.is_seizer,
+        is_allowlister: roles.is_allowlister,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+impl<'info> RevokeRoleAccount<'info> {
+    pub fn revoke_role_account(&mut self) -> Result<()> {
+        let holder = self.role.holder;
+        apply_revoke_role(&self.stablecoin, &self.role, holder, self.authority.key())
     }
 }
+
+/// Validates and records a role revocation. The caller closes the RoleAccount.
+/// Shared by the direct path and admin-action execution (multisig).
+pub(crate) fn apply_revoke_role(
+    stablecoin: &Account<StablecoinState>,
+    role: &Account<RoleAccount>,
+    holder: Pubkey,
+    revoked_by: Pubkey,
+) -> Result<()> {
+    require!(
+        role.stablecoin == stablecoin.key() && role.holder == holder,
+        StablecoinError::InvalidRoleConfig
+    );
+
+    emit!(RoleRevoked {
+        stablecoin: stablecoin.key(),
+        holder,
+        revoked_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
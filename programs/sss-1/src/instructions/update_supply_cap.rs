@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
+use spl_token_2022::{extension::StateWithExtensions, state::Mint as SplMint};
 
 use crate::constants::*;
-use crate::events::SupplyCapUpdated;
+use crate::error::StablecoinError;
+use crate::events::{SupplyCapModeUpdated, SupplyCapUpdated};
+use crate::instructions::timelock::{
+    require_not_timelocked, supply_cap_change_is_sensitive, supply_cap_mode_change_is_sensitive,
+};
 use crate::state::*;
+use crate::SupplyCapMode;
 
 #[derive(Accounts)]
 pub struct UpdateSupplyCap<'info> {
@@ -26,36 +32,162 @@ pub struct UpdateSupplyCap<'info> {
     pub supply_cap: Account<'info, SupplyCap>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Token-2022 mint; required only in MintSupply mode (validated in current_supply).
+    /// Trailing so existing clients that omit it keep working.
+    pub mint: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSupplyCapMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+        constraint = stablecoin.authority == authority.key(),
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    /// Must already exist (created by update_supply_cap)
+    #[account(
+        mut,
+        seeds = [SUPPLY_CAP_SEED, stablecoin.key().as_ref()],
+        bump = supply_cap.bump,
+    )]
+    pub supply_cap: Account<'info, SupplyCap>,
+
+    /// CHECK: Token-2022 mint; required when switching to MintSupply (validated in current_supply)
+    pub mint: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> UpdateSupplyCap<'info> {
-    pub fn update_supply_cap(&mut self, cap: u64) -> Result<()> {
-        // cap == NO_SUPPLY_CAP_INDICATOR means "remove cap" — set to NO_SUPPLY_CAP (effectively no limit)
-        let effective_cap = if cap == NO_SUPPLY_CAP_INDICATOR {
-            NO_SUPPLY_CAP
-        } else {
-            cap
-        };
-
-        if effective_cap != NO_SUPPLY_CAP {
-            require!(
-                effective_cap >= self.stablecoin.total_minted,
-                crate::error::StablecoinError::SupplyCapExceeded
-            );
+    pub fn update_supply_cap(&mut self, cap: u64, bumps: &UpdateSupplyCapBumps) -> Result<()> {
+        require_not_timelocked(
+            &self.stablecoin,
+            supply_cap_change_is_sensitive(self.supply_cap.cap, cap),
+        )?;
+        let mint = self.mint.as_ref().map(|m| m.to_account_info());
+        apply_update_supply_cap(
+            &self.stablecoin,
+            &mut self.supply_cap,
+            cap,
+            bumps.supply_cap,
+            mint.as_ref(),
+            self.authority.key(),
+        )
+    }
+}
+
+impl<'info> UpdateSupplyCapMode<'info> {
+    pub fn update_supply_cap_mode(&mut self, mode: SupplyCapMode) -> Result<()> {
+        require_not_timelocked(
+            &self.stablecoin,
+            supply_cap_mode_change_is_sensitive(self.supply_cap.mode, mode),
+        )?;
+        let mint = self.mint.as_ref().map(|m| m.to_account_info());
+        apply_update_supply_cap_mode(
+            &self.stablecoin,
+            &mut self.supply_cap,
+            mode,
+            mint.as_ref(),
+            self.authority.key(),
+        )
+    }
+}
+
+/// Current supply as measured by `mode`, plus any legacy supply still to be swapped in.
+/// MintSupply reads the Token-2022 mint, which must be the stablecoin's mint.
+pub(crate) fn current_supply(
+    mode: SupplyCapMode,
+    stablecoin: &StablecoinState,
+    mint: Option<&AccountInfo>,
+) -> Result<u64> {
+    let supply = match mode {
+        SupplyCapMode::LifetimeMinted => stablecoin.total_minted,
+        SupplyCapMode::Circulating => stablecoin
+            .total_minted
+            .checked_sub(stablecoin.total_burned)
+            .ok_or(StablecoinError::MathOverflow)?,
+        SupplyCapMode::MintSupply => {
+            let mint = mint.ok_or(StablecoinError::MissingAdminAccount)?;
+            require_keys_eq!(mint.key(), stablecoin.mint, StablecoinError::Unauthorized);
+            require_keys_eq!(*mint.owner, spl_token_2022::ID, StablecoinError::Unauthorized);
+            let data = mint.try_borrow_data()?;
+            StateWithExtensions::<SplMint>::unpack(&data)?.base.supply
         }
+    };
+    Ok(supply
+        .checked_add(stablecoin.legacy_outstanding)
+        .ok_or(StablecoinError::MathOverflow)?)
+}
 
-        self.supply_cap.set_inner(SupplyCap {
-            cap: effective_cap,
-            bump: self.supply_cap.bump,
-        });
+/// Shared by the direct update_supply_cap path and admin-action execution (multisig).
+pub(crate) fn apply_update_supply_cap(
+    stablecoin: &Account<StablecoinState>,
+    supply_cap: &mut Account<SupplyCap>,
+    cap: u64,
+    bump: u8,
+    mint: Option<&AccountInfo>,
+    updated_by: Pubkey,
+) -> Result<()> {
+    // cap == NO_SUPPLY_CAP_INDICATOR means "remove cap" — set to NO_SUPPLY_CAP (effectively no limit)
+    let effective_cap = if cap == NO_SUPPLY_CAP_INDICATOR {
+        NO_SUPPLY_CAP
+    } else {
+        cap
+    };
 
-        emit!(SupplyCapUpdated {
-            stablecoin: self.stablecoin.key(),
-            new_cap: effective_cap,
-            updated_by: self.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+    if effective_cap != NO_SUPPLY_CAP {
+        require!(
+            effective_cap >= current_supply(supply_cap.mode, stablecoin, mint)?,
+            StablecoinError::SupplyCapExceeded
+        );
+    }
+
+    // Mode is preserved; a freshly created cap starts as LifetimeMinted.
+    supply_cap.cap = effective_cap;
+    supply_cap.bump = bump;
+
+    emit!(SupplyCapUpdated {
+        stablecoin: stablecoin.key(),
+        new_cap: effective_cap,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
 
-        Ok(())
+/// Shared by the direct update_supply_cap_mode path and admin-action execution.
+pub(crate) fn apply_update_supply_cap_mode(
+    stablecoin: &Account<StablecoinState>,
+    supply_cap: &mut Account<SupplyCap>,
+    mode: SupplyCapMode,
+    mint: Option<&AccountInfo>,
+    updated_by: Pubkey,
+) -> Result<()> {
+    // Admin execution may pass a freshly created (zeroed) PDA; a cap must be set first.
+    require!(
+        supply_cap.cap != NO_SUPPLY_CAP_INDICATOR,
+        StablecoinError::SupplyCapNotSet
+    );
+    if supply_cap.cap != NO_SUPPLY_CAP {
+        require!(
+            supply_cap.cap >= current_supply(mode, stablecoin, mint)?,
+            StablecoinError::SupplyCapExceeded
+        );
     }
+
+    supply_cap.mode = mode;
+
+    emit!(SupplyCapModeUpdated {
+        stablecoin: stablecoin.key(),
+        mode,
+        cap: supply_cap.cap,
+        updated_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
 }
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::error::StablecoinError;
+use crate::events::{VelocityExemptionAdded, VelocityExemptionRemoved, VelocityLimitsUpdated};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetVelocityLimits<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = blacklister,
+        space = 8 + VelocityConfig::INIT_SPACE,
+        seeds = [VELOCITY_CONFIG_SEED, stablecoin.key().as_ref()],
+        bump,
+    )]
+    pub velocity_config: Account<'info, VelocityConfig>,
+
+    /// CHECK: ExtraAccountMetaList PDA; its size shows whether the hook resolves the velocity
+    /// accounts yet
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, stablecoin.mint.as_ref()],
+        bump,
+        seeds::program = SSS_TRANSFER_HOOK_PROGRAM_ID,
+    )]
+    pub extra_account_metas: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddVelocityExemption<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = blacklister,
+        space = 8 + VelocityExemption::INIT_SPACE,
+        seeds = [VELOCITY_EXEMPTION_SEED, stablecoin.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub velocity_exemption: Account<'info, VelocityExemption>,
+
+    /// CHECK: The wallet (source token account owner) being exempted
+    pub wallet: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveVelocityExemption<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [STABLECOIN_SEED, stablecoin.mint.as_ref()],
+        bump = stablecoin.bump,
+    )]
+    pub stablecoin: Account<'info, StablecoinState>,
+
+    #[account(
+        seeds = [ROLE_SEED, stablecoin.key().as_ref(), blacklister.key().as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        close = blacklister,
+        seeds = [VELOCITY_EXEMPTION_SEED, stablecoin.key().as_ref(), wallet.key().as_ref()],
+        bump = velocity_exemption.bump,
+    )]
+    pub velocity_exemption: Account<'info, VelocityExemption>,
+
+    /// CHECK: The wallet losing its exemption
+    pub wallet: AccountInfo<'info>,
+}
+
+impl<'info> SetVelocityLimits<'info> {
+    /// Set the per-transfer and rolling 24h limits (0 disables either). Velocity limits are
+    /// an AML control, so they belong to the blacklister (compliance) role.
+    pub fn set_velocity_limits(
+        &mut self,
+        max_per_transfer: u64,
+        max_per_window: u64,
+        bumps: SetVelocityLimitsBumps,
+    ) -> Result<()> {
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+        // A list without the velocity metas would silently skip the limits
+        require!(
+            self.extra_account_metas.data_len() >= VELOCITY_EXTRA_ACCOUNT_METAS_LEN,
+            StablecoinError::VelocityHookAccountsOutdated
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        self.velocity_config.set_inner(VelocityConfig {
+            stablecoin: self.stablecoin.key(),
+            max_per_transfer,
+            max_per_window,
+            updated_by: self.blacklister.key(),
+            updated_at: now,
+            bump: bumps.velocity_config,
+        });
+
+        emit!(VelocityLimitsUpdated {
+            stablecoin: self.stablecoin.key(),
+            max_per_transfer,
+            max_per_window,
+            updated_by: self.blacklister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> AddVelocityExemption<'info> {
+    pub fn add_velocity_exemption(&mut self, bumps: AddVelocityExemptionBumps) -> Result<()> {
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+
+        // Same init_if_needed pattern as add_to_blacklist
+        if self.velocity_exemption.wallet != Pubkey::default() {
+            return Err(StablecoinError::AlreadyVelocityExempt.into());
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        self.velocity_exemption.set_inner(VelocityExemption {
+            stablecoin: self.stablecoin.key(),
+            wallet: self.wallet.key(),
+            exempted_at: now,
+            exempted_by: self.blacklister.key(),
+            bump: bumps.velocity_exemption,
+        });
+
+        emit!(VelocityExemptionAdded {
+            stablecoin: self.stablecoin.key(),
+            wallet: self.wallet.key(),
+            exempted_by: self.blacklister.key(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> RemoveVelocityExemption<'info> {
+    pub fn remove_velocity_exemption(&mut self) -> Result<()> {
+        require!(
+            self.stablecoin.is_sss2(),
+            StablecoinError::ComplianceNotEnabled
+        );
+        require!(
+            self.role.roles.is_blacklister,
+            StablecoinError::Unauthorized
+        );
+
+        emit!(VelocityExemptionRemoved {
+            stablecoin: self.stablecoin.key(),
+            wallet: self.wallet.key(),
+            removed_by: self.blacklister.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
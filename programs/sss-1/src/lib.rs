@@ -20,22 +20,91 @@ mod tests {
     use super::*;
     use crate::error::StablecoinError;
 
+    /// StablecoinState as the deployed program serialized it (no header, none of the fields
+    /// added since), at its allocated size.
+    fn baseline_stablecoin_state(
+        authority: Pubkey,
+        mint: Pubkey,
+        sss2: bool,
+        paused: bool,
+    ) -> Vec<u8> {
+        let mut data = LEGACY_STABLECOIN_STATE_DISCRIMINATOR.to_vec();
+        (authority, mint, "Compliant USD".to_string(), "cUSD".to_string(), String::new())
+            .serialize(&mut data)
+            .unwrap();
+        (6u8, sss2, sss2, true, paused, 5_000u64, 1_000u64, 254u8)
+            .serialize(&mut data)
+            .unwrap();
+        data.resize(LegacyStablecoinState::SPACE, 0);
+        data
+    }
+
+    fn migrated_stablecoin_state(data: &[u8]) -> StablecoinState {
+        let upgraded = upgrade_stablecoin_state(data).unwrap();
+        StablecoinState::try_deserialize(&mut &upgraded.data[..]).unwrap()
+    }
+
+    /// A current-version SSS-1 StablecoinState with every optional feature unset; tests
+    /// override only the fields they exercise.
+    fn sample_state() -> StablecoinState {
+        StablecoinState {
+            version: STABLECOIN_STATE_VERSION,
+            authority: Pubkey::default(),
+            mint: Pubkey::default(),
+            name: String::new(),
+            symbol: String::new(),
+            uri: String::new(),
+            decimals: 6,
+            enable_permanent_delegate: false,
+            enable_transfer_hook: false,
+            default_account_frozen: false,
+            paused: false,
+            pause_flags: 0,
+            paused_until: 0,
+            enable_allowlist: false,
+            total_minted: 0,
+            total_burned: 0,
+            pending_authority: Pubkey::default(),
+            timelock_delay: 0,
+            guardian: Pubkey::default(),
+            queued_change_count: 0,
+            minter_epoch: 0,
+            treasury: Pubkey::default(),
+            bump: 0,
+            successor_mint: Pubkey::default(),
+            legacy_mint: Pubkey::default(),
+            legacy_outstanding: 0,
+            reserved: [0; STABLECOIN_STATE_RESERVED_LEN],
+        }
+    }
+
     #[test]
     fn constants_seeds_and_limits() {
         assert_eq!(STABLECOIN_SEED, b"stablecoin");
         assert_eq!(ROLE_SEED, b"role");
         assert_eq!(MINTER_SEED, b"minter");
         assert_eq!(BLACKLIST_SEED, b"blacklist");
+        assert_eq!(ALLOWLIST_SEED, b"allowlist");
         assert_eq!(SUPPLY_CAP_SEED, b"supply_cap");
         assert_eq!(MAX_NAME_LEN, 32);
         assert_eq!(MAX_SYMBOL_LEN, 10);
         assert_eq!(MAX_URI_LEN, 200);
         assert_eq!(MAX_REASON_LEN, 100);
+        assert_eq!(MAX_REDEMPTION_REF_LEN, 64);
+        assert_eq!(MAX_BATCH_SIZE, 10);
+        assert_eq!(MAX_METADATA_FIELDS, 8);
+        assert_eq!(MAX_METADATA_KEY_LEN, 32);
+        assert_eq!(MAX_METADATA_VALUE_LEN, MAX_URI_LEN);
+        let hash =
+            anchor_lang::solana_program::hash::hash(b"global:initialize_extra_account_meta_list");
+        assert_eq!(HOOK_INITIALIZE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR, hash.to_bytes()[..8]);
+        let hash = anchor_lang::solana_program::hash::hash(b"global:update_extra_account_meta_list");
+        assert_eq!(HOOK_UPDATE_EXTRA_ACCOUNT_METAS_DISCRIMINATOR, hash.to_bytes()[..8]);
     }
 
     #[test]
     fn role_flags_len_and_serialization() {
-        assert_eq!(RoleFlags::LEN, 6);
+        assert_eq!(RoleFlags::LEN, 7);
         let all = RoleFlags {
             is_minter: true,
             is_burner: true,
@@ -43,50 +112,672 @@ mod tests {
             is_freezer: true,
             is_blacklister: true,
             is_seizer: true,
+            is_allowlister: true,
         };
         let bytes = all.try_to_vec().unwrap();
-        assert_eq!(bytes.len(), 6);
+        assert_eq!(bytes.len(), 7);
         let decoded: RoleFlags = RoleFlags::deserialize(&mut &bytes[..]).unwrap();
-        assert!(decoded.is_minter && decoded.is_seizer);
+        assert!(decoded.is_minter && decoded.is_seizer && decoded.is_allowlister);
     }
 
     #[test]
     fn stablecoin_state_is_sss2() {
-        let base = StablecoinState {
-            authority: Pubkey::default(),
-            mint: Pubkey::default(),
-            name: String::new(),
-            symbol: String::new(),
-            uri: String::new(),
-            decimals: 6,
-            enable_permanent_delegate: false,
-            enable_transfer_hook: false,
-            default_account_frozen: false,
-            paused: false,
-            total_minted: 0,
-            total_burned: 0,
-            bump: 0,
-        };
+        let base = sample_state();
         assert!(!base.is_sss2());
+        assert!(!base.has_pending_authority());
 
         let sss2_like = StablecoinState {
-            authority: Pubkey::default(),
-            mint: Pubkey::default(),
-            name: String::new(),
-            symbol: String::new(),
-            uri: String::new(),
-            decimals: 6,
             enable_permanent_delegate: true,
             enable_transfer_hook: true,
             default_account_frozen: true,
-            paused: false,
-            total_minted: 0,
-            total_burned: 0,
-            bump: 0,
+            ..base.clone()
         };
         assert!(sss2_like.is_sss2());
     }
 
+    #[test]
+    fn multisig_membership_and_approvals() {
+        let members = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let multisig = MultisigConfig {
+            stablecoin: Pubkey::default(),
+            members: members.clone(),
+            threshold: 2,
+            proposal_count: 0,
+            config_version: 0,
+            bump: 0,
+        };
+        assert_eq!(multisig.member_index(&members[2]), Some(2));
+        assert_eq!(multisig.member_index(&Pubkey::new_unique()), None);
+
+        let holder = Pubkey::new_unique();
+        let action = AdminAction::UpdateRoles {
+            holder,
+            roles: RoleFlags::default(),
+        };
+        assert_eq!(action.target(), holder);
+        assert_eq!(AdminAction::UpdateSupplyCap { cap: 1 }.target(), Pubkey::default());
+        let bytes = action.try_to_vec().unwrap();
+        assert_eq!(AdminAction::deserialize(&mut &bytes[..]).unwrap(), action);
+
+        let proposal = AdminProposal {
+            multisig: Pubkey::default(),
+            id: 0,
+            proposer: members[0],
+            action,
+            approvals: 0b101,
+            config_version: 0,
+            created_at: 0,
+            expires_at: 100,
+            executed: false,
+            bump: 0,
+        };
+        assert_eq!(proposal.approval_count(), 2);
+        assert!(!proposal.is_expired(99));
+        assert!(proposal.is_expired(100));
+
+        // A metadata update at every length bound still fits a proposal.
+        let field = MetadataField {
+            key: "k".repeat(MAX_METADATA_KEY_LEN),
+            value: "v".repeat(MAX_METADATA_VALUE_LEN),
+        };
+        let params = UpdateMetadataParams {
+            name: Some("n".repeat(MAX_NAME_LEN)),
+            symbol: Some("s".repeat(MAX_SYMBOL_LEN)),
+            uri: Some("u".repeat(MAX_URI_LEN)),
+            fields: vec![field; MAX_METADATA_FIELDS],
+        };
+        let proposal = AdminProposal {
+            action: AdminAction::UpdateMetadata { params },
+            ..proposal
+        };
+        assert!(proposal.try_to_vec().unwrap().len() <= AdminProposal::INIT_SPACE);
+
+        // Authority-only actions outside the config set execute directly, never queued.
+        for action in [
+            proposal.action,
+            AdminAction::LinkSuccessorMint {
+                successor_mint: Pubkey::new_unique(),
+            },
+            AdminAction::InitializeTransferHookAccounts,
+            AdminAction::UpdateTransferHookAccounts,
+            AdminAction::ExtendEmergencyPause { duration: 60 },
+            AdminAction::CancelChange {
+                change: Pubkey::new_unique(),
+            },
+        ] {
+            assert!(!is_timelockable(&action));
+        }
+
+        // Clients encode CancelChange by its variant index.
+        let cancel = AdminAction::CancelChange {
+            change: Pubkey::new_unique(),
+        };
+        assert_eq!(cancel.try_to_vec().unwrap()[0], 18);
+    }
+
+    #[test]
+    fn timelock_sensitivity_rules() {
+        let minter_only = RoleFlags {
+            is_minter: true,
+            ..RoleFlags::default()
+        };
+        assert!(roles_change_is_sensitive(&RoleFlags::default(), &minter_only));
+        assert!(!roles_change_is_sensitive(&minter_only, &RoleFlags::default()));
+        assert!(quota_change_is_sensitive(100, 101));
+        assert!(!quota_change_is_sensitive(100, 50));
+        // An unset cap (0) is treated as unlimited, so setting any cap tightens it.
+        assert!(!supply_cap_change_is_sensitive(0, 1_000));
+        assert!(supply_cap_change_is_sensitive(1_000, 0));
+        assert!(supply_cap_change_is_sensitive(1_000, 2_000));
+        // Registering the first treasury only narrows seize destinations; redirecting or
+        // clearing it is sensitive.
+        let treasury = Pubkey::new_unique();
+        assert!(!treasury_change_is_sensitive(Pubkey::default(), treasury));
+        assert!(!treasury_change_is_sensitive(treasury, treasury));
+        assert!(treasury_change_is_sensitive(treasury, Pubkey::new_unique()));
+        assert!(treasury_change_is_sensitive(treasury, Pubkey::default()));
+
+        let guardian = Pubkey::new_unique();
+        let mut state = sample_state();
+        assert!(!state.is_timelocked());
+        assert!(!timelock_change_is_sensitive(&state, 3600, guardian));
+        state.timelock_delay = 3600;
+        state.guardian = guardian;
+        assert!(state.is_timelocked());
+        assert!(timelock_change_is_sensitive(&state, 60, guardian));
+        assert!(timelock_change_is_sensitive(&state, 3600, Pubkey::default()));
+        assert!(!timelock_change_is_sensitive(&state, 7200, guardian));
+
+        let queued = QueuedChange {
+            stablecoin: Pubkey::default(),
+            id: 0,
+            action: AdminAction::UpdateSupplyCap { cap: 1 },
+            eta: 100,
+            queued_by: Pubkey::default(),
+            rent_payer: Pubkey::default(),
+            bump: 0,
+        };
+        assert!(!queued.is_ready(99));
+        assert!(queued.is_ready(100));
+    }
+
+    #[test]
+    fn minter_allowance_windows() {
+        let mut info = MinterInfo {
+            stablecoin: Pubkey::default(),
+            minter: Pubkey::default(),
+            quota: 0,
+            minted_amount: 500,
+            window_seconds: 0,
+            window_limit: 0,
+            window_start: 0,
+            window_minted: 0,
+            epoch: 0,
+            bump: 0,
+            version: MINTER_INFO_VERSION,
+            reserved: [0; MINTER_INFO_RESERVED_LEN],
+        };
+        assert!(!info.is_periodic());
+        assert!(allowance_change_is_sensitive(&info, 86_400, 100));
+        assert!(!allowance_change_is_sensitive(&info, 0, 0));
+
+        info.window_seconds = 100;
+        info.window_limit = 50;
+        info.window_start = 1_000;
+        info.window_minted = 40;
+        assert_eq!(info.current_window_start(1_099), 1_000);
+        assert_eq!(info.minted_in_window(1_099), 40);
+        // Windows stay on the grid anchored at window_start.
+        assert_eq!(info.current_window_start(1_100), 1_100);
+        assert_eq!(info.current_window_start(1_350), 1_300);
+        assert_eq!(info.minted_in_window(1_350), 0);
+
+        assert!(!allowance_change_is_sensitive(&info, 200, 50));
+        assert!(allowance_change_is_sensitive(&info, 100, 60));
+        assert!(allowance_change_is_sensitive(&info, 50, 50));
+        assert!(allowance_change_is_sensitive(&info, 0, 0));
+    }
+
+    #[test]
+    fn supply_cap_modes() {
+        assert_eq!(SupplyCapMode::default(), SupplyCapMode::LifetimeMinted);
+        for mode in [
+            SupplyCapMode::LifetimeMinted,
+            SupplyCapMode::Circulating,
+            SupplyCapMode::MintSupply,
+        ] {
+            assert_eq!(SupplyCapMode::from_u8(mode as u8), Some(mode));
+            assert_eq!(mode.try_to_vec().unwrap(), vec![mode as u8]);
+        }
+        assert_eq!(SupplyCapMode::from_u8(3), None);
+        // The mode byte sits right after the bump so legacy 17-byte accounts parse unchanged.
+        assert_eq!(SUPPLY_CAP_MODE_OFFSET, 17);
+        assert_eq!(8 + SupplyCap::INIT_SPACE, SUPPLY_CAP_MODE_OFFSET + 1);
+
+        assert!(supply_cap_mode_change_is_sensitive(
+            SupplyCapMode::LifetimeMinted,
+            SupplyCapMode::Circulating
+        ));
+        assert!(!supply_cap_mode_change_is_sensitive(
+            SupplyCapMode::MintSupply,
+            SupplyCapMode::LifetimeMinted
+        ));
+    }
+
+    #[test]
+    fn pause_scopes() {
+        assert_eq!(PAUSE_ALL_SCOPES, 0b1111);
+        let mut state = StablecoinState {
+            enable_permanent_delegate: true,
+            enable_transfer_hook: true,
+            pause_flags: PAUSE_MINT,
+            ..sample_state()
+        };
+        assert!(state.is_paused_for(PAUSE_MINT, 0));
+        assert!(!state.is_paused_for(PAUSE_BURN, 0));
+        assert!(!state.is_paused_for(PAUSE_TRANSFER, 0));
+        state.paused = true;
+        assert!(state.is_paused_for(PAUSE_BURN, 0) && state.is_paused_for(PAUSE_FREEZE, 0));
+        // Freeze and thaw stay available during an incident; only their own scope stops them.
+        assert!(!state.is_freeze_paused());
+
+        state.paused = false;
+        state.pause_flags = 0;
+        state.paused_until = 1_000;
+        assert!(state.is_emergency_paused(999));
+        assert!(state.is_paused_for(PAUSE_MINT, 999));
+        assert!(!state.is_freeze_paused());
+        assert!(!state.is_paused_for(PAUSE_MINT, 1_000));
+        // Once it lapses, a guardian or pauser must wait out the cooldown to trigger another.
+        assert!(state.is_emergency_cooldown(1_000 + EMERGENCY_PAUSE_COOLDOWN - 1));
+        assert!(!state.is_emergency_cooldown(1_000 + EMERGENCY_PAUSE_COOLDOWN));
+        state.pause_flags = PAUSE_FREEZE;
+        assert!(state.is_freeze_paused());
+
+        // The hook reads paused, pause_flags and paused_until from the fixed header.
+        state.pause_flags = PAUSE_TRANSFER;
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        assert_eq!(data[STABLECOIN_PAUSED_OFFSET], 0);
+        assert_eq!(data[STABLECOIN_PAUSE_FLAGS_OFFSET], PAUSE_TRANSFER);
+        assert_eq!(
+            data[STABLECOIN_PAUSED_UNTIL_OFFSET..STABLECOIN_PAUSED_UNTIL_OFFSET + 8],
+            1_000i64.to_le_bytes()
+        );
+
+        // ...and the allowlist flag.
+        assert!(!state.is_allowlist_mode());
+        state.enable_allowlist = true;
+        assert!(state.is_allowlist_mode());
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        assert_eq!(data[STABLECOIN_ALLOWLIST_OFFSET], 1);
+    }
+
+    #[test]
+    fn baseline_pause_carries_over_without_scopes() {
+        // A globally paused deployed stablecoin stays paused in every scope, and unpausing it
+        // leaves no scope behind: pause_flags did not exist, so it starts at 0.
+        let mint = Pubkey::new_unique();
+        let data = baseline_stablecoin_state(Pubkey::new_unique(), mint, true, true);
+        let mut state = migrated_stablecoin_state(&data);
+        assert_eq!(state.pause_flags, 0);
+        for scope in [PAUSE_MINT, PAUSE_BURN, PAUSE_TRANSFER, PAUSE_FREEZE] {
+            assert!(state.is_paused_for(scope, 0));
+        }
+        state.paused = false;
+        assert!(!state.is_paused_for(PAUSE_ALL_SCOPES, 0));
+
+        let data = baseline_stablecoin_state(Pubkey::new_unique(), mint, true, false);
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!(upgraded.data[STABLECOIN_PAUSED_OFFSET], 0);
+        assert_eq!(upgraded.data[STABLECOIN_PAUSE_FLAGS_OFFSET], 0);
+    }
+
+    #[test]
+    fn baseline_state_has_no_emergency_pause() {
+        // paused_until did not exist on deployed stablecoins; stale bytes in the slack must
+        // not turn into an emergency pause the guardian never triggered.
+        let mint = Pubkey::new_unique();
+        let mut data = baseline_stablecoin_state(Pubkey::new_unique(), mint, true, false);
+        data.iter_mut().rev().take(16).for_each(|b| *b = 0xFF);
+        let state = migrated_stablecoin_state(&data);
+        assert_eq!(state.paused_until, 0);
+        assert!(!state.is_emergency_paused(0) && !state.is_paused_for(PAUSE_ALL_SCOPES, 0));
+
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!(
+            upgraded.data[STABLECOIN_PAUSED_UNTIL_OFFSET..STABLECOIN_HEADER_LEN],
+            0i64.to_le_bytes()
+        );
+    }
+
+    #[test]
+    fn baseline_accounts_gain_no_allowlist_access() {
+        // Deployed RoleAccounts end in the bump where is_allowlister now sits; whatever the
+        // bump, no holder becomes an allowlister and the bump survives.
+        let (stablecoin, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+        for bump in [0u8, 1, 254, 255] {
+            let mut data = RoleAccount::DISCRIMINATOR.to_vec();
+            (stablecoin, holder, [true; 6], bump).serialize(&mut data).unwrap();
+            let upgraded = upgrade_account::<RoleAccount>(&data).unwrap();
+            let role = RoleAccount::try_deserialize(&mut &upgraded.data[..]).unwrap();
+            assert!(role.roles.is_minter && role.roles.is_seizer);
+            assert!(!role.roles.is_allowlister);
+            assert_eq!(role.bump, bump);
+        }
+
+        // Deployed SSS-2 stablecoins stay in blacklist mode.
+        let mint = Pubkey::new_unique();
+        let data = baseline_stablecoin_state(Pubkey::new_unique(), mint, true, false);
+        let state = migrated_stablecoin_state(&data);
+        assert!(state.is_sss2() && !state.is_allowlist_mode());
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!(upgraded.data[STABLECOIN_ALLOWLIST_OFFSET], 0);
+    }
+
+    #[test]
+    fn blacklist_entry_expiry() {
+        let mut entry = BlacklistEntry {
+            stablecoin: Pubkey::default(),
+            address: Pubkey::default(),
+            expires_at: 0,
+            reason_code: BlacklistReason::Ofac,
+            reason: "SDN match".to_string(),
+            blacklisted_at: 0,
+            blacklisted_by: Pubkey::default(),
+            bump: 0,
+            version: BLACKLIST_ENTRY_VERSION,
+            reserved: [0; BLACKLIST_ENTRY_RESERVED_LEN],
+        };
+        assert!(entry.is_active(i64::MAX));
+        entry.expires_at = 1_000;
+        assert!(entry.is_active(999));
+        assert!(!entry.is_active(1_000));
+
+        // The hook reads expires_at at a fixed offset, ahead of the variable-length reason.
+        let mut data = BlacklistEntry::DISCRIMINATOR.to_vec();
+        entry.serialize(&mut data).unwrap();
+        assert!(data.len() <= BlacklistEntry::LEN);
+        assert_eq!(
+            data[BLACKLIST_EXPIRES_AT_OFFSET..BLACKLIST_EXPIRES_AT_OFFSET + 8],
+            1_000i64.to_le_bytes()
+        );
+        assert_eq!(data[BLACKLIST_EXPIRES_AT_OFFSET + 8], BlacklistReason::Ofac as u8);
+    }
+
+    #[test]
+    fn velocity_config_layout() {
+        let config = VelocityConfig {
+            stablecoin: Pubkey::new_unique(),
+            max_per_transfer: 1_000,
+            max_per_window: 5_000,
+            updated_by: Pubkey::default(),
+            updated_at: 0,
+            bump: 0,
+        };
+        // The hook reads both limits at fixed offsets.
+        let mut data = VelocityConfig::DISCRIMINATOR.to_vec();
+        config.serialize(&mut data).unwrap();
+        assert_eq!(
+            data[VELOCITY_MAX_PER_TRANSFER_OFFSET..VELOCITY_MAX_PER_TRANSFER_OFFSET + 8],
+            1_000u64.to_le_bytes()
+        );
+        assert_eq!(
+            data[VELOCITY_MAX_PER_WINDOW_OFFSET..VELOCITY_MAX_PER_WINDOW_OFFSET + 8],
+            5_000u64.to_le_bytes()
+        );
+        assert_eq!(VELOCITY_CONFIG_SEED, b"velocity_config");
+        assert_eq!(VELOCITY_EXEMPTION_SEED, b"velocity_exempt");
+    }
+
+    #[test]
+    fn stablecoin_state_header_and_migration() {
+        // StablecoinState as the deployed program serialized it: no header, and none of the
+        // fields added since (pause scopes, emergency pause, allowlist, timelock, treasury).
+        assert_eq!(LegacyStablecoinState::SPACE, 348);
+        let (authority, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = baseline_stablecoin_state(authority, mint, true, true);
+
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!((upgraded.from_version, upgraded.to_version), (0, STABLECOIN_STATE_VERSION));
+        assert_eq!(upgraded.space, StablecoinState::SPACE);
+        let state = StablecoinState::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!(state.version, STABLECOIN_STATE_VERSION);
+        assert_eq!((state.authority, state.mint), (authority, mint));
+        assert_eq!((state.name.as_str(), state.symbol.as_str()), ("Compliant USD", "cUSD"));
+        assert_eq!(state.decimals, 6);
+        assert!(state.is_sss2() && state.default_account_frozen && state.paused);
+        assert_eq!((state.total_minted, state.total_burned), (5_000, 1_000));
+        assert_eq!(state.bump, 254);
+        assert_eq!((state.pause_flags, state.paused_until), (0, 0));
+        assert!(!state.enable_allowlist && !state.is_timelocked());
+        assert!(!state.has_pending_authority() && !state.has_treasury());
+        assert_eq!((state.guardian, state.minter_epoch), (Pubkey::default(), 0));
+
+        // Any other unversioned size is rejected instead of being read at the wrong offsets.
+        let mut longer = data.clone();
+        longer.push(0);
+        assert!(upgrade_stablecoin_state(&longer).is_err());
+
+        let state = StablecoinState {
+            pause_flags: PAUSE_TRANSFER,
+            paused_until: 1_700_000_000,
+            enable_allowlist: true,
+            ..state
+        };
+
+        // The hook reads the header at fixed offsets, regardless of string lengths.
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        assert_eq!(&data[..8], STABLECOIN_STATE_DISCRIMINATOR);
+        assert_ne!(&data[..8], LEGACY_STABLECOIN_STATE_DISCRIMINATOR);
+        assert_eq!(data[STABLECOIN_VERSION_OFFSET], STABLECOIN_STATE_VERSION);
+        assert_eq!(data[STABLECOIN_PAUSED_OFFSET], 1);
+        assert_eq!(data[STABLECOIN_PAUSE_FLAGS_OFFSET], PAUSE_TRANSFER);
+        assert_eq!(data[STABLECOIN_PERMANENT_DELEGATE_OFFSET], 1);
+        assert_eq!(data[STABLECOIN_TRANSFER_HOOK_OFFSET], 1);
+        assert_eq!(data[STABLECOIN_ALLOWLIST_OFFSET], 1);
+        assert_eq!(
+            data[STABLECOIN_PAUSED_UNTIL_OFFSET..STABLECOIN_HEADER_LEN],
+            1_700_000_000i64.to_le_bytes()
+        );
+        assert_eq!(&data[STABLECOIN_HEADER_LEN..STABLECOIN_HEADER_LEN + 32], state.authority.as_ref());
+        assert!(data.len() <= 8 + StablecoinState::INIT_SPACE);
+    }
+
+    #[test]
+    fn unversioned_accounts_upgrade_in_place() {
+        let stablecoin = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+
+        // RoleAccount as the deployed program wrote it: six role flags, then the bump, which
+        // must not be read as the later `is_allowlister` flag.
+        let mut data = RoleAccount::DISCRIMINATOR.to_vec();
+        (stablecoin, holder, [true, false, false, false, false, true], 1u8)
+            .serialize(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), RoleAccountV0::LEN);
+        let upgraded = upgrade_account::<RoleAccount>(&data).unwrap();
+        assert_eq!(upgraded.account_type, VersionedAccountType::RoleAccount);
+        assert_eq!((upgraded.from_version, upgraded.to_version), (0, ROLE_ACCOUNT_VERSION));
+        assert_eq!(upgraded.space, RoleAccount::LEN);
+        let role = RoleAccount::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!((role.stablecoin, role.holder), (stablecoin, holder));
+        let roles = RoleFlags {
+            is_minter: true,
+            is_seizer: true,
+            ..RoleFlags::default()
+        };
+        assert_eq!(role.roles, roles);
+        assert_eq!((role.bump, role.version), (1, ROLE_ACCOUNT_VERSION));
+
+        // Once stored at the new size it is current.
+        let mut stored = upgraded.data.clone();
+        stored.resize(upgraded.space, 0);
+        assert_eq!(RoleAccount::stored_version(&stored).unwrap(), ROLE_ACCOUNT_VERSION);
+        assert!(upgrade_account::<RoleAccount>(&stored).is_err());
+
+        // MinterInfo keeps its lifetime quota and counter; the bump is not read as a window.
+        let mut data = MinterInfo::DISCRIMINATOR.to_vec();
+        (stablecoin, holder, 1_000u64, 400u64, 252u8).serialize(&mut data).unwrap();
+        assert_eq!(data.len(), MinterInfoV0::LEN);
+        let upgraded = upgrade_account::<MinterInfo>(&data).unwrap();
+        let info = MinterInfo::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!((info.quota, info.minted_amount, info.epoch), (1_000, 400, 0));
+        assert!(!info.is_periodic());
+        assert_eq!((info.bump, info.version), (252, MINTER_INFO_VERSION));
+
+        // BlacklistEntry had the reason ahead of blacklisted_at. A shortened reason leaves
+        // stale bytes in the slack; they are not read.
+        let legacy_entry = |reason: &str| {
+            let mut data = BlacklistEntry::DISCRIMINATOR.to_vec();
+            (stablecoin, holder, reason.to_string(), 1_000i64, holder, 251u8)
+                .serialize(&mut data)
+                .unwrap();
+            data
+        };
+        let mut data = legacy_entry(&"x".repeat(MAX_REASON_LEN));
+        assert_eq!(data.len(), BlacklistEntryV0::LEN);
+        let shorter = legacy_entry("fraud ring");
+        data[..shorter.len()].copy_from_slice(&shorter);
+        let upgraded = upgrade_account::<BlacklistEntry>(&data).unwrap();
+        assert_eq!(upgraded.space, BlacklistEntry::LEN);
+        let entry = BlacklistEntry::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!(entry.reason, "fraud ring");
+        assert_eq!(entry.reason_code, BlacklistReason::Internal);
+        assert_eq!((entry.blacklisted_at, entry.blacklisted_by), (1_000, holder));
+        assert!(entry.is_active(i64::MAX));
+        assert_eq!((entry.bump, entry.version), (251, BLACKLIST_ENTRY_VERSION));
+        assert_eq!(entry.reserved, [0; BLACKLIST_ENTRY_RESERVED_LEN]);
+        // The hook finds "never expires" at its fixed offset.
+        assert_eq!(
+            upgraded.data[BLACKLIST_EXPIRES_AT_OFFSET..BLACKLIST_EXPIRES_AT_OFFSET + 8],
+            0i64.to_le_bytes()
+        );
+
+        // Unversioned data of any other size is rejected instead of being misread.
+        let mut data = MinterInfo::DISCRIMINATOR.to_vec();
+        (stablecoin, holder, 1_000u64, 400u64, 100i64, 50u64, 252u8)
+            .serialize(&mut data)
+            .unwrap();
+        assert!(upgrade_account::<MinterInfo>(&data).is_err());
+        assert!(upgrade_account::<MinterInfo>(RoleAccount::DISCRIMINATOR).is_err());
+    }
+
+    #[test]
+    fn supply_caps_without_mode_byte_upgrade() {
+        // SupplyCap as deployed before cap modes: cap and bump only.
+        let mut data = SupplyCap::DISCRIMINATOR.to_vec();
+        (1_000_000u64, 254u8).serialize(&mut data).unwrap();
+        assert_eq!(data.len(), SupplyCapV0::LEN);
+        assert_eq!(SupplyCap::stored_version(&data).unwrap(), 0);
+        assert!(SupplyCap::try_deserialize(&mut &data[..]).is_err());
+
+        let upgraded = upgrade_account::<SupplyCap>(&data).unwrap();
+        assert_eq!(upgraded.account_type, VersionedAccountType::SupplyCap);
+        assert_eq!((upgraded.from_version, upgraded.to_version), (0, SUPPLY_CAP_VERSION));
+        assert_eq!(upgraded.space, SupplyCap::SPACE);
+        let cap = SupplyCap::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!((cap.cap, cap.bump), (1_000_000, 254));
+        assert_eq!(cap.mode, SupplyCapMode::LifetimeMinted);
+        // mint_tokens reads the same values at its fixed offsets.
+        assert_eq!(
+            upgraded.data[SUPPLY_CAP_VALUE_OFFSET..MIN_SUPPLY_CAP_DATA_LEN],
+            1_000_000u64.to_le_bytes()
+        );
+        assert_eq!(upgraded.data[SUPPLY_CAP_MODE_OFFSET], SupplyCapMode::LifetimeMinted as u8);
+
+        // A cap with its mode byte is current.
+        assert!(upgrade_account::<SupplyCap>(&upgraded.data).is_err());
+    }
+
+    #[test]
+    fn stablecoin_state_upgrades_from_version_1() {
+        let state = StablecoinState {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: "Compliant USD".to_string(),
+            symbol: "cUSD".to_string(),
+            uri: "https://example.com/cusd.json".to_string(),
+            enable_permanent_delegate: true,
+            enable_transfer_hook: true,
+            pause_flags: PAUSE_MINT,
+            total_minted: 9_000,
+            total_burned: 2_000,
+            timelock_delay: 3_600,
+            guardian: Pubkey::new_unique(),
+            queued_change_count: 4,
+            minter_epoch: 1,
+            treasury: Pubkey::new_unique(),
+            bump: 250,
+            ..sample_state()
+        };
+
+        // Version 1 is the same layout without the tail after `bump` (version 2 added it as
+        // reserved bytes), allocated that much smaller; the slack after the strings may hold
+        // anything.
+        let tail = 32 + 32 + 8 + STABLECOIN_STATE_RESERVED_LEN;
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        data.truncate(data.len() - tail);
+        data[STABLECOIN_VERSION_OFFSET] = 1;
+        data.resize(StablecoinState::SPACE - tail, 0xAA);
+
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!((upgraded.from_version, upgraded.to_version), (1, STABLECOIN_STATE_VERSION));
+        assert_eq!(upgraded.space, StablecoinState::SPACE);
+        let migrated = StablecoinState::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert_eq!(migrated.version, STABLECOIN_STATE_VERSION);
+        assert_eq!(migrated.reserved, [0; STABLECOIN_STATE_RESERVED_LEN]);
+        // The slack read into the link fields is cleared, so the mint is not left linked
+        assert!(!migrated.has_successor() && !migrated.has_legacy_mint());
+        assert_eq!(migrated.legacy_outstanding, 0);
+        assert_eq!(migrated.uri, state.uri);
+        assert_eq!(migrated.guardian, state.guardian);
+        assert_eq!(migrated.treasury, state.treasury);
+        assert!(migrated.is_sss2() && migrated.is_timelocked());
+        assert!(migrated.is_paused_for(PAUSE_MINT, 0) && !migrated.is_paused_for(PAUSE_BURN, 0));
+
+        let mut stored = upgraded.data.clone();
+        stored.resize(upgraded.space, 0);
+        assert!(upgrade_stablecoin_state(&stored).is_err());
+        assert!(upgrade_stablecoin_state(RoleAccount::DISCRIMINATOR).is_err());
+    }
+
+    #[test]
+    fn legacy_successor_link() {
+        let mut legacy = StablecoinState {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            total_minted: 5_000,
+            total_burned: 1_000,
+            ..sample_state()
+        };
+        assert!(!legacy.has_successor() && !legacy.is_paused_for(PAUSE_MINT, 0));
+
+        // Linking pauses legacy minting for good; burns (and so swaps) stay open.
+        legacy.successor_mint = Pubkey::new_unique();
+        assert!(legacy.has_successor());
+        assert!(legacy.is_paused_for(PAUSE_MINT, 0) && !legacy.is_paused_for(PAUSE_BURN, 0));
+
+        // Outstanding legacy supply counts towards the successor's supply cap in every mode
+        // that can be computed without the mint account.
+        let successor = StablecoinState {
+            enable_permanent_delegate: true,
+            enable_transfer_hook: true,
+            total_minted: 700,
+            total_burned: 200,
+            successor_mint: Pubkey::default(),
+            legacy_mint: legacy.mint,
+            legacy_outstanding: 3_300,
+            ..legacy.clone()
+        };
+        assert!(successor.has_legacy_mint() && !successor.is_paused_for(PAUSE_MINT, 0));
+        assert_eq!(
+            current_supply(SupplyCapMode::LifetimeMinted, &successor, None).unwrap(),
+            4_000
+        );
+        assert_eq!(
+            current_supply(SupplyCapMode::Circulating, &successor, None).unwrap(),
+            3_800
+        );
+
+        // Version 2 accounts had zeros where the link fields now are: they decode unlinked.
+        let mut data = Vec::new();
+        StablecoinState {
+            version: 2,
+            successor_mint: Pubkey::default(),
+            ..legacy.clone()
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        data.resize(StablecoinState::SPACE, 0);
+        let upgraded = upgrade_stablecoin_state(&data).unwrap();
+        assert_eq!((upgraded.from_version, upgraded.to_version), (2, 3));
+        let migrated = StablecoinState::try_deserialize(&mut &upgraded.data[..]).unwrap();
+        assert!(!migrated.has_successor() && !migrated.has_legacy_mint());
+        assert_eq!(migrated.legacy_outstanding, 0);
+    }
+
+    #[test]
+    fn reporting_config_layout() {
+        let config = ReportingConfig {
+            stablecoin: Pubkey::new_unique(),
+            threshold: 10_000_000_000,
+            updated_by: Pubkey::default(),
+            updated_at: 0,
+            bump: 0,
+        };
+        let mut data = ReportingConfig::DISCRIMINATOR.to_vec();
+        config.serialize(&mut data).unwrap();
+        assert_eq!(
+            data[REPORTING_THRESHOLD_OFFSET..REPORTING_THRESHOLD_OFFSET + 8],
+            10_000_000_000u64.to_le_bytes()
+        );
+        assert_eq!(REPORTING_CONFIG_SEED, b"reporting_config");
+    }
+
     #[test]
     fn stablecoin_error_variants_exist() {
         let _ = StablecoinError::Unauthorized;
@@ -104,6 +795,40 @@ mod tests {
         let _ = StablecoinError::MathOverflow;
         let _ = StablecoinError::InvalidRoleConfig;
         let _ = StablecoinError::SupplyCapExceeded;
+        let _ = StablecoinError::NoPendingAuthority;
+        let _ = StablecoinError::InvalidMultisigConfig;
+        let _ = StablecoinError::NotMultisigMember;
+        let _ = StablecoinError::ThresholdNotMet;
+        let _ = StablecoinError::ProposalExpired;
+        let _ = StablecoinError::ProposalAlreadyExecuted;
+        let _ = StablecoinError::ProposalStale;
+        let _ = StablecoinError::TimelockRequired;
+        let _ = StablecoinError::TimelockNotReady;
+        let _ = StablecoinError::QueuedChangeStale;
+        let _ = StablecoinError::AllowanceExceeded;
+        let _ = StablecoinError::InvalidAllowanceConfig;
+        let _ = StablecoinError::MinterNotConfigured;
+        let _ = StablecoinError::SupplyCapNotSet;
+        let _ = StablecoinError::ReferenceTooLong;
+        let _ = StablecoinError::InvalidPauseScope;
+        let _ = StablecoinError::InvalidPauseDuration;
+        let _ = StablecoinError::EmergencyPauseActive;
+        let _ = StablecoinError::EmergencyPauseNotActive;
+        let _ = StablecoinError::AllowlistNotEnabled;
+        let _ = StablecoinError::AlreadyAllowlisted;
+        let _ = StablecoinError::InvalidHolderAccount;
+        let _ = StablecoinError::InvalidBlacklistExpiry;
+        let _ = StablecoinError::InvalidBatchSize;
+        let _ = StablecoinError::InvalidBatchAccount;
+        let _ = StablecoinError::VelocityHookAccountsOutdated;
+        let _ = StablecoinError::AlreadyVelocityExempt;
+        let _ = StablecoinError::ReportingHookAccountsOutdated;
+        let _ = StablecoinError::AccountAlreadyMigrated;
+        let _ = StablecoinError::InvalidMigrationTarget;
+        let _ = StablecoinError::MigrationAlreadyLinked;
+        let _ = StablecoinError::MintNotLinked;
+        let _ = StablecoinError::InvalidMetadataField;
+        let _ = StablecoinError::MintMetadataNotInitialized;
     }
 }
 
@@ -127,10 +852,44 @@ pub mod solana_stablecoin_standard {
         ctx.accounts.burn_tokens(amount)
     }
 
+    /// Burns from a holder's account via the permanent delegate (redemption).
+    pub fn burn_from(ctx: Context<BurnFrom>, amount: u64, reference: String) -> Result<()> {
+        ctx.accounts.burn_from(amount, reference)
+    }
+
+    // === Redemption Queue ===
+
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestRedemption<'info>>,
+        amount: u64,
+        reference_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .request_redemption(amount, reference_hash, ctx.bumps, ctx.remaining_accounts)
+    }
+
+    pub fn fulfill_redemption(ctx: Context<FulfillRedemption>) -> Result<()> {
+        ctx.accounts.fulfill_redemption()
+    }
+
+    pub fn reject_redemption<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RejectRedemption<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.reject_redemption(ctx.remaining_accounts)
+    }
+
     pub fn freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
         ctx.accounts.freeze_token_account()
     }
 
+    /// remaining_accounts: up to MAX_BATCH_SIZE token accounts; already-frozen ones are skipped.
+    pub fn batch_freeze_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchFreezeTokenAccounts<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .batch_freeze_token_accounts(ctx.remaining_accounts)
+    }
+
     pub fn thaw_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
         ctx.accounts.thaw_token_account()
     }
@@ -143,33 +902,276 @@ pub mod solana_stablecoin_standard {
         ctx.accounts.unpause()
     }
 
+    /// Pauses individual scopes (PAUSE_MINT | PAUSE_BURN | PAUSE_TRANSFER | PAUSE_FREEZE).
+    pub fn pause_scoped(ctx: Context<PauseUnpause>, scopes: u8) -> Result<()> {
+        ctx.accounts.pause_scoped(scopes)
+    }
+
+    pub fn unpause_scoped(ctx: Context<PauseUnpause>, scopes: u8) -> Result<()> {
+        ctx.accounts.unpause_scoped(scopes)
+    }
+
+    /// Time-boxed pause (guardian or pauser) that lifts automatically after `duration` seconds.
+    pub fn emergency_pause(ctx: Context<EmergencyPause>, duration: i64) -> Result<()> {
+        ctx.accounts.emergency_pause(duration)
+    }
+
+    pub fn extend_emergency_pause(ctx: Context<ExtendEmergencyPause>, duration: i64) -> Result<()> {
+        ctx.accounts.extend_emergency_pause(duration)
+    }
+
     pub fn update_roles(ctx: Context<UpdateRoles>, roles: RoleFlags) -> Result<()> {
-        ctx.accounts.update_roles(roles)
+        ctx.accounts.update_roles(roles, &ctx.bumps)
     }
 
     pub fn update_minter(ctx: Context<UpdateMinter>, quota: u64) -> Result<()> {
         ctx.accounts.update_minter(quota, &ctx.bumps)
     }
 
+    /// Closes the MinterInfo PDA to the authority. Re-adding starts a new epoch.
+    pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
+        ctx.accounts.remove_minter()
+    }
+
+    /// Closes the RoleAccount PDA to the authority.
+    pub fn revoke_role_account(ctx: Context<RevokeRoleAccount>) -> Result<()> {
+        ctx.accounts.revoke_role_account()
+    }
+
+    /// window_seconds = 0 restores lifetime quota mode.
+    pub fn update_minter_allowance(
+        ctx: Context<UpdateMinterAllowance>,
+        window_seconds: i64,
+        window_limit: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_minter_allowance(window_seconds, window_limit)
+    }
+
+    /// Immediate handoff signed by both the current and the new authority.
     pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
         ctx.accounts.transfer_authority()
     }
 
+    pub fn nominate_authority(ctx: Context<NominateAuthority>) -> Result<()> {
+        ctx.accounts.nominate_authority()
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        ctx.accounts.accept_authority()
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        ctx.accounts.cancel_authority_transfer()
+    }
+
     pub fn update_supply_cap(ctx: Context<UpdateSupplyCap>, cap: u64) -> Result<()> {
-        ctx.accounts.update_supply_cap(cap)
+        ctx.accounts.update_supply_cap(cap, &ctx.bumps)
+    }
+
+    /// Selects the metric the supply cap is enforced against.
+    pub fn update_supply_cap_mode(
+        ctx: Context<UpdateSupplyCapMode>,
+        mode: SupplyCapMode,
+    ) -> Result<()> {
+        ctx.accounts.update_supply_cap_mode(mode)
+    }
+
+    // === Multisig Admin Instructions ===
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.create_multisig(members, threshold, ctx.bumps)
+    }
+
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action: AdminAction,
+        ttl_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.propose_admin_action(action, ttl_seconds, ctx.bumps)
+    }
+
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        ctx.accounts.approve_admin_action()
+    }
+
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        ctx.accounts.execute_admin_action(&ctx.bumps)
+    }
+
+    pub fn close_admin_proposal(ctx: Context<CloseAdminProposal>) -> Result<()> {
+        ctx.accounts.close_admin_proposal()
+    }
+
+    /// Updates name, symbol, uri and additional key/value fields (issuer, attestation URL, ...)
+    /// in StablecoinState and the on-mint metadata. Authority only.
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, params: UpdateMetadataParams) -> Result<()> {
+        ctx.accounts.update_metadata(params)
+    }
+
+    /// Registers the token account seizures must be sent to.
+    pub fn update_treasury(ctx: Context<UpdateTreasury>, treasury: Pubkey) -> Result<()> {
+        ctx.accounts.update_treasury(treasury)
+    }
+
+    // === Timelock Instructions ===
+
+    pub fn update_timelock(
+        ctx: Context<UpdateTimelock>,
+        delay: i64,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.update_timelock(delay, guardian)
+    }
+
+    pub fn queue_change(ctx: Context<QueueChange>, action: AdminAction) -> Result<()> {
+        ctx.accounts.queue_change(action, ctx.bumps)
+    }
+
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        ctx.accounts.execute_change(&ctx.bumps)
+    }
+
+    pub fn cancel_change(ctx: Context<CancelChange>) -> Result<()> {
+        ctx.accounts.cancel_change()
     }
 
     // === SSS-2 Compliance Instructions ===
 
-    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, reason: String) -> Result<()> {
-        ctx.accounts.add_to_blacklist(reason, ctx.bumps)
+    /// `expires_at` (unix seconds) makes the entry inactive once passed; `None` = permanent.
+    pub fn add_to_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_to_blacklist(reason, reason_code, expires_at, ctx.bumps)
+    }
+
+    pub fn update_blacklist_entry(
+        ctx: Context<UpdateBlacklistEntry>,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_blacklist_entry(reason, reason_code, expires_at)
     }
 
     pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
         ctx.accounts.remove_from_blacklist()
     }
 
-    pub fn seize(ctx: Context<Seize>) -> Result<()> {
-        ctx.accounts.seize()
+    /// remaining_accounts: up to MAX_BATCH_SIZE `[address, blacklist_entry]` pairs. Addresses
+    /// with an active entry are skipped.
+    pub fn batch_add_to_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchAddToBlacklist<'info>>,
+        reason: String,
+        reason_code: BlacklistReason,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ctx.accounts.batch_add_to_blacklist(
+            reason,
+            reason_code,
+            expires_at,
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// remaining_accounts: up to MAX_BATCH_SIZE `[address, blacklist_entry]` pairs. Addresses
+    /// without an entry are skipped.
+    pub fn batch_remove_from_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchRemoveFromBlacklist<'info>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .batch_remove_from_blacklist(ctx.remaining_accounts)
+    }
+
+    /// Per-transfer and rolling 24h per-wallet limits enforced by the transfer hook; 0 disables.
+    pub fn set_velocity_limits(
+        ctx: Context<SetVelocityLimits>,
+        max_per_transfer: u64,
+        max_per_window: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_velocity_limits(max_per_transfer, max_per_window, ctx.bumps)
+    }
+
+    pub fn add_velocity_exemption(ctx: Context<AddVelocityExemption>) -> Result<()> {
+        ctx.accounts.add_velocity_exemption(ctx.bumps)
+    }
+
+    pub fn remove_velocity_exemption(ctx: Context<RemoveVelocityExemption>) -> Result<()> {
+        ctx.accounts.remove_velocity_exemption()
+    }
+
+    /// Transfers above `threshold` emit LargeTransferObserved from the transfer hook; 0 disables.
+    pub fn set_reporting_threshold(ctx: Context<SetReportingThreshold>, threshold: u64) -> Result<()> {
+        ctx.accounts.set_reporting_threshold(threshold, ctx.bumps)
+    }
+
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>) -> Result<()> {
+        ctx.accounts.add_to_allowlist(ctx.bumps)
+    }
+
+    pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+        ctx.accounts.remove_from_allowlist()
+    }
+
+    /// Allowlists a token account's owner and thaws the account in one step.
+    pub fn approve_holder(ctx: Context<ApproveHolder>) -> Result<()> {
+        ctx.accounts.approve_holder(ctx.bumps)
+    }
+
+    /// Creates the mint's transfer hook ExtraAccountMetaList (stablecoin PDA signs the hook CPI).
+    pub fn initialize_transfer_hook_accounts(
+        ctx: Context<InitializeTransferHookAccounts>,
+    ) -> Result<()> {
+        ctx.accounts.initialize_transfer_hook_accounts()
+    }
+
+    /// Rewrites the mint's transfer hook ExtraAccountMetaList (stablecoin PDA signs the hook CPI).
+    pub fn update_transfer_hook_accounts(ctx: Context<UpdateTransferHookAccounts>) -> Result<()> {
+        ctx.accounts.update_transfer_hook_accounts()
+    }
+
+    /// Seizes `amount` (or the full balance when `None`) from a blacklisted or frozen account.
+    pub fn seize(
+        ctx: Context<Seize>,
+        amount: Option<u64>,
+        case_reference_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.seize(amount, case_reference_hash, ctx.bumps)
+    }
+
+    // === Migration Instructions ===
+
+    /// Upgrades an older StablecoinState to the current layout. Permissionless.
+    pub fn migrate_stablecoin_state(ctx: Context<MigrateStablecoinState>) -> Result<()> {
+        ctx.accounts.migrate_stablecoin_state()
+    }
+
+    /// Upgrades a StablecoinState, RoleAccount, MinterInfo, BlacklistEntry or SupplyCap in an
+    /// older layout to the current one. Permissionless.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        ctx.accounts.migrate_account()
+    }
+
+    /// Links a new SSS-2 stablecoin as the successor of an SSS-1 one. Legacy minting stops;
+    /// holders move over with swap_legacy. Authority of both stablecoins only.
+    pub fn link_successor_mint(ctx: Context<LinkSuccessorMint>) -> Result<()> {
+        ctx.accounts.link_successor_mint()
+    }
+
+    /// Burns `amount` legacy tokens from the holder and mints the same amount of the linked
+    /// SSS-2 token to them. The recipient owner must pass the successor's blacklist and, in
+    /// allowlist mode, its allowlist.
+    pub fn swap_legacy(ctx: Context<SwapLegacy>, amount: u64) -> Result<()> {
+        ctx.accounts.swap_legacy(amount)
     }
 }
@@ -0,0 +1,113 @@
+use crate::{RoleFlags, SupplyCapMode, UpdateMetadataParams, MAX_MULTISIG_MEMBERS};
+use anchor_lang::prelude::*;
+
+/// Admin action stored in a proposal and applied on execution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminAction {
+    UpdateRoles {
+        holder: Pubkey,
+        roles: RoleFlags,
+    },
+    UpdateMinter {
+        minter: Pubkey,
+        quota: u64,
+    },
+    UpdateSupplyCap {
+        cap: u64,
+    },
+    NominateAuthority {
+        new_authority: Pubkey,
+    },
+    /// Accept a nomination of the multisig itself as authority
+    AcceptAuthority,
+    CancelAuthorityTransfer,
+    UpdateMultisig {
+        #[max_len(MAX_MULTISIG_MEMBERS)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+    UpdateTimelock {
+        delay: i64,
+        guardian: Pubkey,
+    },
+    UpdateMinterAllowance {
+        minter: Pubkey,
+        window_seconds: i64,
+        window_limit: u64,
+    },
+    /// Closes the MinterInfo PDA; rent goes to the executor
+    RemoveMinter {
+        minter: Pubkey,
+    },
+    /// Closes the RoleAccount PDA; rent goes to the executor
+    RevokeRole {
+        holder: Pubkey,
+    },
+    UpdateSupplyCapMode {
+        mode: SupplyCapMode,
+    },
+    UpdateTreasury {
+        treasury: Pubkey,
+    },
+    /// Needs the mint (writable) and the Token-2022 program; the executor pays any rent
+    UpdateMetadata {
+        params: UpdateMetadataParams,
+    },
+    /// Executed through the legacy stablecoin's multisig, which must also be the successor's
+    /// authority; needs the successor stablecoin
+    LinkSuccessorMint {
+        successor_mint: Pubkey,
+    },
+    /// Need the mint, the extra-account-metas PDA and the hook program; the executor pays rent
+    InitializeTransferHookAccounts,
+    UpdateTransferHookAccounts,
+    ExtendEmergencyPause {
+        duration: i64,
+    },
+    /// Cancels the QueuedChange at `change`; needs it and its rent payer
+    CancelChange {
+        change: Pubkey,
+    },
+}
+
+impl AdminAction {
+    /// Key used to derive the per-address PDA (role / minter) an action writes to.
+    pub fn target(&self) -> Pubkey {
+        match self {
+            AdminAction::UpdateRoles { holder, .. } | AdminAction::RevokeRole { holder } => *holder,
+            AdminAction::UpdateMinter { minter, .. }
+            | AdminAction::UpdateMinterAllowance { minter, .. }
+            | AdminAction::RemoveMinter { minter } => *minter,
+            _ => Pubkey::default(),
+        }
+    }
+}
+
+/// Seeds: [b"proposal", multisig.key().as_ref(), id.to_le_bytes().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    pub multisig: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    /// Bitmask of approving members, indexed by position in `MultisigConfig.members`
+    pub approvals: u16,
+    /// `MultisigConfig.config_version` at creation
+    pub config_version: u32,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub executed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Seeds: [b"allowlist", stablecoin.key().as_ref(), address.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub stablecoin: Pubkey,
+    /// Wallet (token account owner) allowed to send and receive in allowlist mode
+    pub address: Pubkey,
+    pub allowlisted_at: i64,
+    pub allowlisted_by: Pubkey,
+    pub bump: u8,
+}
@@ -1,4 +1,7 @@
-use crate::MAX_REASON_LEN;
+use crate::{
+    decode_v0_layout, BlacklistReason, VersionedAccount, VersionedAccountType,
+    BLACKLIST_ENTRY_RESERVED_LEN, BLACKLIST_ENTRY_VERSION, MAX_REASON_LEN,
+};
 use anchor_lang::prelude::*;
 
 /// Seeds: [b"blacklist", stablecoin.key().as_ref(), address.key().as_ref()]
@@ -7,11 +10,20 @@ use anchor_lang::prelude::*;
 pub struct BlacklistEntry {
     pub stablecoin: Pubkey,
     pub address: Pubkey,
+    /// Unix seconds after which the entry is inactive; 0 = never expires. Kept at a fixed
+    /// offset (BLACKLIST_EXPIRES_AT_OFFSET) ahead of the variable-length reason: the transfer
+    /// hook reads it.
+    pub expires_at: i64,
+    pub reason_code: BlacklistReason,
     #[max_len(MAX_REASON_LEN)]
     pub reason: String,
     pub blacklisted_at: i64,
     pub blacklisted_by: Pubkey,
     pub bump: u8,
+    /// Layout version (BLACKLIST_ENTRY_VERSION). Follows the variable-length reason, so it has
+    /// no fixed offset.
+    pub version: u8,
+    pub reserved: [u8; BLACKLIST_ENTRY_RESERVED_LEN],
 }
 
 impl BlacklistEntry {
@@ -20,6 +32,10 @@ impl BlacklistEntry {
         .unwrap()
         .checked_add(32)
         .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(1)
+        .unwrap()
         .checked_add(4usize.checked_add(MAX_REASON_LEN).unwrap())
         .unwrap()
         .checked_add(8)
@@ -27,5 +43,61 @@ impl BlacklistEntry {
         .checked_add(32)
         .unwrap()
         .checked_add(1)
+        .unwrap()
+        .checked_add(1)
+        .unwrap()
+        .checked_add(BLACKLIST_ENTRY_RESERVED_LEN)
         .unwrap();
+
+    /// An entry blocks transfers until its expiry (if any) passes.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at == 0 || now < self.expires_at
+    }
+}
+
+impl VersionedAccount for BlacklistEntry {
+    const ACCOUNT_TYPE: VersionedAccountType = VersionedAccountType::BlacklistEntry;
+    const VERSION: u8 = BLACKLIST_ENTRY_VERSION;
+    const SPACE: usize = Self::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _from_version: u8) {
+        self.version = BLACKLIST_ENTRY_VERSION;
+        self.reserved = [0; BLACKLIST_ENTRY_RESERVED_LEN];
+    }
+
+    /// Deployed entries never expire and get the default reason code.
+    fn decode_v0(data: &[u8]) -> Result<Self> {
+        let v0: BlacklistEntryV0 = decode_v0_layout(data, BlacklistEntryV0::LEN)?;
+        Ok(Self {
+            stablecoin: v0.stablecoin,
+            address: v0.address,
+            expires_at: 0,
+            reason_code: BlacklistReason::default(),
+            reason: v0.reason,
+            blacklisted_at: v0.blacklisted_at,
+            blacklisted_by: v0.blacklisted_by,
+            bump: v0.bump,
+            version: 0,
+            reserved: [0; BLACKLIST_ENTRY_RESERVED_LEN],
+        })
+    }
+}
+
+/// BlacklistEntry as deployed before the version field, without expiry or reason code.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BlacklistEntryV0 {
+    pub stablecoin: Pubkey,
+    pub address: Pubkey,
+    pub reason: String,
+    pub blacklisted_at: i64,
+    pub blacklisted_by: Pubkey,
+    pub bump: u8,
+}
+
+impl BlacklistEntryV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + MAX_REASON_LEN + 8 + 32 + 1;
 }
@@ -1,3 +1,7 @@
+use crate::{
+    decode_v0_layout, VersionedAccount, VersionedAccountType, MINTER_INFO_RESERVED_LEN,
+    MINTER_INFO_VERSION,
+};
 use anchor_lang::prelude::*;
 
 /// Seeds: [b"minter", stablecoin.key().as_ref(), minter.key().as_ref()]
@@ -6,11 +10,24 @@ use anchor_lang::prelude::*;
 pub struct MinterInfo {
     pub stablecoin: Pubkey,
     pub minter: Pubkey,
-    /// Maximum amount this minter is allowed to mint
+    /// Maximum amount this minter is allowed to mint (lifetime mode only)
     pub quota: u64,
-    /// Running total of tokens minted by this minter
+    /// Running total of tokens minted by this minter (kept in both modes for audit)
     pub minted_amount: u64,
+    /// Allowance window length in seconds. 0 = lifetime quota mode
+    pub window_seconds: i64,
+    /// Maximum amount mintable per window (periodic mode only)
+    pub window_limit: u64,
+    /// Start of the window that window_minted counts against
+    pub window_start: i64,
+    /// Amount minted since window_start
+    pub window_minted: u64,
+    /// stablecoin.minter_epoch when this account was created
+    pub epoch: u64,
     pub bump: u8,
+    /// Layout version (MINTER_INFO_VERSION)
+    pub version: u8,
+    pub reserved: [u8; MINTER_INFO_RESERVED_LEN],
 }
 
 impl MinterInfo {
@@ -23,6 +40,92 @@ impl MinterInfo {
         .unwrap()
         .checked_add(8)
         .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(8)
+        .unwrap()
+        .checked_add(1)
+        .unwrap()
         .checked_add(1)
+        .unwrap()
+        .checked_add(MINTER_INFO_RESERVED_LEN)
         .unwrap();
+
+    pub fn is_periodic(&self) -> bool {
+        self.window_seconds > 0
+    }
+
+    /// Start of the window containing `now`. Windows stay aligned to the configured start.
+    /// Only meaningful in periodic mode.
+    pub fn current_window_start(&self, now: i64) -> i64 {
+        let elapsed = now.saturating_sub(self.window_start);
+        if elapsed < self.window_seconds {
+            self.window_start
+        } else {
+            now - elapsed % self.window_seconds
+        }
+    }
+
+    /// Amount already minted in the window containing `now`.
+    pub fn minted_in_window(&self, now: i64) -> u64 {
+        if self.current_window_start(now) == self.window_start {
+            self.window_minted
+        } else {
+            0
+        }
+    }
+}
+
+impl VersionedAccount for MinterInfo {
+    const ACCOUNT_TYPE: VersionedAccountType = VersionedAccountType::MinterInfo;
+    const VERSION: u8 = MINTER_INFO_VERSION;
+    const SPACE: usize = Self::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _from_version: u8) {
+        self.version = MINTER_INFO_VERSION;
+        self.reserved = [0; MINTER_INFO_RESERVED_LEN];
+    }
+
+    /// Deployed minters keep their lifetime quota and counter; no allowance window is set.
+    fn decode_v0(data: &[u8]) -> Result<Self> {
+        let v0: MinterInfoV0 = decode_v0_layout(data, MinterInfoV0::LEN)?;
+        Ok(Self {
+            stablecoin: v0.stablecoin,
+            minter: v0.minter,
+            quota: v0.quota,
+            minted_amount: v0.minted_amount,
+            window_seconds: 0,
+            window_limit: 0,
+            window_start: 0,
+            window_minted: 0,
+            epoch: 0,
+            bump: v0.bump,
+            version: 0,
+            reserved: [0; MINTER_INFO_RESERVED_LEN],
+        })
+    }
+}
+
+/// MinterInfo as deployed before the version field, without allowance windows or epoch.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MinterInfoV0 {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub quota: u64,
+    pub minted_amount: u64,
+    pub bump: u8,
+}
+
+impl MinterInfoV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1;
 }
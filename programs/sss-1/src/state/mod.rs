@@ -12,3 +12,30 @@ pub use blacklist_entry::*;
 
 pub mod supply_cap;
 pub use supply_cap::*;
+
+pub mod multisig_config;
+pub use multisig_config::*;
+
+pub mod admin_proposal;
+pub use admin_proposal::*;
+
+pub mod queued_change;
+pub use queued_change::*;
+
+pub mod redemption_request;
+pub use redemption_request::*;
+
+pub mod seizure_record;
+pub use seizure_record::*;
+
+pub mod allowlist_entry;
+pub use allowlist_entry::*;
+
+pub mod velocity;
+pub use velocity::*;
+
+pub mod reporting;
+pub use reporting::*;
+
+pub mod versioned;
+pub use versioned::*;
@@ -0,0 +1,27 @@
+use crate::MAX_MULTISIG_MEMBERS;
+use anchor_lang::prelude::*;
+
+/// M-of-N admin multisig. Set its address as `StablecoinState.authority` to require
+/// threshold approval for every admin action.
+/// Seeds: [b"multisig", stablecoin.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigConfig {
+    pub stablecoin: Pubkey,
+    #[max_len(MAX_MULTISIG_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    /// Approvals required to execute a proposal
+    pub threshold: u8,
+    /// Next proposal id (monotonic; used in the proposal PDA seeds)
+    pub proposal_count: u64,
+    /// Bumped on membership change; proposals from an older version cannot execute
+    pub config_version: u32,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MultisigConfig {
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|m| m == key)
+    }
+}
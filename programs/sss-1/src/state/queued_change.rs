@@ -0,0 +1,27 @@
+use crate::AdminAction;
+use anchor_lang::prelude::*;
+
+/// A timelocked admin change. Executable by anyone once `eta` has passed, provided the
+/// authority that queued it is still the stablecoin authority.
+/// Seeds: [b"queued_change", stablecoin.key().as_ref(), id.to_le_bytes().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedChange {
+    pub stablecoin: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    /// Earliest unix timestamp at which the change can execute
+    pub eta: i64,
+    /// Authority (signer or multisig) that queued the change
+    pub queued_by: Pubkey,
+    /// Receives the rent back when the change is executed or cancelled
+    pub rent_payer: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl QueuedChange {
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Seeds: [b"redemption", stablecoin.key().as_ref(), requester.key().as_ref(), reference_hash]
+/// Escrow token account seeds: [b"redemption_escrow", redemption_request.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionRequest {
+    pub stablecoin: Pubkey,
+    pub requester: Pubkey,
+    /// Token account the tokens came from; refunds go back here
+    pub source_token_account: Pubkey,
+    /// Amount held in escrow
+    pub amount: u64,
+    /// Hash of the off-chain redemption reference (e.g. bank wire instructions)
+    pub reference_hash: [u8; 32],
+    pub requested_at: i64,
+    pub bump: u8,
+    pub escrow_bump: u8,
+}
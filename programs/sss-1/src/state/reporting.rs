@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Large-transfer reporting threshold read by the SSS-2 transfer hook at
+/// REPORTING_THRESHOLD_OFFSET, so `threshold` must stay directly after `stablecoin`.
+/// Seeds: [b"reporting_config", stablecoin.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct ReportingConfig {
+    pub stablecoin: Pubkey,
+    /// Transfers above this amount (base units) emit LargeTransferObserved; 0 = off
+    pub threshold: u64,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
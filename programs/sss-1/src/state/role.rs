@@ -1,4 +1,7 @@
-use crate::RoleFlags;
+use crate::{
+    decode_v0_layout, RoleFlags, VersionedAccount, VersionedAccountType,
+    ROLE_ACCOUNT_RESERVED_LEN, ROLE_ACCOUNT_VERSION,
+};
 use anchor_lang::prelude::*;
 
 /// Seeds: [b"role", stablecoin.key().as_ref(), holder.key().as_ref()]
@@ -9,6 +12,9 @@ pub struct RoleAccount {
     pub holder: Pubkey,
     pub roles: RoleFlags,
     pub bump: u8,
+    /// Layout version (ROLE_ACCOUNT_VERSION)
+    pub version: u8,
+    pub reserved: [u8; ROLE_ACCOUNT_RESERVED_LEN],
 }
 
 impl RoleAccount {
@@ -20,5 +26,63 @@ impl RoleAccount {
         .checked_add(RoleFlags::LEN)
         .unwrap()
         .checked_add(1)
+        .unwrap()
+        .checked_add(1)
+        .unwrap()
+        .checked_add(ROLE_ACCOUNT_RESERVED_LEN)
         .unwrap();
+}
+
+impl VersionedAccount for RoleAccount {
+    const ACCOUNT_TYPE: VersionedAccountType = VersionedAccountType::RoleAccount;
+    const VERSION: u8 = ROLE_ACCOUNT_VERSION;
+    const SPACE: usize = Self::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _from_version: u8) {
+        self.version = ROLE_ACCOUNT_VERSION;
+        self.reserved = [0; ROLE_ACCOUNT_RESERVED_LEN];
+    }
+
+    fn decode_v0(data: &[u8]) -> Result<Self> {
+        let v0: RoleAccountV0 = decode_v0_layout(data, RoleAccountV0::LEN)?;
+        Ok(Self {
+            stablecoin: v0.stablecoin,
+            holder: v0.holder,
+            roles: RoleFlags {
+                is_minter: v0.is_minter,
+                is_burner: v0.is_burner,
+                is_pauser: v0.is_pauser,
+                is_freezer: v0.is_freezer,
+                is_blacklister: v0.is_blacklister,
+                is_seizer: v0.is_seizer,
+                is_allowlister: false,
+            },
+            bump: v0.bump,
+            version: 0,
+            reserved: [0; ROLE_ACCOUNT_RESERVED_LEN],
+        })
+    }
+}
+
+/// RoleAccount as deployed before the version field, with the six original role flags
+/// inline (no `is_allowlister`).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RoleAccountV0 {
+    pub stablecoin: Pubkey,
+    pub holder: Pubkey,
+    pub is_minter: bool,
+    pub is_burner: bool,
+    pub is_pauser: bool,
+    pub is_freezer: bool,
+    pub is_blacklister: bool,
+    pub is_seizer: bool,
+    pub bump: u8,
+}
+
+impl RoleAccountV0 {
+    pub const LEN: usize = 8 + 32 + 32 + 6 + 1;
 }
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Seeds: [b"seizure", stablecoin.key().as_ref(), source_token_account.key().as_ref(), case_reference_hash]
+#[account]
+#[derive(InitSpace)]
+pub struct SeizureRecord {
+    pub stablecoin: Pubkey,
+    pub source_token_account: Pubkey,
+    /// Owner of the source token account at the time of seizure
+    pub source_owner: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+    /// Hash of the off-chain case reference (e.g. court order number)
+    pub case_reference_hash: [u8; 32],
+    /// Whether the source was blacklisted (otherwise it was frozen)
+    pub source_blacklisted: bool,
+    pub seized_by: Pubkey,
+    pub seized_at: i64,
+    pub bump: u8,
+}
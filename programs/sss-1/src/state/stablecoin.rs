@@ -1,10 +1,35 @@
-use crate::{MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN};
+use crate::{
+    decode_v0_layout, VersionedAccount, VersionedAccountType, MAX_NAME_LEN, MAX_SYMBOL_LEN, MAX_URI_LEN,
+    EMERGENCY_PAUSE_COOLDOWN, PAUSE_FREEZE, PAUSE_MINT, STABLECOIN_STATE_DISCRIMINATOR, STABLECOIN_STATE_RESERVED_LEN,
+    STABLECOIN_STATE_VERSION, STABLECOIN_VERSION_OFFSET,
+};
 use anchor_lang::prelude::*;
 
 /// Seeds: [b"stablecoin", mint.key().as_ref()]
-#[account]
+///
+/// Starts with a fixed-offset header (`version` through `paused_until`, STABLECOIN_*_OFFSET)
+/// that the transfer hook reads without decoding the strings. The header is frozen: later
+/// layout versions may only change what follows it.
+#[account(discriminator = STABLECOIN_STATE_DISCRIMINATOR)]
 #[derive(InitSpace)]
 pub struct StablecoinState {
+    /// Layout version (STABLECOIN_STATE_VERSION)
+    pub version: u8,
+    /// Operational state
+    pub paused: bool,
+    /// Scoped pauses (PAUSE_* bitmask)
+    pub pause_flags: u8,
+    /// Feature flags. Immutable after init; choose SSS-1 vs SSS-2 at creation.
+    /// To move an SSS-1 coin to SSS-2, create a new SSS-2 stablecoin, `link_successor_mint`
+    /// and let holders `swap_legacy`.
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    /// Allowlist (KYC-gated) mode, chosen at init: the transfer hook rejects transfers unless
+    /// both owners hold an AllowlistEntry.
+    pub enable_allowlist: bool,
+    /// End of a time-boxed emergency pause (unix seconds); lifts automatically once passed.
+    /// 0 when none.
+    pub paused_until: i64,
     /// Master authority who can update roles and transfer authority
     pub authority: Pubkey,
     /// Token-2022 mint address
@@ -17,21 +42,187 @@ pub struct StablecoinState {
     #[max_len(MAX_URI_LEN)]
     pub uri: String,
     pub decimals: u8,
-    /// Feature flags. Immutable after init; choose SSS-1 vs SSS-2 at creation.
-    /// Cannot upgrade SSS-1 to SSS-2; create new stablecoin and migrate if needed.
-    pub enable_permanent_delegate: bool,
-    pub enable_transfer_hook: bool,
     pub default_account_frozen: bool,
-    /// Operational state
-    pub paused: bool,
     pub total_minted: u64,
     pub total_burned: u64,
+    /// Nominated authority awaiting `accept_authority`. Pubkey::default() when none.
+    pub pending_authority: Pubkey,
+    /// Delay (seconds) before sensitive config changes take effect. 0 = timelock disabled.
+    pub timelock_delay: i64,
+    /// May cancel queued changes alongside the authority. Pubkey::default() when unset.
+    pub guardian: Pubkey,
+    /// Next queued change id (monotonic; used in the QueuedChange PDA seeds)
+    pub queued_change_count: u64,
+    /// Incremented on every remove_minter. New MinterInfo accounts record the current value,
+    /// so a re-added minter starts a new epoch with fresh counters.
+    pub minter_epoch: u64,
+    /// Token account that seizures must be sent to. Pubkey::default() when unset, in which
+    /// case seize is rejected.
+    pub treasury: Pubkey,
     /// PDA bump
     pub bump: u8,
+    /// SSS-2 mint this SSS-1 stablecoin is migrating to. Once set, minting stays paused and
+    /// holders move over with `swap_legacy`. Pubkey::default() when none.
+    pub successor_mint: Pubkey,
+    /// SSS-1 mint whose holders can `swap_legacy` into this stablecoin. Pubkey::default()
+    /// when none.
+    pub legacy_mint: Pubkey,
+    /// Legacy supply not yet swapped in; counts towards this stablecoin's supply cap so the
+    /// two mints share one supply.
+    pub legacy_outstanding: u64,
+    pub reserved: [u8; STABLECOIN_STATE_RESERVED_LEN],
 }
 
 impl StablecoinState {
     pub fn is_sss2(&self) -> bool {
         self.enable_permanent_delegate && self.enable_transfer_hook
     }
+
+    pub fn is_allowlist_mode(&self) -> bool {
+        self.is_sss2() && self.enable_allowlist
+    }
+
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// True when the global pause is on, an emergency pause has not yet expired,
+    /// or any of `scopes` is paused. Minting stays paused once a successor is linked.
+    pub fn is_paused_for(&self, scopes: u8, now: i64) -> bool {
+        self.paused
+            || self.is_emergency_paused(now)
+            || self.pause_flags & scopes != 0
+            || (scopes & PAUSE_MINT != 0 && self.has_successor())
+    }
+
+    /// Freeze and thaw follow only their own scope bit: they are incident-response tools, so
+    /// the global and emergency pauses leave them available.
+    pub fn is_freeze_paused(&self) -> bool {
+        self.pause_flags & PAUSE_FREEZE != 0
+    }
+
+    pub fn is_emergency_paused(&self, now: i64) -> bool {
+        now < self.paused_until
+    }
+
+    /// Within EMERGENCY_PAUSE_COOLDOWN of the last emergency pause ending. paused_until is 0
+    /// when none was triggered or `unpause` lifted it.
+    pub fn is_emergency_cooldown(&self, now: i64) -> bool {
+        now < self.paused_until.saturating_add(EMERGENCY_PAUSE_COOLDOWN)
+    }
+
+    pub fn has_treasury(&self) -> bool {
+        self.treasury != Pubkey::default()
+    }
+
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    /// Linked to an SSS-2 successor (this is the legacy side)
+    pub fn has_successor(&self) -> bool {
+        self.successor_mint != Pubkey::default()
+    }
+
+    /// Linked to an SSS-1 predecessor (this is the successor side)
+    pub fn has_legacy_mint(&self) -> bool {
+        self.legacy_mint != Pubkey::default()
+    }
+}
+
+impl VersionedAccount for StablecoinState {
+    const ACCOUNT_TYPE: VersionedAccountType = VersionedAccountType::StablecoinState;
+    const VERSION: u8 = STABLECOIN_STATE_VERSION;
+    const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, from_version: u8) {
+        // Version 3 took the link fields from bytes that version 1 accounts may not have had
+        // zeroed (the slack after shorter strings)
+        if from_version < 3 {
+            self.successor_mint = Pubkey::default();
+            self.legacy_mint = Pubkey::default();
+            self.legacy_outstanding = 0;
+        }
+        self.version = STABLECOIN_STATE_VERSION;
+        self.reserved = [0; STABLECOIN_STATE_RESERVED_LEN];
+    }
+
+    /// Version 0 is the unversioned layout, which has its own discriminator (not checked here).
+    fn decode_v0(data: &[u8]) -> Result<Self> {
+        let legacy: LegacyStablecoinState = decode_v0_layout(data, LegacyStablecoinState::SPACE)?;
+        Ok(legacy.into())
+    }
+
+    /// Read from the fixed header; version 1 accounts are shorter than `SPACE` too.
+    fn stored_version(data: &[u8]) -> Result<u8> {
+        data.get(STABLECOIN_VERSION_OFFSET)
+            .copied()
+            .ok_or(ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// StablecoinState as deployed before the versioned header (discriminator
+/// LEGACY_STABLECOIN_STATE_DISCRIMINATOR), kept only so `migrate_stablecoin_state` can read
+/// existing accounts. Fields added since start out unset.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LegacyStablecoinState {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_SYMBOL_LEN)]
+    pub symbol: String,
+    #[max_len(MAX_URI_LEN)]
+    pub uri: String,
+    pub decimals: u8,
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub paused: bool,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub bump: u8,
+}
+
+impl LegacyStablecoinState {
+    /// Allocated size of a legacy account, discriminator included
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl From<LegacyStablecoinState> for StablecoinState {
+    fn from(legacy: LegacyStablecoinState) -> Self {
+        Self {
+            version: STABLECOIN_STATE_VERSION,
+            paused: legacy.paused,
+            pause_flags: 0,
+            enable_permanent_delegate: legacy.enable_permanent_delegate,
+            enable_transfer_hook: legacy.enable_transfer_hook,
+            enable_allowlist: false,
+            paused_until: 0,
+            authority: legacy.authority,
+            mint: legacy.mint,
+            name: legacy.name,
+            symbol: legacy.symbol,
+            uri: legacy.uri,
+            decimals: legacy.decimals,
+            default_account_frozen: legacy.default_account_frozen,
+            total_minted: legacy.total_minted,
+            total_burned: legacy.total_burned,
+            pending_authority: Pubkey::default(),
+            timelock_delay: 0,
+            guardian: Pubkey::default(),
+            queued_change_count: 0,
+            minter_epoch: 0,
+            treasury: Pubkey::default(),
+            bump: legacy.bump,
+            successor_mint: Pubkey::default(),
+            legacy_mint: Pubkey::default(),
+            legacy_outstanding: 0,
+            reserved: [0; STABLECOIN_STATE_RESERVED_LEN],
+        }
+    }
 }
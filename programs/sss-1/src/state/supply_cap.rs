@@ -1,11 +1,50 @@
+use crate::{
+    decode_v0_layout, SupplyCapMode, VersionedAccount, VersionedAccountType, SUPPLY_CAP_VERSION,
+};
 use anchor_lang::prelude::*;
 
 /// Seeds: [b"supply_cap", stablecoin.key().as_ref()]
 #[account]
 #[derive(InitSpace)]
 pub struct SupplyCap {
-    /// Maximum total supply (total_minted must not exceed this)
+    /// Maximum supply, measured by `mode`
     pub cap: u64,
     /// PDA bump
     pub bump: u8,
+    /// Appended after bump so the fixed offsets read by mint.rs stay valid. Accounts
+    /// created before this field existed have no mode byte and are treated as LifetimeMinted.
+    pub mode: SupplyCapMode,
+}
+
+impl VersionedAccount for SupplyCap {
+    const ACCOUNT_TYPE: VersionedAccountType = VersionedAccountType::SupplyCap;
+    const VERSION: u8 = SUPPLY_CAP_VERSION;
+    const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        SUPPLY_CAP_VERSION
+    }
+
+    fn upgrade(&mut self, _from_version: u8) {}
+
+    /// Caps created before modes existed keep their value and count lifetime mints.
+    fn decode_v0(data: &[u8]) -> Result<Self> {
+        let v0: SupplyCapV0 = decode_v0_layout(data, SupplyCapV0::LEN)?;
+        Ok(Self {
+            cap: v0.cap,
+            bump: v0.bump,
+            mode: SupplyCapMode::LifetimeMinted,
+        })
+    }
+}
+
+/// SupplyCap as deployed before the mode byte.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SupplyCapV0 {
+    pub cap: u64,
+    pub bump: u8,
+}
+
+impl SupplyCapV0 {
+    pub const LEN: usize = 8 + 8 + 1;
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Per-wallet transfer velocity limits enforced by the SSS-2 transfer hook. The hook reads the
+/// limits at fixed offsets (VELOCITY_MAX_PER_TRANSFER_OFFSET / VELOCITY_MAX_PER_WINDOW_OFFSET),
+/// so they must stay directly after `stablecoin`.
+/// Seeds: [b"velocity_config", stablecoin.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct VelocityConfig {
+    pub stablecoin: Pubkey,
+    /// Largest single transfer in base units; 0 = no per-transfer limit
+    pub max_per_transfer: u64,
+    /// Largest total sent by one source owner in any rolling 24h window; 0 = no window limit
+    pub max_per_window: u64,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Source owner exempt from velocity limits (treasury, market makers).
+/// Seeds: [b"velocity_exempt", stablecoin.key().as_ref(), wallet.key().as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct VelocityExemption {
+    pub stablecoin: Pubkey,
+    pub wallet: Pubkey,
+    pub exempted_at: i64,
+    pub exempted_by: Pubkey,
+    pub bump: u8,
+}
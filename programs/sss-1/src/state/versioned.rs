@@ -0,0 +1,63 @@
+use crate::VersionedAccountType;
+use anchor_lang::prelude::*;
+
+/// An account layout that `migrate_account` can upgrade in place.
+///
+/// Version 0 is the layout the account was first deployed with, before it had a version field.
+/// Fields were later inserted into the middle of those layouts, so version 0 data is decoded
+/// with its own struct and converted field by field (`decode_v0`). From version 1 on, layouts
+/// only grow at the end or take bytes from the reserved tail: older versioned data followed by
+/// zeros decodes as the current layout, and `upgrade` clears whatever the old version did not
+/// have, which may hold stale data on old accounts.
+pub trait VersionedAccount: AccountSerialize + AnchorDeserialize + Discriminator {
+    const ACCOUNT_TYPE: VersionedAccountType;
+    /// Current layout version
+    const VERSION: u8;
+    /// Allocated size of the current layout, discriminator included
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    /// Sets the current version and clears the fields `from_version` did not have, reserved
+    /// tail included
+    fn upgrade(&mut self, from_version: u8);
+
+    /// Decodes version 0 data (discriminator included, not checked) into the current layout
+    fn decode_v0(data: &[u8]) -> Result<Self>;
+
+    /// Layout version of raw account data of this type. Accounts shorter than `SPACE` predate
+    /// the version field.
+    fn stored_version(data: &[u8]) -> Result<u8> {
+        if data.len() < Self::SPACE {
+            return Ok(0);
+        }
+        Ok(Self::decode_padded(data)?.version())
+    }
+
+    /// Decodes raw account data (discriminator included, not checked) stored at `version`.
+    fn decode_version(data: &[u8], version: u8) -> Result<Self> {
+        if version == 0 {
+            return Self::decode_v0(data);
+        }
+        Self::decode_padded(data)
+    }
+
+    /// Decodes versioned account data (discriminator included, not checked), zero-filling
+    /// fields that are missing from older versions.
+    fn decode_padded(data: &[u8]) -> Result<Self> {
+        let mut buf = data
+            .get(8..)
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?
+            .to_vec();
+        buf.resize(buf.len() + Self::SPACE, 0);
+        Self::deserialize(&mut &buf[..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// Decodes a version 0 layout (discriminator included, not checked). The data must be exactly
+/// the deployed size `space`, so an account in any other layout is rejected rather than read
+/// at the wrong offsets.
+pub fn decode_v0_layout<T: AnchorDeserialize>(data: &[u8], space: usize) -> Result<T> {
+    require!(data.len() == space, ErrorCode::AccountDidNotDeserialize);
+    T::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
@@ -8,4 +8,24 @@ pub enum HookError {
     Paused,
     #[msg("Invalid instruction discriminator for transfer hook")]
     InvalidInstruction,
+    #[msg("ExtraAccountMetaList is missing or malformed")]
+    InvalidExtraAccountMetaList,
+    #[msg("Signer is not the mint's transfer hook authority")]
+    Unauthorized,
+    #[msg("sss_token_program_id is not the canonical sss-token program")]
+    InvalidSssTokenProgram,
+    #[msg("Transfer denied: address is not allowlisted")]
+    NotAllowlisted,
+    #[msg("Transfer denied: amount exceeds the per-transfer limit")]
+    TransferLimitExceeded,
+    #[msg("Transfer denied: source owner would exceed the rolling 24h limit")]
+    VelocityLimitExceeded,
+    #[msg("Source owner's velocity usage account is not initialized")]
+    VelocityUsageNotInitialized,
+    #[msg("Stablecoin state is in an unsupported layout; run migrate_stablecoin_state")]
+    UnsupportedStablecoinVersion,
+    #[msg("Transfer hook called outside a Token-2022 transfer")]
+    NotTransferring,
+    #[msg("Velocity usage account does not belong to the source owner")]
+    InvalidVelocityUsage,
 }
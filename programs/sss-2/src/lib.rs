@@ -1,12 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::System;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 use spl_tlv_account_resolution::{
     account::ExtraAccountMeta,
     seeds::Seed,
     state::ExtraAccountMetaList,
 };
+use spl_type_length_value::state::TlvStateBorrowed;
+use spl_token_2022::{
+    extension::{
+        transfer_hook::{TransferHook, TransferHookAccount},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as SplAccount, Mint as SplMint},
+};
 use spl_discriminator::discriminator::SplDiscriminate;
 
 pub mod error;
@@ -15,72 +23,64 @@ use error::HookError;
 declare_id!("8DMsf39fGWfcrWVjfyEq8fqZf5YcTvVPGgdJr8s2S8Nc");
 
 pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+/// Canonical sss-token program; the only program ID accepted at initialization
+pub const SSS_TOKEN_PROGRAM_ID: Pubkey = pubkey!("47TNsKC1iJvLTKYRMbfYjrod4a56YE1f4qv73hZkdWUZ");
+/// Transfer bit of StablecoinState.pause_flags (matches sss-token PAUSE_TRANSFER)
+pub const PAUSE_TRANSFER: u8 = 1 << 2;
+/// Versioned StablecoinState discriminator (matches sss-token STABLECOIN_STATE_DISCRIMINATOR)
+pub const STABLECOIN_STATE_DISCRIMINATOR: [u8; 8] = [126, 150, 54, 111, 226, 127, 234, 242];
+/// Newest StablecoinState layout version this hook understands. Every version keeps the
+/// fixed header below; anything newer is rejected until the hook is upgraded.
+pub const MAX_STABLECOIN_STATE_VERSION: u8 = 3;
+/// StablecoinState fixed header offsets (match sss-token STABLECOIN_*_OFFSET)
+pub const STABLECOIN_VERSION_OFFSET: usize = 8;
+pub const STABLECOIN_PAUSED_OFFSET: usize = 9;
+pub const STABLECOIN_PAUSE_FLAGS_OFFSET: usize = 10;
+pub const STABLECOIN_ALLOWLIST_OFFSET: usize = 13;
+pub const STABLECOIN_PAUSED_UNTIL_OFFSET: usize = 14;
+pub const STABLECOIN_HEADER_LEN: usize = 22;
+/// Offset of BlacklistEntry.expires_at (discriminator + stablecoin + address; matches sss-token)
+pub const BLACKLIST_EXPIRES_AT_OFFSET: usize = 8 + 32 + 32;
+/// Per-wallet velocity usage PDA seed (owned by this program)
+pub const VELOCITY_USAGE_SEED: &[u8] = b"velocity_usage";
+/// VelocityConfig limit offsets (discriminator + stablecoin; matches sss-token)
+pub const VELOCITY_MAX_PER_TRANSFER_OFFSET: usize = 8 + 32;
+pub const VELOCITY_MAX_PER_WINDOW_OFFSET: usize = VELOCITY_MAX_PER_TRANSFER_OFFSET + 8;
+/// ReportingConfig.threshold offset (discriminator + stablecoin; matches sss-token)
+pub const REPORTING_THRESHOLD_OFFSET: usize = 8 + 32;
+/// ReportingConfig.bump offset (threshold + updated_by + updated_at)
+pub const REPORTING_BUMP_OFFSET: usize = REPORTING_THRESHOLD_OFFSET + 8 + 32 + 8;
+pub const REPORTING_CONFIG_SEED: &[u8] = b"reporting_config";
+/// The rolling window is tracked in hourly buckets
+pub const VELOCITY_WINDOW_HOURS: usize = 24;
+const SECONDS_PER_HOUR: i64 = 60 * 60;
 
 #[program]
 pub mod sss_transfer_hook {
     use super::*;
 
     /// Initialize the ExtraAccountMetaList PDA.
-    /// Defines which extra accounts Token-2022 must include on every transfer CPI.
+    /// Defines which extra accounts Token-2022 must include on every transfer CPI
+    /// (layout documented on `extra_account_metas`).
     ///
-    /// Extra account layout (Execute instruction accounts):
-    ///   [0] source token account
-    ///   [1] mint
-    ///   [2] destination token account
-    ///   [3] authority (source wallet owner)
-    ///   [4] ExtraAccountMetaList PDA
-    ///   --- extra accounts ---
-    ///   [5] sss-token program ID (for PDA derivation)
-    ///   [6] stablecoin state PDA: seeds=[b"stablecoin", mint(1)] under program(5)
-    ///   [7] source blacklist PDA: seeds=[b"blacklist", stablecoin(6), authority(3)] under program(5)
-    ///   [8] dest blacklist PDA:   seeds=[b"blacklist", stablecoin(6), dest_owner_from_data(2,32,32)] under program(5)
+    /// The mint's TransferHook authority (the stablecoin PDA) must sign, so only the
+    /// sss-token `initialize_transfer_hook_accounts` CPI can create the list; this stops
+    /// anyone front-running initialization of a fresh mint with their own program ID.
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetas>,
         sss_token_program_id: Pubkey,
     ) -> Result<()> {
-        // Order matters: each account can only reference earlier accounts.
-        let extra_account_metas = vec![
-            // [5] sss-token program ID (literal, no dependencies)
-            ExtraAccountMeta::new_with_pubkey(&sss_token_program_id, false, false)?,
-
-            // [6] Stablecoin state PDA: seeds=[b"stablecoin", mint_key]
-            //     External PDA owned by sss-token program (index 5)
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5, // program at index 5
-                &[
-                    Seed::Literal { bytes: b"stablecoin".to_vec() },
-                    Seed::AccountKey { index: 1 }, // mint
-                ],
-                false,
-                false,
-            )?,
-
-            // [7] Source blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, source_authority]
-            //     May or may not exist; if it does, source is blacklisted
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 6 }, // stablecoin state
-                    Seed::AccountKey { index: 3 }, // authority (source wallet)
-                ],
-                false,
-                false,
-            )?,
-
-            // [8] Destination blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, dest_owner]
-            //     dest_owner extracted from destination token account data bytes 32..64
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 6 }, // stablecoin state
-                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // dest owner
-                ],
-                false,
-                false,
-            )?,
-        ];
+        require_keys_eq!(
+            sss_token_program_id,
+            SSS_TOKEN_PROGRAM_ID,
+            HookError::InvalidSssTokenProgram
+        );
+        require_hook_authority(
+            &ctx.accounts.mint,
+            &ctx.accounts.transfer_hook_authority.key(),
+        )?;
+
+        let extra_account_metas = extra_account_metas(&sss_token_program_id)?;
 
         // Calculate required account size
         let account_size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
@@ -96,14 +96,14 @@ pub mod sss_transfer_hook {
         // Create the ExtraAccountMetaList account (PDA must sign)
         invoke_signed(
             &anchor_lang::solana_program::system_instruction::create_account(
-                &ctx.accounts.authority.key(),
+                &ctx.accounts.payer.key(),
                 &ctx.accounts.extra_account_meta_list.key(),
                 lamports,
                 account_size as u64,
                 &crate::ID,
             ),
             &[
-                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
                 ctx.accounts.extra_account_meta_list.to_account_info(),
             ],
             &[&[EXTRA_ACCOUNT_METAS_SEED, mint_key.as_ref(), &[bump]]],
@@ -116,8 +116,42 @@ pub mod sss_transfer_hook {
         Ok(())
     }
 
+    /// Realloc and rewrite the ExtraAccountMetaList for a mint whose layout needs to change.
+    /// Must be signed by the mint's TransferHook extension authority (the stablecoin PDA), so
+    /// in practice it is reached through the sss-token `update_transfer_hook_accounts` CPI.
+    /// This is the migration for lists written in an older layout (e.g. before the source-owner
+    /// and delegate checks); there is deliberately no permissionless path.
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetas>) -> Result<()> {
+        require_hook_authority(&ctx.accounts.mint, &ctx.accounts.authority.key())?;
+
+        let list = ctx.accounts.extra_account_meta_list.to_account_info();
+        let sss_token_program_id = stored_sss_token_program_id(&list)?;
+
+        rewrite_extra_account_meta_list(
+            &list,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &extra_account_metas(&sss_token_program_id)?,
+        )
+    }
+
+    /// Create the velocity usage PDA for `owner`. Permissionless (the payer funds rent); a
+    /// source owner needs one before sending once a rolling window limit is set.
+    pub fn initialize_velocity_usage(ctx: Context<InitializeVelocityUsage>) -> Result<()> {
+        ctx.accounts.velocity_usage.set_inner(VelocityUsage {
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.owner.key(),
+            last_hour: 0,
+            hourly: [0; VELOCITY_WINDOW_HOURS],
+            bump: ctx.bumps.velocity_usage,
+        });
+        Ok(())
+    }
+
     /// Fallback handler — Token-2022 CPIs here on every transfer.
-    /// Verifies the Execute discriminator, checks pause status, and checks blacklist.
+    /// Verifies the Execute discriminator and that Token-2022 is mid-transfer, checks pause
+    /// status, blacklist, (in allowlist mode) allowlist and velocity limits, then reports
+    /// transfers above the threshold.
     pub fn fallback<'info>(
         _program_id: &Pubkey,
         accounts: &'info [AccountInfo<'info>],
@@ -131,33 +165,149 @@ pub mod sss_transfer_hook {
         if discriminator != ExecuteInstruction::SPL_DISCRIMINATOR_SLICE {
             return Err(HookError::InvalidInstruction.into());
         }
+        if accounts.len() < 2 {
+            return Err(HookError::InvalidInstruction.into());
+        }
+        // Only Token-2022 may run the hook: a direct call could otherwise write another
+        // owner's velocity usage
+        require_transferring(&accounts[0], accounts[1].key)?;
 
         // Accounts layout:
         // [0] source, [1] mint, [2] dest, [3] authority, [4] extra_meta_list
-        // [5] sss-token program, [6] stablecoin state, [7] source blacklist, [8] dest blacklist
-
-        // Check pause: read the `paused` flag from the stablecoin state PDA.
-        // The flag is embedded in a Borsh-serialized struct with variable-length
-        // strings, so we must walk the layout dynamically to find it.
-        if accounts.len() > 6 {
-            let stablecoin_data = accounts[6].try_borrow_data()?;
-            if read_paused_flag(&stablecoin_data) {
+        // [5] sss-token program, [6] stablecoin state, [7] source owner blacklist,
+        // [8] dest blacklist, [9] authority blacklist, [10] source owner allowlist,
+        // [11] dest owner allowlist, [12] velocity config, [13] source owner velocity
+        // exemption, [14] source owner velocity usage, [15] reporting config ([9..] absent on
+        // lists not yet migrated)
+
+        // Seizure exemption: the stablecoin PDA signing for a token account it does not own
+        // can only be the permanent delegate (seize), which must be able to move funds out
+        // of a blacklisted owner's account, including while the token is paused. Transfers
+        // out of accounts the PDA owns (e.g. redemption escrow refunds) get no exemption.
+        let is_permanent_delegate = accounts.len() > 6
+            && accounts[3].key == accounts[6].key
+            && token_account_owner(&accounts[0])? != *accounts[6].key;
+
+        // Check pause: read the `paused` flag, the transfer pause scope and the emergency
+        // pause expiry from the stablecoin state's fixed header. Fails closed on a state
+        // account in an unknown (or the unversioned) layout.
+        let now = Clock::get()?.unix_timestamp;
+        let allowlist_mode = if accounts.len() > 6 {
+            let header = read_stablecoin_header(&accounts[6], accounts[5].key)?;
+            if !is_permanent_delegate && header.is_transfer_paused(now) {
                 return Err(HookError::Paused.into());
             }
+            header.enable_allowlist
+        } else {
+            false
+        };
+
+        // Check blacklist: if the PDA account has data and has not expired, the address is
+        // blacklisted
+        if accounts.len() > 7 && !is_permanent_delegate && is_blacklisted(&accounts[7], now)? {
+            return Err(HookError::Blacklisted.into());
         }
 
-        // Check blacklist: if the PDA account has data, the address is blacklisted
-        if accounts.len() > 7 {
-            let source_blacklist = &accounts[7];
-            if source_blacklist.data_len() > 0 && **source_blacklist.try_borrow_lamports()? > 0 {
-                return Err(HookError::Blacklisted.into());
+        if accounts.len() > 8 && is_blacklisted(&accounts[8], now)? {
+            return Err(HookError::Blacklisted.into());
+        }
+
+        // A blacklisted wallet must not be able to move funds as a delegate either
+        if accounts.len() > 9 && is_blacklisted(&accounts[9], now)? {
+            return Err(HookError::Blacklisted.into());
+        }
+
+        // Allowlist mode: both owners need an entry. Fails closed on lists that predate the
+        // allowlist metas. The stablecoin PDA (redemption escrow, seize) needs no entry, and
+        // a seizure does not require the source owner to be allowlisted.
+        if allowlist_mode {
+            if accounts.len() <= 11 {
+                return Err(HookError::InvalidExtraAccountMetaList.into());
+            }
+            let stablecoin = *accounts[6].key;
+            if !is_permanent_delegate
+                && token_account_owner(&accounts[0])? != stablecoin
+                && !entry_exists(&accounts[10])?
+            {
+                return Err(HookError::NotAllowlisted.into());
+            }
+            if token_account_owner(&accounts[2])? != stablecoin && !entry_exists(&accounts[11])? {
+                return Err(HookError::NotAllowlisted.into());
+            }
+        }
+
+        // Velocity limits apply to the source owner unless exempt. Seizures and transfers out
+        // of stablecoin PDA accounts (redemption escrow) are not customer transfers.
+        if accounts.len() > 14
+            && entry_exists(&accounts[12])?
+            && !is_permanent_delegate
+            && token_account_owner(&accounts[0])? != *accounts[6].key
+            && !entry_exists(&accounts[13])?
+        {
+            let amount = transfer_amount(data)?;
+            let (max_per_transfer, max_per_window) = read_velocity_limits(&accounts[12])?;
+
+            if max_per_transfer != 0 && amount > max_per_transfer {
+                return Err(HookError::TransferLimitExceeded.into());
+            }
+            // The hook cannot create the usage PDA mid-transfer, so a source owner without one
+            // (see `initialize_velocity_usage`) counts as having sent nothing in the window:
+            // the transfer only has to fit the window on its own and is not recorded.
+            if max_per_window != 0 {
+                let usage_info = &accounts[14];
+                let source_owner = token_account_owner(&accounts[0])?;
+                if !entry_exists(usage_info)? {
+                    let (expected, _) = Pubkey::find_program_address(
+                        &[
+                            VELOCITY_USAGE_SEED,
+                            accounts[1].key.as_ref(),
+                            source_owner.as_ref(),
+                        ],
+                        &crate::ID,
+                    );
+                    require_keys_eq!(*usage_info.key, expected, HookError::InvalidVelocityUsage);
+                    require!(amount <= max_per_window, HookError::VelocityLimitExceeded);
+                } else {
+                    require_keys_eq!(
+                        *usage_info.owner,
+                        crate::ID,
+                        HookError::VelocityUsageNotInitialized
+                    );
+                    let mut usage =
+                        VelocityUsage::try_deserialize(&mut &usage_info.try_borrow_data()?[..])?;
+                    let expected = Pubkey::create_program_address(
+                        &[
+                            VELOCITY_USAGE_SEED,
+                            accounts[1].key.as_ref(),
+                            source_owner.as_ref(),
+                            &[usage.bump],
+                        ],
+                        &crate::ID,
+                    )
+                    .map_err(|_| HookError::InvalidVelocityUsage)?;
+                    require_keys_eq!(*usage_info.key, expected, HookError::InvalidVelocityUsage);
+                    usage.record(now, amount, max_per_window)?;
+                    usage.try_serialize(&mut &mut usage_info.try_borrow_mut_data()?[..])?;
+                }
             }
         }
 
-        if accounts.len() > 8 {
-            let dest_blacklist = &accounts[8];
-            if dest_blacklist.data_len() > 0 && **dest_blacklist.try_borrow_lamports()? > 0 {
-                return Err(HookError::Blacklisted.into());
+        // Large-transfer reporting: plain Token-2022 transfers log nothing decodable, so the
+        // hook emits the event indexers use for Travel Rule / CTR reports. Seizures included.
+        // Only reached mid-transfer (checked above) and only for the stablecoin's own config,
+        // so the event cannot be forged by calling the hook directly.
+        if accounts.len() > 15 && entry_exists(&accounts[15])? {
+            let threshold =
+                read_reporting_threshold(&accounts[15], accounts[6].key, accounts[5].key)?;
+            let amount = transfer_amount(data)?;
+            if threshold != 0 && amount > threshold {
+                emit!(LargeTransferObserved {
+                    mint: *accounts[1].key,
+                    source_owner: token_account_owner(&accounts[0])?,
+                    destination_owner: token_account_owner(&accounts[2])?,
+                    amount,
+                    slot: Clock::get()?.slot,
+                });
             }
         }
 
@@ -166,75 +316,496 @@ pub mod sss_transfer_hook {
     }
 }
 
-/// Read the `paused` flag from a Borsh-serialized StablecoinState account.
-///
-/// Layout:
-///   8  bytes — Anchor discriminator
-///   32 bytes — authority (Pubkey)
-///   32 bytes — mint (Pubkey)
-///   4 + N    — name (String: 4-byte LE length prefix + UTF-8 bytes)
-///   4 + N    — symbol (String)
-///   4 + N    — uri (String)
-///   1  byte  — decimals
-///   1  byte  — enable_permanent_delegate
-///   1  byte  — enable_transfer_hook
-///   1  byte  — default_account_frozen
-///   1  byte  — paused  ← this is what we read
-fn read_paused_flag(data: &[u8]) -> bool {
-    // Skip discriminator + authority + mint
-    let mut offset: usize = 8usize
-        .checked_add(32)
-        .and_then(|o| o.checked_add(32))
-        .expect("initial offset overflow"); // 72
-
-    // Skip three variable-length Borsh strings (name, symbol, uri)
-    for _ in 0..3 {
-        let end = match offset.checked_add(4) {
-            Some(e) => e,
-            None => return false,
-        };
-        if data.len() < end {
-            return false;
+/// Emitted by the hook for a transfer above the stablecoin's reporting threshold.
+#[event]
+pub struct LargeTransferObserved {
+    pub mint: Pubkey,
+    pub source_owner: Pubkey,
+    pub destination_owner: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+/// Amount sent by one source owner, bucketed by hour so the window rolls.
+/// Seeds: [b"velocity_usage", mint, owner]
+#[account]
+#[derive(InitSpace)]
+pub struct VelocityUsage {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    /// Hour index (unix time / 3600) of the most recent recorded transfer
+    pub last_hour: i64,
+    /// Amount sent per hour, indexed by hour % 24
+    pub hourly: [u64; VELOCITY_WINDOW_HOURS],
+    pub bump: u8,
+}
+
+impl VelocityUsage {
+    /// Drop buckets older than 24h, then add `amount` to the current hour if the window total
+    /// stays within `max_per_window`.
+    pub fn record(&mut self, now: i64, amount: u64, max_per_window: u64) -> Result<()> {
+        let hour = now.div_euclid(SECONDS_PER_HOUR);
+        let elapsed = hour.saturating_sub(self.last_hour);
+        if elapsed >= VELOCITY_WINDOW_HOURS as i64 {
+            self.hourly = [0; VELOCITY_WINDOW_HOURS];
+        } else {
+            for h in (self.last_hour + 1)..=hour {
+                self.hourly[h.rem_euclid(VELOCITY_WINDOW_HOURS as i64) as usize] = 0;
+            }
         }
-        let len_slice: [u8; 4] = match data[offset..end].try_into() {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
-        let str_len = u32::from_le_bytes(len_slice) as usize;
-        offset = match offset.checked_add(4) {
-            Some(o) => match o.checked_add(str_len) {
-                Some(o2) => o2,
-                None => return false,
-            },
-            None => return false,
-        };
+        self.last_hour = self.last_hour.max(hour);
+
+        let total = self
+            .hourly
+            .iter()
+            .try_fold(amount, |sum, bucket| sum.checked_add(*bucket))
+            .ok_or(HookError::VelocityLimitExceeded)?;
+        require!(total <= max_per_window, HookError::VelocityLimitExceeded);
+
+        let bucket = &mut self.hourly[hour.rem_euclid(VELOCITY_WINDOW_HOURS as i64) as usize];
+        *bucket = bucket.checked_add(amount).ok_or(HookError::VelocityLimitExceeded)?;
+        Ok(())
+    }
+}
+
+/// Extra accounts Token-2022 must include on every transfer CPI.
+/// Order matters: each account can only reference earlier accounts.
+///
+/// Extra account layout (Execute instruction accounts):
+///   [0] source token account
+///   [1] mint
+///   [2] destination token account
+///   [3] authority (source owner or delegate)
+///   [4] ExtraAccountMetaList PDA
+///   --- extra accounts ---
+///   [5] sss-token program ID (for PDA derivation)
+///   [6] stablecoin state PDA: seeds=[b"stablecoin", mint(1)] under program(5)
+///   [7] source blacklist PDA:    seeds=[b"blacklist", stablecoin(6), source_owner_from_data(0,32,32)] under program(5)
+///   [8] dest blacklist PDA:      seeds=[b"blacklist", stablecoin(6), dest_owner_from_data(2,32,32)] under program(5)
+///   [9] authority blacklist PDA: seeds=[b"blacklist", stablecoin(6), authority(3)] under program(5)
+///   [10] source allowlist PDA:   seeds=[b"allowlist", stablecoin(6), source_owner_from_data(0,32,32)] under program(5)
+///   [11] dest allowlist PDA:     seeds=[b"allowlist", stablecoin(6), dest_owner_from_data(2,32,32)] under program(5)
+///   [12] velocity config PDA:    seeds=[b"velocity_config", stablecoin(6)] under program(5)
+///   [13] source velocity exemption PDA: seeds=[b"velocity_exempt", stablecoin(6), source_owner_from_data(0,32,32)] under program(5)
+///   [14] source velocity usage PDA (writable): seeds=[b"velocity_usage", mint(1), source_owner_from_data(0,32,32)] under this program
+///   [15] reporting config PDA:   seeds=[b"reporting_config", stablecoin(6)] under program(5)
+fn extra_account_metas(sss_token_program_id: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // [5] sss-token program ID (literal, no dependencies)
+        ExtraAccountMeta::new_with_pubkey(sss_token_program_id, false, false)?,
+
+        // [6] Stablecoin state PDA: seeds=[b"stablecoin", mint_key]
+        //     External PDA owned by sss-token program (index 5)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5, // program at index 5
+            &[
+                Seed::Literal { bytes: b"stablecoin".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+
+        // [7] Source blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, source_owner]
+        //     source_owner extracted from source token account data bytes 32..64, so a
+        //     blacklisted owner cannot move funds by approving a clean delegate
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"blacklist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 0, data_index: 32, length: 32 }, // source owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [8] Destination blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, dest_owner]
+        //     dest_owner extracted from destination token account data bytes 32..64
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"blacklist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // dest owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [9] Authority blacklist entry PDA: seeds=[b"blacklist", stablecoin_key, authority]
+        //     Same PDA as [7] when the owner signs; differs for delegated transfers
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"blacklist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountKey { index: 3 }, // authority
+            ],
+            false,
+            false,
+        )?,
+
+        // [10] Source allowlist entry PDA: seeds=[b"allowlist", stablecoin_key, source_owner]
+        //      Only consulted when the stablecoin is in allowlist mode
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"allowlist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 0, data_index: 32, length: 32 }, // source owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [11] Destination allowlist entry PDA: seeds=[b"allowlist", stablecoin_key, dest_owner]
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"allowlist".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 2, data_index: 32, length: 32 }, // dest owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [12] Velocity config PDA: seeds=[b"velocity_config", stablecoin_key]
+        //      Limits apply only once the config exists
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"velocity_config".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+            ],
+            false,
+            false,
+        )?,
+
+        // [13] Source velocity exemption PDA: seeds=[b"velocity_exempt", stablecoin_key, source_owner]
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"velocity_exempt".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+                Seed::AccountData { account_index: 0, data_index: 32, length: 32 }, // source owner
+            ],
+            false,
+            false,
+        )?,
+
+        // [14] Source velocity usage PDA: seeds=[b"velocity_usage", mint, source_owner]
+        //      Owned by this program, so it is the one writable extra account
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: VELOCITY_USAGE_SEED.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData { account_index: 0, data_index: 32, length: 32 }, // source owner
+            ],
+            false,
+            true,
+        )?,
+
+        // [15] Reporting config PDA: seeds=[b"reporting_config", stablecoin_key]
+        //      Transfers above its threshold emit LargeTransferObserved
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: b"reporting_config".to_vec() },
+                Seed::AccountKey { index: 6 }, // stablecoin state
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+/// Require that `mint` points its TransferHook extension at this program and that
+/// `authority` is the extension's authority.
+fn require_hook_authority(mint: &AccountInfo, authority: &Pubkey) -> Result<()> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+    let hook = mint.get_extension::<TransferHook>()?;
+    require_keys_eq!(
+        Option::<Pubkey>::from(hook.program_id).unwrap_or_default(),
+        crate::ID,
+        HookError::Unauthorized
+    );
+    require_keys_eq!(
+        Option::<Pubkey>::from(hook.authority).unwrap_or_default(),
+        *authority,
+        HookError::Unauthorized
+    );
+    Ok(())
+}
+
+/// Require that `source` is a Token-2022 account of `mint` whose TransferHookAccount extension
+/// is flagged as transferring, which Token-2022 only sets while it invokes the hook.
+fn require_transferring(source: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    require_keys_eq!(*source.owner, spl_token_2022::ID, HookError::NotTransferring);
+    let data = source.try_borrow_data()?;
+    let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+    require_keys_eq!(account.base.mint, *mint, HookError::NotTransferring);
+    let hook_account = account
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| HookError::NotTransferring)?;
+    require!(
+        bool::from(hook_account.transferring),
+        HookError::NotTransferring
+    );
+    Ok(())
+}
+
+/// The sss-token program ID an existing list was initialized with ([5] is always that literal).
+fn stored_sss_token_program_id(list: &AccountInfo) -> Result<Pubkey> {
+    require_keys_eq!(*list.owner, crate::ID, HookError::InvalidExtraAccountMetaList);
+    let data = list.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&data)?;
+    let metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+    let first = metas
+        .data()
+        .first()
+        .ok_or(HookError::InvalidExtraAccountMetaList)?;
+    require!(first.discriminator == 0, HookError::InvalidExtraAccountMetaList);
+    Ok(Pubkey::new_from_array(first.address_config))
+}
+
+/// Resize an existing ExtraAccountMetaList (topping up rent from `payer`) and rewrite its metas.
+fn rewrite_extra_account_meta_list<'info>(
+    list: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    extra_account_metas: &[ExtraAccountMeta],
+) -> Result<()> {
+    let account_size = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+    let required = Rent::get()?.minimum_balance(account_size);
+    let current = list.lamports();
+    if required > current {
+        invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                list.key,
+                required.checked_sub(current).ok_or(HookError::InvalidExtraAccountMetaList)?,
+            ),
+            &[payer.clone(), list.clone(), system_program.clone()],
+        )?;
+    }
+    if list.data_len() < account_size {
+        list.resize(account_size)?;
+    }
+
+    let mut data = list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, extra_account_metas)?;
+    Ok(())
+}
+
+/// A blacklist or allowlist entry exists if the PDA holds data and lamports.
+fn entry_exists(entry: &AccountInfo) -> Result<bool> {
+    Ok(entry.data_len() > 0 && **entry.try_borrow_lamports()? > 0)
+}
+
+/// A blacklist entry is active while it exists and its `expires_at` (0 = never) has not passed.
+/// Entries too short to hold an expiry are treated as permanent.
+fn is_blacklisted(entry: &AccountInfo, now: i64) -> Result<bool> {
+    if !entry_exists(entry)? {
+        return Ok(false);
     }
+    let data = entry.try_borrow_data()?;
+    let expires_at = data
+        .get(BLACKLIST_EXPIRES_AT_OFFSET..BLACKLIST_EXPIRES_AT_OFFSET + 8)
+        .and_then(|s| s.try_into().ok())
+        .map(i64::from_le_bytes)
+        .unwrap_or(0);
+    Ok(expires_at == 0 || now < expires_at)
+}
 
-    // Skip decimals(1) + enable_permanent_delegate(1) + enable_transfer_hook(1) + default_account_frozen(1)
-    offset = match offset.checked_add(4) {
-        Some(o) => o,
-        None => return false,
+/// `(max_per_transfer, max_per_window)` from a VelocityConfig account (0 = no limit).
+fn read_velocity_limits(config: &AccountInfo) -> Result<(u64, u64)> {
+    let data = config.try_borrow_data()?;
+    let read = |offset: usize| {
+        data.get(offset..offset + 8)
+            .and_then(|s| s.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(HookError::InvalidExtraAccountMetaList)
     };
+    Ok((
+        read(VELOCITY_MAX_PER_TRANSFER_OFFSET)?,
+        read(VELOCITY_MAX_PER_WINDOW_OFFSET)?,
+    ))
+}
+
+/// ReportingConfig.threshold (0 = reporting off). `config` must be the reporting config PDA
+/// of `stablecoin` under the sss-token program.
+fn read_reporting_threshold(
+    config: &AccountInfo,
+    stablecoin: &Pubkey,
+    sss_token_program: &Pubkey,
+) -> Result<u64> {
+    require_keys_eq!(
+        *config.owner,
+        *sss_token_program,
+        HookError::InvalidExtraAccountMetaList
+    );
+    let data = config.try_borrow_data()?;
+    let bump = *data
+        .get(REPORTING_BUMP_OFFSET)
+        .ok_or(HookError::InvalidExtraAccountMetaList)?;
+    let expected = Pubkey::create_program_address(
+        &[REPORTING_CONFIG_SEED, stablecoin.as_ref(), &[bump]],
+        sss_token_program,
+    )
+    .map_err(|_| HookError::InvalidExtraAccountMetaList)?;
+    require_keys_eq!(*config.key, expected, HookError::InvalidExtraAccountMetaList);
+    data.get(REPORTING_THRESHOLD_OFFSET..REPORTING_THRESHOLD_OFFSET + 8)
+        .and_then(|s| s.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(HookError::InvalidExtraAccountMetaList.into())
+}
+
+/// Transfer amount from Execute instruction data (discriminator, then u64 LE).
+fn transfer_amount(data: &[u8]) -> Result<u64> {
+    data.get(8..16)
+        .and_then(|s| s.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(HookError::InvalidInstruction.into())
+}
 
-    // Read the paused byte
-    if data.len() <= offset {
-        return false;
+/// Owner field of a token account (bytes 32..64 of the base layout).
+fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey> {
+    let data = token_account.try_borrow_data()?;
+    let owner: [u8; 32] = data
+        .get(32..64)
+        .and_then(|s| s.try_into().ok())
+        .ok_or(HookError::InvalidInstruction)?;
+    Ok(Pubkey::new_from_array(owner))
+}
+
+/// Hot flags from the fixed StablecoinState header.
+struct StablecoinHeader {
+    paused: bool,
+    pause_flags: u8,
+    enable_allowlist: bool,
+    paused_until: i64,
+}
+
+impl StablecoinHeader {
+    /// True if the global pause, the transfer scope or an unexpired emergency pause is on.
+    fn is_transfer_paused(&self, now: i64) -> bool {
+        self.paused || self.pause_flags & PAUSE_TRANSFER != 0 || now < self.paused_until
     }
-    data[offset] != 0
+}
+
+/// Read the StablecoinState header (layout on sss-token `StablecoinState`):
+///   8  bytes — discriminator (versioned layout)
+///   1  byte  — version
+///   1  byte  — paused           ← global pause
+///   1  byte  — pause_flags      ← PAUSE_TRANSFER bit
+///   1  byte  — enable_permanent_delegate
+///   1  byte  — enable_transfer_hook
+///   1  byte  — enable_allowlist ← allowlist mode
+///   8  bytes — paused_until     ← emergency pause, active while now < paused_until
+///
+/// Fails closed: an account not owned by the sss-token program, with another discriminator
+/// (including the unversioned layout, until `migrate_stablecoin_state`), an unknown version
+/// or a truncated header rejects the transfer instead of reading as unpaused.
+fn read_stablecoin_header(
+    stablecoin: &AccountInfo,
+    sss_token_program: &Pubkey,
+) -> Result<StablecoinHeader> {
+    require_keys_eq!(
+        *stablecoin.owner,
+        *sss_token_program,
+        HookError::UnsupportedStablecoinVersion
+    );
+    let data = stablecoin.try_borrow_data()?;
+    let header = data
+        .get(..STABLECOIN_HEADER_LEN)
+        .ok_or(HookError::UnsupportedStablecoinVersion)?;
+    require!(
+        header[..8] == STABLECOIN_STATE_DISCRIMINATOR,
+        HookError::UnsupportedStablecoinVersion
+    );
+    let version = header[STABLECOIN_VERSION_OFFSET];
+    require!(
+        (1..=MAX_STABLECOIN_STATE_VERSION).contains(&version),
+        HookError::UnsupportedStablecoinVersion
+    );
+    let mut paused_until = [0u8; 8];
+    paused_until.copy_from_slice(&header[STABLECOIN_PAUSED_UNTIL_OFFSET..STABLECOIN_HEADER_LEN]);
+    Ok(StablecoinHeader {
+        paused: header[STABLECOIN_PAUSED_OFFSET] != 0,
+        pause_flags: header[STABLECOIN_PAUSE_FLAGS_OFFSET],
+        enable_allowlist: header[STABLECOIN_ALLOWLIST_OFFSET] != 0,
+        paused_until: i64::from_le_bytes(paused_until),
+    })
 }
 
 #[derive(Accounts)]
 pub struct InitializeExtraAccountMetas<'info> {
+    /// Pays rent for the list
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub payer: Signer<'info>,
 
     /// CHECK: The ExtraAccountMetaList PDA — initialized here.
     /// Seeds: ["extra-account-metas", mint]
     #[account(mut)]
     pub extra_account_meta_list: AccountInfo<'info>,
 
-    /// CHECK: The Token-2022 mint that has this hook attached
+    /// CHECK: Token-2022 mint; TransferHook extension is read in the handler
+    #[account(owner = spl_token_2022::ID)]
     pub mint: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Mint's TransferHook extension authority (the stablecoin PDA, signing via CPI)
+    pub transfer_hook_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetas<'info> {
+    /// Mint's TransferHook extension authority (the stablecoin PDA, signing via CPI)
+    pub authority: Signer<'info>,
+
+    /// Pays any additional rent for the larger list
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Existing ExtraAccountMetaList PDA (owner checked in the handler)
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: Token-2022 mint; TransferHook extension is read in the handler
+    #[account(owner = spl_token_2022::ID)]
+    pub mint: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVelocityUsage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VelocityUsage::INIT_SPACE,
+        seeds = [VELOCITY_USAGE_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub velocity_usage: Account<'info, VelocityUsage>,
+
+    /// CHECK: Token-2022 mint that has this hook attached
+    #[account(owner = spl_token_2022::ID)]
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: Wallet (token account owner) whose transfers are tracked
+    pub owner: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
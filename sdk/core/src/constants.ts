@@ -8,6 +8,9 @@ export const SSS_HOOK_PROGRAM_ID = new PublicKey(
   "8DMsf39fGWfcrWVjfyEq8fqZf5YcTvVPGgdJr8s2S8Nc"
 );
 
+/** Entries per batch blacklist/freeze instruction (program `MAX_BATCH_SIZE`). */
+export const MAX_BATCH_SIZE = 10;
+
 export const TOKEN_2022_PROGRAM_ID = new PublicKey(
   "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
 );
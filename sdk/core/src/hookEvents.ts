@@ -0,0 +1,47 @@
+import { PublicKey } from "@solana/web3.js";
+
+/** sha256("event:LargeTransferObserved")[..8] */
+export const LARGE_TRANSFER_OBSERVED_DISCRIMINATOR = Buffer.from([151, 151, 202, 196, 59, 177, 29, 224]);
+
+/** Emitted by the SSS-2 transfer hook for transfers above the reporting threshold. */
+export interface LargeTransferObserved {
+  mint: PublicKey;
+  sourceOwner: PublicKey;
+  destinationOwner: PublicKey;
+  amount: bigint;
+  slot: bigint;
+}
+
+const PROGRAM_DATA_PREFIX = "Program data: ";
+const LARGE_TRANSFER_OBSERVED_LEN = 8 + 32 * 3 + 8 + 8;
+
+/** Decode one `Program data:` payload; null if it is not a LargeTransferObserved event. */
+export function decodeLargeTransferObserved(data: Buffer): LargeTransferObserved | null {
+  if (
+    data.length < LARGE_TRANSFER_OBSERVED_LEN ||
+    !data.subarray(0, 8).equals(LARGE_TRANSFER_OBSERVED_DISCRIMINATOR)
+  ) {
+    return null;
+  }
+  return {
+    mint: new PublicKey(data.subarray(8, 40)),
+    sourceOwner: new PublicKey(data.subarray(40, 72)),
+    destinationOwner: new PublicKey(data.subarray(72, 104)),
+    amount: data.readBigUInt64LE(104),
+    slot: data.readBigUInt64LE(112),
+  };
+}
+
+/**
+ * Extract LargeTransferObserved events from transaction logs. The hook runs inside a
+ * Token-2022 CPI, so the hook program's IDL is not needed; events are matched by discriminator.
+ */
+export function parseLargeTransferEvents(logs: string[]): LargeTransferObserved[] {
+  const events: LargeTransferObserved[] = [];
+  for (const log of logs) {
+    if (!log.startsWith(PROGRAM_DATA_PREFIX)) continue;
+    const event = decodeLargeTransferObserved(Buffer.from(log.slice(PROGRAM_DATA_PREFIX.length), "base64"));
+    if (event) events.push(event);
+  }
+  return events;
+}
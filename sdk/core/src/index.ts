@@ -4,6 +4,7 @@ export * from "./constants";
 export * from "./types";
 export * from "./errors";
 export * from "./oracle";
+export * from "./hookEvents";
 
 export { BN } from "@coral-xyz/anchor";
 export { PublicKey } from "@solana/web3.js";
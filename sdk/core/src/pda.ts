@@ -1,12 +1,18 @@
 import { PublicKey } from "@solana/web3.js";
-import { SSS_TOKEN_PROGRAM_ID } from "./constants";
+import { SSS_HOOK_PROGRAM_ID, SSS_TOKEN_PROGRAM_ID } from "./constants";
 
 export const STABLECOIN_SEED = Buffer.from("stablecoin");
 export const ROLE_SEED = Buffer.from("role");
 export const MINTER_SEED = Buffer.from("minter");
 export const BLACKLIST_SEED = Buffer.from("blacklist");
+export const ALLOWLIST_SEED = Buffer.from("allowlist");
 export const SUPPLY_CAP_SEED = Buffer.from("supply_cap");
 export const EXTRA_ACCOUNT_METAS_SEED = Buffer.from("extra-account-metas");
+export const SEIZURE_SEED = Buffer.from("seizure");
+export const VELOCITY_CONFIG_SEED = Buffer.from("velocity_config");
+export const VELOCITY_EXEMPTION_SEED = Buffer.from("velocity_exempt");
+export const VELOCITY_USAGE_SEED = Buffer.from("velocity_usage");
+export const REPORTING_CONFIG_SEED = Buffer.from("reporting_config");
 
 export function findStablecoinPDA(
   mint: PublicKey,
@@ -51,6 +57,29 @@ export function findBlacklistPDA(
   );
 }
 
+export function findAllowlistPDA(
+  stablecoin: PublicKey,
+  address: PublicKey,
+  programId: PublicKey = SSS_TOKEN_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [ALLOWLIST_SEED, stablecoin.toBuffer(), address.toBuffer()],
+    programId
+  );
+}
+
+export function findSeizureRecordPDA(
+  stablecoin: PublicKey,
+  sourceTokenAccount: PublicKey,
+  caseReferenceHash: Buffer,
+  programId: PublicKey = SSS_TOKEN_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEIZURE_SEED, stablecoin.toBuffer(), sourceTokenAccount.toBuffer(), caseReferenceHash],
+    programId
+  );
+}
+
 export function findSupplyCapPDA(
   stablecoin: PublicKey,
   programId: PublicKey = SSS_TOKEN_PROGRAM_ID
@@ -70,3 +99,46 @@ export function findExtraAccountMetasPDA(
     hookProgramId
   );
 }
+
+export function findVelocityConfigPDA(
+  stablecoin: PublicKey,
+  programId: PublicKey = SSS_TOKEN_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [VELOCITY_CONFIG_SEED, stablecoin.toBuffer()],
+    programId
+  );
+}
+
+export function findVelocityExemptionPDA(
+  stablecoin: PublicKey,
+  wallet: PublicKey,
+  programId: PublicKey = SSS_TOKEN_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [VELOCITY_EXEMPTION_SEED, stablecoin.toBuffer(), wallet.toBuffer()],
+    programId
+  );
+}
+
+/** Per-wallet rolling-window usage; owned by the transfer hook program. */
+export function findVelocityUsagePDA(
+  mint: PublicKey,
+  owner: PublicKey,
+  hookProgramId: PublicKey = SSS_HOOK_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [VELOCITY_USAGE_SEED, mint.toBuffer(), owner.toBuffer()],
+    hookProgramId
+  );
+}
+
+export function findReportingConfigPDA(
+  stablecoin: PublicKey,
+  programId: PublicKey = SSS_TOKEN_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [REPORTING_CONFIG_SEED, stablecoin.toBuffer()],
+    programId
+  );
+}
@@ -1,14 +1,15 @@
+import { createHash } from "crypto";
 import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
 import type { Idl } from "@coral-xyz/anchor";
-import { Connection, Keypair, PublicKey, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { AccountMeta, Connection, Keypair, PublicKey, Transaction, TransactionInstruction } from "@solana/web3.js";
 import {
   getAssociatedTokenAddressSync,
   getAccount,
   createAssociatedTokenAccountInstruction,
 } from "@solana/spl-token";
 import idl from "./idl/solana_stablecoin_standard.json";
-import hookIdl from "./idl/sss_transfer_hook.json";
 import {
+  MAX_BATCH_SIZE,
   SSS_TOKEN_PROGRAM_ID,
   SSS_HOOK_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
@@ -18,10 +19,25 @@ import {
   findRolePDA,
   findMinterPDA,
   findBlacklistPDA,
+  findAllowlistPDA,
   findExtraAccountMetasPDA,
   findSupplyCapPDA,
+  findSeizureRecordPDA,
+  findVelocityConfigPDA,
+  findVelocityExemptionPDA,
+  findVelocityUsagePDA,
+  findReportingConfigPDA,
 } from "./pda";
-import type { MintParams, BurnParams, UpdateRolesParams, UpdateMinterParams } from "./types";
+import type {
+  BlacklistEntryParams,
+  BlacklistReason,
+  MintParams,
+  BurnParams,
+  SeizeParams,
+  UpdateRolesParams,
+  UpdateMinterParams,
+  UpdateMetadataParams,
+} from "./types";
 import { normalizeInitializeParams, type CreateStablecoinParams } from "./types";
 import { ComplianceNotEnabledError } from "./errors";
 
@@ -29,11 +45,107 @@ const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
   "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
 );
 const SYSTEM_PROGRAM_ID = new PublicKey("11111111111111111111111111111111");
+/** sha256("global:initialize_transfer_hook_accounts")[..8]; not in the bundled IDL. */
+const INITIALIZE_TRANSFER_HOOK_ACCOUNTS_DISCRIMINATOR = Buffer.from([167, 57, 149, 85, 204, 167, 151, 21]);
+/** sha256("global:update_transfer_hook_accounts")[..8]; not in the bundled IDL. */
+const UPDATE_TRANSFER_HOOK_ACCOUNTS_DISCRIMINATOR = Buffer.from([219, 77, 72, 230, 170, 108, 214, 234]);
+/** sha256("global:update_treasury")[..8]; not in the bundled IDL. */
+const UPDATE_TREASURY_DISCRIMINATOR = Buffer.from([60, 16, 243, 66, 96, 59, 254, 131]);
+/** sha256("global:add_to_allowlist")[..8]; not in the bundled IDL. */
+const ADD_TO_ALLOWLIST_DISCRIMINATOR = Buffer.from([149, 143, 78, 134, 241, 244, 7, 56]);
+/** sha256("global:remove_from_allowlist")[..8]; not in the bundled IDL. */
+const REMOVE_FROM_ALLOWLIST_DISCRIMINATOR = Buffer.from([45, 46, 214, 56, 189, 77, 242, 227]);
+/** sha256("global:approve_holder")[..8]; not in the bundled IDL. */
+const APPROVE_HOLDER_DISCRIMINATOR = Buffer.from([242, 175, 168, 245, 181, 103, 74, 208]);
+/** sha256("global:update_blacklist_entry")[..8]; not in the bundled IDL. */
+const UPDATE_BLACKLIST_ENTRY_DISCRIMINATOR = Buffer.from([229, 244, 198, 15, 197, 205, 243, 182]);
+/** sha256("global:batch_add_to_blacklist")[..8]; not in the bundled IDL. */
+const BATCH_ADD_TO_BLACKLIST_DISCRIMINATOR = Buffer.from([139, 227, 56, 87, 165, 251, 16, 84]);
+/** sha256("global:batch_remove_from_blacklist")[..8]; not in the bundled IDL. */
+const BATCH_REMOVE_FROM_BLACKLIST_DISCRIMINATOR = Buffer.from([207, 132, 79, 59, 88, 121, 95, 56]);
+/** sha256("global:batch_freeze_accounts")[..8]; not in the bundled IDL. */
+const BATCH_FREEZE_ACCOUNTS_DISCRIMINATOR = Buffer.from([71, 84, 226, 140, 114, 13, 26, 211]);
+/** sha256("global:set_velocity_limits")[..8]; not in the bundled IDL. */
+const SET_VELOCITY_LIMITS_DISCRIMINATOR = Buffer.from([208, 32, 211, 116, 163, 231, 165, 203]);
+/** sha256("global:add_velocity_exemption")[..8]; not in the bundled IDL. */
+const ADD_VELOCITY_EXEMPTION_DISCRIMINATOR = Buffer.from([180, 252, 45, 91, 177, 79, 214, 247]);
+/** sha256("global:remove_velocity_exemption")[..8]; not in the bundled IDL. */
+const REMOVE_VELOCITY_EXEMPTION_DISCRIMINATOR = Buffer.from([178, 208, 90, 192, 179, 235, 106, 104]);
+/** sha256("global:set_reporting_threshold")[..8]; not in the bundled IDL. */
+const SET_REPORTING_THRESHOLD_DISCRIMINATOR = Buffer.from([136, 133, 26, 74, 164, 142, 110, 139]);
+/** sha256("global:migrate_stablecoin_state")[..8]; not in the bundled IDL. */
+const MIGRATE_STABLECOIN_STATE_DISCRIMINATOR = Buffer.from([188, 107, 52, 221, 52, 153, 105, 94]);
+/** sha256("global:migrate_account")[..8]; not in the bundled IDL. */
+const MIGRATE_ACCOUNT_DISCRIMINATOR = Buffer.from([177, 228, 60, 125, 13, 116, 44, 84]);
+/** sha256("global:link_successor_mint")[..8]; not in the bundled IDL. */
+const LINK_SUCCESSOR_MINT_DISCRIMINATOR = Buffer.from([186, 62, 44, 239, 104, 28, 152, 95]);
+/** sha256("global:swap_legacy")[..8]; not in the bundled IDL. */
+const SWAP_LEGACY_DISCRIMINATOR = Buffer.from([130, 192, 69, 22, 66, 183, 114, 248]);
+/** sha256("global:update_metadata")[..8]; not in the bundled IDL. */
+const UPDATE_METADATA_DISCRIMINATOR = Buffer.from([170, 182, 43, 239, 97, 78, 225, 186]);
+/** sha256("global:initialize_velocity_usage")[..8]; transfer hook program instruction. */
+const INITIALIZE_VELOCITY_USAGE_DISCRIMINATOR = Buffer.from([253, 10, 6, 125, 199, 150, 180, 41]);
+/** Borsh variant order of the on-chain `BlacklistReason` enum. */
+const BLACKLIST_REASON_CODES: BlacklistReason[] = ["ofac", "courtOrder", "fraud", "internal"];
+
+/** Borsh-encode the `(reason, reason_code, expires_at)` args shared by blacklist instructions. */
+function encodeBlacklistEntryArgs(reason: string, params: BlacklistEntryParams): Buffer {
+  const reasonBytes = Buffer.from(reason, "utf-8");
+  const reasonLen = Buffer.alloc(4);
+  reasonLen.writeUInt32LE(reasonBytes.length);
+  let expiresAt = Buffer.from([0]);
+  if (params.expiresAt !== undefined) {
+    expiresAt = Buffer.alloc(9);
+    expiresAt.writeUInt8(1, 0);
+    expiresAt.writeBigInt64LE(params.expiresAt, 1);
+  }
+  return Buffer.concat([
+    reasonLen,
+    reasonBytes,
+    Buffer.from([BLACKLIST_REASON_CODES.indexOf(params.reasonCode ?? "internal")]),
+    expiresAt,
+  ]);
+}
+
+/** Borsh-encode `UpdateMetadataParams` (Option<String> x3, Vec<MetadataField>). */
+export function encodeUpdateMetadataArgs(params: UpdateMetadataParams): Buffer {
+  const str = (s: string): Buffer => {
+    const bytes = Buffer.from(s, "utf-8");
+    const len = Buffer.alloc(4);
+    len.writeUInt32LE(bytes.length);
+    return Buffer.concat([len, bytes]);
+  };
+  const opt = (s?: string): Buffer =>
+    s === undefined ? Buffer.from([0]) : Buffer.concat([Buffer.from([1]), str(s)]);
+  const fields = Object.entries(params.fields ?? {});
+  const count = Buffer.alloc(4);
+  count.writeUInt32LE(fields.length);
+  return Buffer.concat([
+    opt(params.name),
+    opt(params.symbol),
+    opt(params.uri),
+    count,
+    ...fields.flatMap(([key, value]) => [str(key), str(value)]),
+  ]);
+}
+
+/** Split `items` into chunks of at most `size`. */
+function chunk<T>(items: T[], size: number): T[][] {
+  const chunks: T[][] = [];
+  for (let i = 0; i < items.length; i += size) {
+    chunks.push(items.slice(i, i + size));
+  }
+  return chunks;
+}
+/** sha256("global:seize")[..8] */
+const SEIZE_DISCRIMINATOR = Buffer.from([129, 159, 143, 31, 161, 224, 241, 84]);
 
 type SSSIDL = typeof idl;
 type SSSProgram = Program;
 
 export interface StablecoinState {
+  /** Account layout version (0 is never returned: unversioned accounts fail to decode). */
+  version: number;
   authority: PublicKey;
   mint: PublicKey;
   name: string;
@@ -47,6 +159,12 @@ export interface StablecoinState {
   total_minted: BN;
   total_burned: BN;
   bump: number;
+  /** SSS-2 mint this stablecoin is migrating to; PublicKey.default when none. */
+  successor_mint: PublicKey;
+  /** SSS-1 mint whose holders can swap into this stablecoin; PublicKey.default when none. */
+  legacy_mint: PublicKey;
+  /** Legacy supply not yet swapped in (counts towards this stablecoin's supply cap). */
+  legacy_outstanding: BN;
 }
 
 export function toStablecoinState(raw: unknown): StablecoinState {
@@ -61,6 +179,7 @@ export function toStablecoinState(raw: unknown): StablecoinState {
   const totalMinted = r.total_minted ?? r.totalMinted;
   const totalBurned = r.total_burned ?? r.totalBurned;
   return {
+    version: (r.version as number) ?? 0,
     authority: new PublicKey(r.authority as string),
     mint: new PublicKey(r.mint as string),
     name: (r.name as string) ?? "",
@@ -74,6 +193,9 @@ export function toStablecoinState(raw: unknown): StablecoinState {
     total_minted: toBN(totalMinted),
     total_burned: toBN(totalBurned),
     bump: (r.bump as number) ?? 0,
+    successor_mint: new PublicKey((r.successor_mint ?? r.successorMint ?? PublicKey.default) as string),
+    legacy_mint: new PublicKey((r.legacy_mint ?? r.legacyMint ?? PublicKey.default) as string),
+    legacy_outstanding: toBN(r.legacy_outstanding ?? r.legacyOutstanding),
   };
 }
 
@@ -155,6 +277,7 @@ export class SolanaStablecoin {
       enablePermanentDelegate: initParams.enable_permanent_delegate,
       enableTransferHook: initParams.enable_transfer_hook,
       defaultAccountFrozen: initParams.default_account_frozen,
+      enableAllowlist: initParams.enable_allowlist,
     };
 
     const connection = provider.connection;
@@ -195,26 +318,66 @@ export class SolanaStablecoin {
     const isSSS2 =
       initParams.enable_permanent_delegate && initParams.enable_transfer_hook;
     if (isSSS2) {
+      // The hook only accepts the stablecoin PDA as initializer, so go through
+      // sss-token's initialize_transfer_hook_accounts rather than the hook directly.
       const [extraMetasPda] = findExtraAccountMetasPDA(mintPk, SSS_HOOK_PROGRAM_ID);
-      const { Program: AnchorProgram } = await import("@coral-xyz/anchor");
-      const hookProgram = new AnchorProgram(
-        hookIdl as unknown as Idl,
-        provider
-      ) as Program;
-      await (hookProgram.methods as unknown as { initializeExtraAccountMetaList: (programId: PublicKey) => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
-        .initializeExtraAccountMetaList(program.programId)
-        .accountsStrict({
-          authority,
-          extraAccountMetaList: extraMetasPda,
-          mint: mintPk,
-          systemProgram: SYSTEM_PROGRAM_ID,
-        })
-        .rpc();
+      const initHookAccountsIx = new TransactionInstruction({
+        programId: program.programId,
+        keys: [
+          { pubkey: authority, isSigner: true, isWritable: true },
+          { pubkey: stablecoinPda, isSigner: false, isWritable: false },
+          { pubkey: mintPk, isSigner: false, isWritable: false },
+          { pubkey: extraMetasPda, isSigner: false, isWritable: true },
+          { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data: INITIALIZE_TRANSFER_HOOK_ACCOUNTS_DISCRIMINATOR,
+      });
+      await provider.sendAndConfirm(new Transaction().add(initHookAccountsIx));
     }
 
     return SolanaStablecoin.load(program, mintPk);
   }
 
+  /**
+   * Rewrite a stablecoin account created before the versioned header into the current layout.
+   * Permissionless; the provider wallet pays any extra rent. Run it before `load` on such
+   * mints, and before upgrading the transfer hook, which rejects unversioned accounts.
+   */
+  static async migrateState(program: SSSProgram, mint: PublicKey): Promise<string> {
+    const provider = program.provider as AnchorProvider;
+    const ix = new TransactionInstruction({
+      programId: program.programId,
+      keys: [
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: true },
+        { pubkey: findStablecoinPDA(mint, program.programId)[0], isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: MIGRATE_STABLECOIN_STATE_DISCRIMINATOR,
+    });
+    return provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
+  /**
+   * Upgrade a StablecoinState, RoleAccount, MinterInfo or BlacklistEntry written in an older
+   * layout to the current one. Permissionless; the provider wallet pays any extra rent. Fails
+   * with `AccountAlreadyMigrated` for accounts that are already current.
+   */
+  static async migrateAccount(program: SSSProgram, account: PublicKey): Promise<string> {
+    const provider = program.provider as AnchorProvider;
+    const ix = new TransactionInstruction({
+      programId: program.programId,
+      keys: [
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: true },
+        { pubkey: account, isSigner: false, isWritable: true },
+        { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: MIGRATE_ACCOUNT_DISCRIMINATOR,
+    });
+    return provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
   async refresh(): Promise<StablecoinState> {
     const accountNs = this.program.account as Record<
       string,
@@ -280,9 +443,8 @@ export class SolanaStablecoin {
     const connection = this.provider.connection;
     const needsAta = !(await connection.getAccountInfo(recipientAta));
 
+    // Always the PDA; the program skips the check while no cap account exists.
     const [supplyCapPda] = findSupplyCapPDA(this.stablecoin, this.program.programId);
-    const supplyCapInfo = await connection.getAccountInfo(supplyCapPda);
-    const supplyCapAccount = supplyCapInfo ? supplyCapPda : this.program.programId;
 
     const mintAccounts = {
       minter: params.minter,
@@ -292,7 +454,7 @@ export class SolanaStablecoin {
       mint: this.mintAddress,
       recipientTokenAccount: recipientAta,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
-      supplyCap: supplyCapAccount,
+      supplyCap: supplyCapPda,
     };
     const mintIxBuilder = (this.program.methods as unknown as {
       mintTokens: (amount: BN) => {
@@ -447,6 +609,31 @@ export class SolanaStablecoin {
       .rpc();
   }
 
+  /**
+   * Freeze many token accounts, one `batch_freeze_accounts` per MAX_BATCH_SIZE accounts.
+   * Accounts that are already frozen are skipped.
+   */
+  async freezeAccounts(signer: PublicKey, targetTokenAccounts: PublicKey[]): Promise<string[]> {
+    const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+    const signatures: string[] = [];
+    for (const batch of chunk(targetTokenAccounts, MAX_BATCH_SIZE)) {
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: this.mintAddress, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+          ...batch.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+        ],
+        data: BATCH_FREEZE_ACCOUNTS_DISCRIMINATOR,
+      });
+      signatures.push(await this.provider.sendAndConfirm(new Transaction().add(ix)));
+    }
+    return signatures;
+  }
+
   async thawAccount(
     signer: PublicKey,
     targetTokenAccount: PublicKey
@@ -528,6 +715,7 @@ export class SolanaStablecoin {
       isFreezer: params.roles.isFreezer,
       isBlacklister: params.roles.isBlacklister,
       isSeizer: params.roles.isSeizer,
+      isAllowlister: params.roles.isAllowlister ?? false,
     };
     return (this.program.methods as unknown as { updateRoles: (r: typeof roles) => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
       .updateRoles(roles)
@@ -575,6 +763,152 @@ export class SolanaStablecoin {
       .rpc();
   }
 
+  /**
+   * Registers the token account seizures must be sent to (authority only). It must be a
+   * Token-2022 account of this mint; `PublicKey.default` clears the treasury.
+   */
+  async updateTreasury(signer: PublicKey, treasury: PublicKey): Promise<string> {
+    // The program ID stands in for the omitted treasury account when clearing
+    const treasuryAccount = treasury.equals(PublicKey.default) ? this.program.programId : treasury;
+    const ix = new TransactionInstruction({
+      programId: this.program.programId,
+      keys: [
+        { pubkey: signer, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoin, isSigner: false, isWritable: true },
+        { pubkey: treasuryAccount, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([UPDATE_TREASURY_DISCRIMINATOR, treasury.toBuffer()]),
+    });
+    return this.provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
+  /**
+   * Update name, symbol, uri and additional key/value fields in the stablecoin state and the
+   * on-mint metadata (authority only). Mints created before on-mint metadata only take
+   * name/symbol/uri.
+   */
+  async updateMetadata(signer: PublicKey, params: UpdateMetadataParams): Promise<string> {
+    const ix = new TransactionInstruction({
+      programId: this.program.programId,
+      keys: [
+        { pubkey: signer, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoin, isSigner: false, isWritable: true },
+        { pubkey: this.mintAddress, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([UPDATE_METADATA_DISCRIMINATOR, encodeUpdateMetadataArgs(params)]),
+    });
+    return this.provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
+  /**
+   * Rewrite this SSS-2 mint's ExtraAccountMetaList to the hook's current layout (authority only).
+   * Lists created before the source-owner and delegate blacklist checks keep deriving the source
+   * blacklist from the signing authority until migrated; `signer` pays any extra rent.
+   */
+  async migrateHookAccounts(signer: PublicKey): Promise<string> {
+    const [extraMetasPda] = findExtraAccountMetasPDA(this.mintAddress, SSS_HOOK_PROGRAM_ID);
+    const ix = new TransactionInstruction({
+      programId: this.program.programId,
+      keys: [
+        { pubkey: signer, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+        { pubkey: this.mintAddress, isSigner: false, isWritable: false },
+        { pubkey: extraMetasPda, isSigner: false, isWritable: true },
+        { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: UPDATE_TRANSFER_HOOK_ACCOUNTS_DISCRIMINATOR,
+    });
+    return this.provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
+  /**
+   * Link this SSS-1 stablecoin to a new SSS-2 one with the same authority and decimals that has
+   * not minted yet. Irreversible: minting here stays paused and holders move over with
+   * `swapLegacy`. `signer` must be the authority of both.
+   */
+  async linkSuccessor(signer: PublicKey, successorMint: PublicKey): Promise<string> {
+    const ix = new TransactionInstruction({
+      programId: this.program.programId,
+      keys: [
+        { pubkey: signer, isSigner: true, isWritable: false },
+        { pubkey: this.stablecoin, isSigner: false, isWritable: true },
+        { pubkey: findStablecoinPDA(successorMint, this.program.programId)[0], isSigner: false, isWritable: true },
+      ],
+      data: LINK_SUCCESSOR_MINT_DISCRIMINATOR,
+    });
+    return this.provider.sendAndConfirm(new Transaction().add(ix));
+  }
+
+  /**
+   * Burn `amount` of the holder's tokens of this (legacy) mint and receive the same amount of
+   * the linked successor mint. Creates the holder's successor token account if missing. The
+   * holder must not be blacklisted on the successor, and must be allowlisted in allowlist mode.
+   */
+  async swapLegacy(holder: PublicKey | Keypair, amount: bigint): Promise<string> {
+    const holderPubkey = holder instanceof Keypair ? holder.publicKey : new PublicKey(holder);
+    const holderKeypair = holder instanceof Keypair ? holder : null;
+    const state = await this.refresh();
+    if (state.successor_mint.equals(PublicKey.default)) {
+      throw new Error("Stablecoin is not linked to a successor mint");
+    }
+    const successorMint = state.successor_mint;
+    const [successorStablecoin] = findStablecoinPDA(successorMint, this.program.programId);
+    const successorAta = getAssociatedTokenAddressSync(
+      successorMint,
+      holderPubkey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const data = Buffer.alloc(16);
+    SWAP_LEGACY_DISCRIMINATOR.copy(data, 0);
+    data.writeBigUInt64LE(amount, 8);
+    const tx = new Transaction();
+    if (!(await this.provider.connection.getAccountInfo(successorAta))) {
+      tx.add(
+        createAssociatedTokenAccountInstruction(
+          this.provider.wallet.publicKey,
+          successorAta,
+          holderPubkey,
+          successorMint,
+          TOKEN_2022_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID
+        )
+      );
+    }
+    tx.add(
+      new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: holderPubkey, isSigner: true, isWritable: false },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: true },
+          { pubkey: this.mintAddress, isSigner: false, isWritable: true },
+          { pubkey: this.getRecipientTokenAccount(holderPubkey), isSigner: false, isWritable: true },
+          { pubkey: successorStablecoin, isSigner: false, isWritable: true },
+          { pubkey: successorMint, isSigner: false, isWritable: true },
+          { pubkey: successorAta, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+          {
+            pubkey: findBlacklistPDA(successorStablecoin, holderPubkey, this.program.programId)[0],
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: findAllowlistPDA(successorStablecoin, holderPubkey, this.program.programId)[0],
+            isSigner: false,
+            isWritable: false,
+          },
+        ],
+        data,
+      })
+    );
+    return this.provider.sendAndConfirm(tx, holderKeypair ? [holderKeypair] : []);
+  }
+
   async getSupplyCap(): Promise<bigint | null> {
     const [supplyCapPda] = findSupplyCapPDA(this.stablecoin, this.program.programId);
     const info = await this.provider.connection.getAccountInfo(supplyCapPda);
@@ -583,25 +917,45 @@ export class SolanaStablecoin {
     return cap === BigInt("18446744073709551615") ? null : cap; // u64::MAX = no cap
   }
 
+  /**
+   * Single-step handoff; the new authority must sign too. Pass it as a Keypair unless it is
+   * the provider wallet. Use the `nominate_authority` / `accept_authority` instructions when it
+   * cannot sign here.
+   */
   async transferAuthority(
     signer: PublicKey,
-    newAuthority: PublicKey
+    newAuthority: PublicKey | Keypair
   ): Promise<string> {
-    return (this.program.methods as unknown as { transferAuthority: () => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
+    const newAuthorityKeypair = newAuthority instanceof Keypair ? newAuthority : null;
+    return (this.program.methods as unknown as { transferAuthority: () => { accountsStrict: (a: object) => { signers: (s: Keypair[]) => { rpc: () => Promise<string> } } } })
       .transferAuthority()
       .accountsStrict({
         authority: signer,
         stablecoin: this.stablecoin,
-        newAuthority,
+        newAuthority: newAuthorityKeypair ? newAuthorityKeypair.publicKey : newAuthority,
       })
+      .signers(newAuthorityKeypair ? [newAuthorityKeypair] : [])
       .rpc();
   }
 
+  /** remaining_accounts for batch blacklist instructions: `[address, blacklist PDA]` pairs. */
+  private blacklistBatchAccounts(addresses: PublicKey[]): AccountMeta[] {
+    return addresses.flatMap((address) => [
+      { pubkey: address, isSigner: false, isWritable: false },
+      {
+        pubkey: findBlacklistPDA(this.stablecoin, address, this.program.programId)[0],
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+  }
+
   readonly compliance = {
     blacklistAdd: async (
       signer: PublicKey,
       address: PublicKey,
-      reason: string
+      reason: string,
+      params: BlacklistEntryParams = {}
     ): Promise<string> => {
       const state = await this.getState();
       if (!state.enable_transfer_hook || !state.enable_permanent_delegate) {
@@ -613,8 +967,12 @@ export class SolanaStablecoin {
         address,
         this.program.programId
       );
-      return (this.program.methods as unknown as { addToBlacklist: (reason: string) => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
-        .addToBlacklist(reason)
+      return (this.program.methods as unknown as { addToBlacklist: (reason: string, reasonCode: object, expiresAt: BN | null) => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
+        .addToBlacklist(
+          reason,
+          { [params.reasonCode ?? "internal"]: {} },
+          params.expiresAt === undefined ? null : new BN(params.expiresAt.toString())
+        )
         .accountsStrict({
           blacklister: signer,
           stablecoin: this.stablecoin,
@@ -652,10 +1010,263 @@ export class SolanaStablecoin {
         .rpc();
     },
 
+    /** Rewrite the reason, reason code and expiry of an existing blacklist entry. */
+    updateBlacklistEntry: async (
+      signer: PublicKey,
+      address: PublicKey,
+      reason: string,
+      params: BlacklistEntryParams = {}
+    ): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [blacklistPda] = findBlacklistPDA(this.stablecoin, address, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: false },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: blacklistPda, isSigner: false, isWritable: true },
+          { pubkey: address, isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([UPDATE_BLACKLIST_ENTRY_DISCRIMINATOR, encodeBlacklistEntryArgs(reason, params)]),
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /**
+     * Blacklist many addresses with one reason, sending one `batch_add_to_blacklist` per
+     * MAX_BATCH_SIZE addresses. Addresses that already have an active entry are skipped.
+     */
+    blacklistAddBatch: async (
+      signer: PublicKey,
+      addresses: PublicKey[],
+      reason: string,
+      params: BlacklistEntryParams = {}
+    ): Promise<string[]> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const data = Buffer.concat([BATCH_ADD_TO_BLACKLIST_DISCRIMINATOR, encodeBlacklistEntryArgs(reason, params)]);
+      const signatures: string[] = [];
+      for (const batch of chunk(addresses, MAX_BATCH_SIZE)) {
+        const ix = new TransactionInstruction({
+          programId: this.program.programId,
+          keys: [
+            { pubkey: signer, isSigner: true, isWritable: true },
+            { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+            { pubkey: rolePda, isSigner: false, isWritable: false },
+            { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+            ...this.blacklistBatchAccounts(batch),
+          ],
+          data,
+        });
+        signatures.push(await this.provider.sendAndConfirm(new Transaction().add(ix)));
+      }
+      return signatures;
+    },
+
+    /** Close the blacklist entries of many addresses, MAX_BATCH_SIZE per transaction. */
+    blacklistRemoveBatch: async (signer: PublicKey, addresses: PublicKey[]): Promise<string[]> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const signatures: string[] = [];
+      for (const batch of chunk(addresses, MAX_BATCH_SIZE)) {
+        const ix = new TransactionInstruction({
+          programId: this.program.programId,
+          keys: [
+            { pubkey: signer, isSigner: true, isWritable: true },
+            { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+            { pubkey: rolePda, isSigner: false, isWritable: false },
+            ...this.blacklistBatchAccounts(batch),
+          ],
+          data: BATCH_REMOVE_FROM_BLACKLIST_DISCRIMINATOR,
+        });
+        signatures.push(await this.provider.sendAndConfirm(new Transaction().add(ix)));
+      }
+      return signatures;
+    },
+
+    /** Allowlist a wallet (allowlist-mode SSS-2; signer needs the allowlister role). */
+    allowlistAdd: async (signer: PublicKey, address: PublicKey): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [allowlistPda] = findAllowlistPDA(this.stablecoin, address, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: allowlistPda, isSigner: false, isWritable: true },
+          { pubkey: address, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data: ADD_TO_ALLOWLIST_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    allowlistRemove: async (signer: PublicKey, address: PublicKey): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [allowlistPda] = findAllowlistPDA(this.stablecoin, address, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: allowlistPda, isSigner: false, isWritable: true },
+          { pubkey: address, isSigner: false, isWritable: false },
+        ],
+        data: REMOVE_FROM_ALLOWLIST_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /** Allowlist the owner of `tokenAccount` and thaw the account in one step. */
+    approveHolder: async (signer: PublicKey, tokenAccount: PublicKey): Promise<string> => {
+      const account = await getAccount(
+        this.provider.connection,
+        tokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [allowlistPda] = findAllowlistPDA(this.stablecoin, account.owner, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: this.mintAddress, isSigner: false, isWritable: false },
+          { pubkey: account.owner, isSigner: false, isWritable: false },
+          { pubkey: tokenAccount, isSigner: false, isWritable: true },
+          { pubkey: allowlistPda, isSigner: false, isWritable: true },
+          { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data: APPROVE_HOLDER_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /**
+     * Set the hook-enforced velocity limits (signer needs the blacklister role). Amounts are in
+     * base units; 0 disables a limit. The mint's extra account meta list must be migrated first.
+     */
+    setVelocityLimits: async (
+      signer: PublicKey,
+      maxPerTransfer: bigint,
+      maxPerWindow: bigint
+    ): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [extraMetasPda] = findExtraAccountMetasPDA(this.mintAddress, SSS_HOOK_PROGRAM_ID);
+      const data = Buffer.alloc(24);
+      SET_VELOCITY_LIMITS_DISCRIMINATOR.copy(data, 0);
+      data.writeBigUInt64LE(maxPerTransfer, 8);
+      data.writeBigUInt64LE(maxPerWindow, 16);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: findVelocityConfigPDA(this.stablecoin, this.program.programId)[0], isSigner: false, isWritable: true },
+          { pubkey: extraMetasPda, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /** Exempt a wallet's outgoing transfers from velocity limits (e.g. treasury, exchange). */
+    velocityExemptionAdd: async (signer: PublicKey, wallet: PublicKey): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [exemptionPda] = findVelocityExemptionPDA(this.stablecoin, wallet, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: exemptionPda, isSigner: false, isWritable: true },
+          { pubkey: wallet, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data: ADD_VELOCITY_EXEMPTION_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    velocityExemptionRemove: async (signer: PublicKey, wallet: PublicKey): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [exemptionPda] = findVelocityExemptionPDA(this.stablecoin, wallet, this.program.programId);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: exemptionPda, isSigner: false, isWritable: true },
+          { pubkey: wallet, isSigner: false, isWritable: false },
+        ],
+        data: REMOVE_VELOCITY_EXEMPTION_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /**
+     * Create `owner`'s velocity usage account on the transfer hook. Until it exists, `owner`'s
+     * transfers are not counted towards the rolling window; anyone may pay for it.
+     */
+    initializeVelocityUsage: async (payer: PublicKey, owner: PublicKey): Promise<string> => {
+      const ix = new TransactionInstruction({
+        programId: SSS_HOOK_PROGRAM_ID,
+        keys: [
+          { pubkey: payer, isSigner: true, isWritable: true },
+          { pubkey: findVelocityUsagePDA(this.mintAddress, owner)[0], isSigner: false, isWritable: true },
+          { pubkey: this.mintAddress, isSigner: false, isWritable: false },
+          { pubkey: owner, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data: INITIALIZE_VELOCITY_USAGE_DISCRIMINATOR,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /**
+     * Set the amount (base units) above which the transfer hook emits `LargeTransferObserved`;
+     * 0 turns reporting off (blacklister role). The mint's ExtraAccountMetaList must include the
+     * reporting config. Decode the events with `parseLargeTransferEvents`.
+     */
+    setReportingThreshold: async (signer: PublicKey, threshold: bigint): Promise<string> => {
+      const [rolePda] = findRolePDA(this.stablecoin, signer, this.program.programId);
+      const [extraMetasPda] = findExtraAccountMetasPDA(this.mintAddress, SSS_HOOK_PROGRAM_ID);
+      const data = Buffer.alloc(16);
+      SET_REPORTING_THRESHOLD_DISCRIMINATOR.copy(data, 0);
+      data.writeBigUInt64LE(threshold, 8);
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys: [
+          { pubkey: signer, isSigner: true, isWritable: true },
+          { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+          { pubkey: rolePda, isSigner: false, isWritable: false },
+          { pubkey: findReportingConfigPDA(this.stablecoin, this.program.programId)[0], isSigner: false, isWritable: true },
+          { pubkey: extraMetasPda, isSigner: false, isWritable: false },
+          { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        data,
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
+    },
+
+    /**
+     * Seize from a blacklisted or frozen token account. Without `caseReference`, a unique
+     * reference is generated so the SeizureRecord PDA does not collide.
+     */
     seize: async (
       signer: PublicKey,
       sourceTokenAccount: PublicKey,
-      destinationTokenAccount: PublicKey
+      destinationTokenAccount: PublicKey,
+      params: SeizeParams = {}
     ): Promise<string> => {
       const state = await this.getState();
       if (
@@ -693,23 +1304,55 @@ export class SolanaStablecoin {
         destOwner,
         this.program.programId
       );
-      return (this.program.methods as unknown as { seize: () => { accountsStrict: (a: object) => { rpc: () => Promise<string> } } })
-        .seize()
-        .accountsStrict({
-          seizer: signer,
-          stablecoin: this.stablecoin,
-          role: rolePda,
-          mint: this.mintAddress,
-          sourceTokenAccount,
-          destinationTokenAccount,
-          transferHookProgram: SSS_HOOK_PROGRAM_ID,
-          extraAccountMetas: extraMetasPda,
-          sssTokenProgram: SSS_TOKEN_PROGRAM_ID,
-          sourceBlacklist: sourceBlacklistPda,
-          destBlacklist: destBlacklistPda,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-        })
-        .rpc();
+      // Trailing account: the hook checks the transfer authority (the stablecoin PDA).
+      const [authorityBlacklistPda] = findBlacklistPDA(
+        this.stablecoin,
+        this.stablecoin,
+        this.program.programId
+      );
+      const caseReference =
+        params.caseReference ?? `seize:${sourceTokenAccount.toBase58()}:${Date.now()}`;
+      const caseReferenceHash = createHash("sha256").update(caseReference).digest();
+      const [seizureRecordPda] = findSeizureRecordPDA(
+        this.stablecoin,
+        sourceTokenAccount,
+        caseReferenceHash,
+        this.program.programId
+      );
+      // Built by hand: the bundled IDL predates the seize arguments and seizure record.
+      const amountArg =
+        params.amount === undefined
+          ? Buffer.from([0])
+          : Buffer.concat([Buffer.from([1]), new BN(params.amount.toString()).toArrayLike(Buffer, "le", 8)]);
+      const keys: AccountMeta[] = [
+        { pubkey: signer, isSigner: true, isWritable: true },
+        { pubkey: this.stablecoin, isSigner: false, isWritable: false },
+        { pubkey: rolePda, isSigner: false, isWritable: false },
+        { pubkey: this.mintAddress, isSigner: false, isWritable: false },
+        { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: destinationTokenAccount, isSigner: false, isWritable: true },
+        { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: extraMetasPda, isSigner: false, isWritable: false },
+        { pubkey: SSS_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: sourceBlacklistPda, isSigner: false, isWritable: false },
+        { pubkey: destBlacklistPda, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: seizureRecordPda, isSigner: false, isWritable: true },
+        { pubkey: SYSTEM_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: authorityBlacklistPda, isSigner: false, isWritable: false },
+        { pubkey: findAllowlistPDA(this.stablecoin, sourceOwner, this.program.programId)[0], isSigner: false, isWritable: false },
+        { pubkey: findAllowlistPDA(this.stablecoin, destOwner, this.program.programId)[0], isSigner: false, isWritable: false },
+        { pubkey: findVelocityConfigPDA(this.stablecoin, this.program.programId)[0], isSigner: false, isWritable: false },
+        { pubkey: findVelocityExemptionPDA(this.stablecoin, sourceOwner, this.program.programId)[0], isSigner: false, isWritable: false },
+        { pubkey: findVelocityUsagePDA(this.mintAddress, sourceOwner)[0], isSigner: false, isWritable: true },
+        { pubkey: findReportingConfigPDA(this.stablecoin, this.program.programId)[0], isSigner: false, isWritable: false },
+      ];
+      const ix = new TransactionInstruction({
+        programId: this.program.programId,
+        keys,
+        data: Buffer.concat([SEIZE_DISCRIMINATOR, amountArg, caseReferenceHash]),
+      });
+      return this.provider.sendAndConfirm(new Transaction().add(ix));
     },
   };
 }
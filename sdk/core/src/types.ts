@@ -4,6 +4,8 @@ export interface StablecoinExtensions {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  /** Allowlist (KYC-gated) mode: only allowlisted holders can send or receive. SSS-2 only. */
+  enableAllowlist?: boolean;
 }
 
 export const Presets = {
@@ -39,6 +41,7 @@ export interface InitializeParams {
   enable_permanent_delegate: boolean;
   enable_transfer_hook: boolean;
   default_account_frozen: boolean;
+  enable_allowlist: boolean;
 }
 
 export interface RoleFlags {
@@ -48,6 +51,7 @@ export interface RoleFlags {
   isFreezer: boolean;
   isBlacklister: boolean;
   isSeizer: boolean;
+  isAllowlister?: boolean;
 }
 
 export interface StablecoinStateType {
@@ -91,6 +95,23 @@ export interface BurnParams {
   amount: bigint;
 }
 
+export interface SeizeParams {
+  /** Partial amount (base units); the full balance when omitted. */
+  amount?: bigint;
+  /** Off-chain case reference (e.g. court order number); stored as sha256 on the SeizureRecord. */
+  caseReference?: string;
+}
+
+/** Mirrors the on-chain `BlacklistReason` enum. */
+export type BlacklistReason = "ofac" | "courtOrder" | "fraud" | "internal";
+
+export interface BlacklistEntryParams {
+  /** Defaults to `"internal"`. */
+  reasonCode?: BlacklistReason;
+  /** Unix seconds after which the entry stops blocking transfers; permanent when omitted. */
+  expiresAt?: bigint;
+}
+
 export interface UpdateRolesParams {
   holder: PublicKey;
   roles: RoleFlags;
@@ -101,6 +122,15 @@ export interface UpdateMinterParams {
   quota: bigint;
 }
 
+export interface UpdateMetadataParams {
+  /** Omitted fields are left unchanged. */
+  name?: string;
+  symbol?: string;
+  uri?: string;
+  /** Additional on-mint fields (e.g. `issuer`, `attestation_url`); an empty value removes the key. */
+  fields?: Record<string, string>;
+}
+
 export function normalizeInitializeParams(
   params: CreateStablecoinParams
 ): InitializeParams {
@@ -112,6 +142,7 @@ export function normalizeInitializeParams(
       enablePermanentDelegate: params.extensions.enablePermanentDelegate ?? false,
       enableTransferHook: params.extensions.enableTransferHook ?? false,
       defaultAccountFrozen: params.extensions.defaultAccountFrozen ?? false,
+      enableAllowlist: params.extensions.enableAllowlist ?? false,
     };
   } else {
     ext = Presets.SSS_1;
@@ -124,5 +155,6 @@ export function normalizeInitializeParams(
     enable_permanent_delegate: ext.enablePermanentDelegate,
     enable_transfer_hook: ext.enableTransferHook,
     default_account_frozen: ext.defaultAccountFrozen,
+    enable_allowlist: ext.enableAllowlist ?? false,
   };
 }
@@ -0,0 +1,56 @@
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import {
+  decodeLargeTransferObserved,
+  LARGE_TRANSFER_OBSERVED_DISCRIMINATOR,
+  parseLargeTransferEvents,
+} from "../src/hookEvents";
+
+describe("Hook events", () => {
+  const mint = Keypair.generate().publicKey;
+  const source = Keypair.generate().publicKey;
+  const dest = Keypair.generate().publicKey;
+
+  function encode(amount: bigint, slot: bigint): Buffer {
+    const tail = Buffer.alloc(16);
+    tail.writeBigUInt64LE(amount, 0);
+    tail.writeBigUInt64LE(slot, 8);
+    return Buffer.concat([
+      LARGE_TRANSFER_OBSERVED_DISCRIMINATOR,
+      mint.toBuffer(),
+      source.toBuffer(),
+      dest.toBuffer(),
+      tail,
+    ]);
+  }
+
+  it("decodes LargeTransferObserved", () => {
+    const event = decodeLargeTransferObserved(encode(25_000_000_000n, 123n));
+    expect(event).to.not.be.null;
+    expect(event!.mint.equals(mint)).to.be.true;
+    expect(event!.sourceOwner.equals(source)).to.be.true;
+    expect(event!.destinationOwner.equals(dest)).to.be.true;
+    expect(event!.amount).to.equal(25_000_000_000n);
+    expect(event!.slot).to.equal(123n);
+  });
+
+  it("ignores other events and short payloads", () => {
+    const other = encode(1n, 1n);
+    other[0] ^= 0xff;
+    expect(decodeLargeTransferObserved(other)).to.be.null;
+    expect(decodeLargeTransferObserved(LARGE_TRANSFER_OBSERVED_DISCRIMINATOR)).to.be.null;
+  });
+
+  it("parseLargeTransferEvents picks events out of transaction logs", () => {
+    const logs = [
+      "Program TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb invoke [1]",
+      "Program log: Instruction: TransferChecked",
+      `Program data: ${encode(42n, 7n).toString("base64")}`,
+      "Program data: AAAA",
+      "Program TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb success",
+    ];
+    const events = parseLargeTransferEvents(logs);
+    expect(events).to.have.length(1);
+    expect(events[0].amount).to.equal(42n);
+  });
+});
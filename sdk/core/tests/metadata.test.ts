@@ -0,0 +1,35 @@
+import { expect } from "chai";
+import { encodeUpdateMetadataArgs } from "../src/stablecoin";
+
+describe("encodeUpdateMetadataArgs", () => {
+  it("encodes omitted fields as None and an empty field list", () => {
+    expect(encodeUpdateMetadataArgs({}).toString("hex")).to.equal("000000" + "00000000");
+  });
+
+  it("encodes set fields as Some(string) followed by key/value pairs", () => {
+    const data = encodeUpdateMetadataArgs({
+      symbol: "USDX",
+      fields: { issuer: "Acme", attestation_url: "" },
+    });
+    let offset = 0;
+    expect(data[offset++]).to.equal(0); // name: None
+    expect(data[offset++]).to.equal(1); // symbol: Some
+    expect(data.readUInt32LE(offset)).to.equal(4);
+    expect(data.subarray(offset + 4, offset + 8).toString()).to.equal("USDX");
+    offset += 8;
+    expect(data[offset++]).to.equal(0); // uri: None
+    expect(data.readUInt32LE(offset)).to.equal(2);
+    offset += 4;
+
+    const readString = () => {
+      const len = data.readUInt32LE(offset);
+      const value = data.subarray(offset + 4, offset + 4 + len).toString();
+      offset += 4 + len;
+      return value;
+    };
+    expect([readString(), readString()]).to.deep.equal(["issuer", "Acme"]);
+    // An empty value removes the key on-chain
+    expect([readString(), readString()]).to.deep.equal(["attestation_url", ""]);
+    expect(offset).to.equal(data.length);
+  });
+});
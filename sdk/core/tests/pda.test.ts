@@ -6,11 +6,13 @@ import {
   findMinterPDA,
   findBlacklistPDA,
   findExtraAccountMetasPDA,
+  findVelocityUsagePDA,
   STABLECOIN_SEED,
   ROLE_SEED,
   MINTER_SEED,
   BLACKLIST_SEED,
   EXTRA_ACCOUNT_METAS_SEED,
+  VELOCITY_USAGE_SEED,
 } from "../src/pda";
 import { SSS_TOKEN_PROGRAM_ID, SSS_HOOK_PROGRAM_ID } from "../src/constants";
 
@@ -98,6 +100,15 @@ describe("PDA derivation", () => {
     expect(pda.equals(expected)).to.be.true;
   });
 
+  it("findVelocityUsagePDA uses hook program", () => {
+    const [pda] = findVelocityUsagePDA(mint, holder);
+    const [expected] = PublicKey.findProgramAddressSync(
+      [VELOCITY_USAGE_SEED, mint.toBuffer(), holder.toBuffer()],
+      SSS_HOOK_PROGRAM_ID
+    );
+    expect(pda.equals(expected)).to.be.true;
+  });
+
   it("same seed with different program ID yields different PDA", () => {
     const [pda1] = findStablecoinPDA(mint, programId);
     const [pda2] = findStablecoinPDA(mint, SSS_HOOK_PROGRAM_ID);
@@ -62,6 +62,16 @@ describe("Presets and config", () => {
     expect(out.default_account_frozen).to.be.true;
   });
 
+  it("normalizeInitializeParams enables allowlist mode only via extensions", () => {
+    expect(normalizeInitializeParams({ ...baseParams, preset: "SSS_2" }).enable_allowlist).to.be.false;
+    const out = normalizeInitializeParams({
+      ...baseParams,
+      extensions: { ...Presets.SSS_2, enableAllowlist: true },
+    });
+    expect(out.enable_transfer_hook).to.be.true;
+    expect(out.enable_allowlist).to.be.true;
+  });
+
   it("normalizeInitializeParams with no preset or extensions defaults to SSS_1", () => {
     const out = normalizeInitializeParams(baseParams);
     expect(out.enable_permanent_delegate).to.be.false;
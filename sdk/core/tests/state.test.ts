@@ -8,6 +8,7 @@ const dummyPubkey = "So11111111111111111111111111111111111111112";
 describe("toStablecoinState and supply", () => {
   it("toStablecoinState with snake_case keys", () => {
     const raw = {
+      version: 1,
       authority: dummyPubkey,
       mint: dummyPubkey,
       name: "Test",
@@ -23,6 +24,7 @@ describe("toStablecoinState and supply", () => {
       bump: 254,
     };
     const state = toStablecoinState(raw);
+    expect(state.version).to.equal(1);
     expect(state.name).to.equal("Test");
     expect(state.enable_permanent_delegate).to.be.true;
     expect(state.enable_transfer_hook).to.be.false;
@@ -45,6 +47,8 @@ describe("toStablecoinState and supply", () => {
       totalMinted: BigInt(999),
       totalBurned: BigInt(111),
       bump: 1,
+      legacyMint: dummyPubkey,
+      legacyOutstanding: BigInt(500),
     };
     const state = toStablecoinState(raw);
     expect(state.total_minted.toString()).to.equal("999");
@@ -52,6 +56,8 @@ describe("toStablecoinState and supply", () => {
     expect(state.enable_transfer_hook).to.be.true;
     expect(state.default_account_frozen).to.be.true;
     expect(state.paused).to.be.true;
+    expect(state.legacy_mint.toBase58()).to.equal(dummyPubkey);
+    expect(state.legacy_outstanding.toString()).to.equal("500");
   });
 
   it("toStablecoinState with null/undefined falls back to defaults", () => {
@@ -67,6 +73,10 @@ describe("toStablecoinState and supply", () => {
     expect(state.total_minted.toString()).to.equal("0");
     expect(state.total_burned.toString()).to.equal("0");
     expect(state.bump).to.equal(0);
+    expect(state.version).to.equal(0);
+    expect(state.successor_mint.equals(PublicKey.default)).to.be.true;
+    expect(state.legacy_mint.equals(PublicKey.default)).to.be.true;
+    expect(state.legacy_outstanding.toString()).to.equal("0");
   });
 
   it("supply = total_minted - total_burned from state", () => {
@@ -0,0 +1,193 @@
+import { createTransferCheckedWithTransferHookInstruction, getAccount } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildApproveHolderIx,
+  buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
+  buildMintTokensIx,
+  buildRemoveFromAllowlistIx,
+  buildThawAccountIx,
+  buildUpdateMinterIx,
+  buildUpdateRolesIx,
+  createTokenAccount,
+  findAllowlistPDA,
+  findExtraAccountMetasPDA,
+  findMinterPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+describe("Allowlist Mode", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let allowlister: Keypair;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+  let aliceATA: PublicKey;
+  let bobATA: PublicKey;
+  let carolATA: PublicKey;
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    allowlister = Keypair.generate();
+    alice = Keypair.generate();
+    bob = Keypair.generate();
+    carol = Keypair.generate();
+    await fundKeypairs(provider, [allowlister, alice, bob, carol]);
+  });
+
+  function approveHolder(signer: Keypair, holder: PublicKey, tokenAccount: PublicKey) {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [role] = findRolePDA(stablecoinPDA, signer.publicKey);
+    const [entry] = findAllowlistPDA(stablecoinPDA, holder);
+    return sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildApproveHolderIx(signer.publicKey, stablecoinPDA, role, mintKeypair.publicKey, holder, tokenAccount, entry)
+      ),
+      [signer]
+    );
+  }
+
+  async function transfer(from: Keypair, source: PublicKey, destination: PublicKey, amount: bigint) {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      connection,
+      source,
+      mintKeypair.publicKey,
+      destination,
+      from.publicKey,
+      amount,
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    return sendAndConfirmTransaction(connection, new Transaction().add(ix), [from]);
+  }
+
+  it("creates an allowlist-mode SSS-2 stablecoin", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [allowlisterRole] = findRolePDA(stablecoinPDA, allowlister.publicKey);
+    const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "KYC USD",
+          symbol: "KUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: true,
+          enableAllowlist: true,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize allowlist mode"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildInitializeTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas))
+        .add(buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, 1_000_000n))
+        .add(
+          buildUpdateRolesIx(authority.publicKey, stablecoinPDA, allowlisterRole, allowlister.publicKey, {
+            isMinter: false,
+            isBurner: false,
+            isPauser: false,
+            isFreezer: false,
+            isBlacklister: false,
+            isSeizer: false,
+            isAllowlister: true,
+          })
+        ),
+      [authority]
+    );
+
+    aliceATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, alice.publicKey);
+    bobATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, bob.publicKey);
+    carolATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, carol.publicKey);
+    expect((await getAccount(connection, aliceATA, "confirmed", TOKEN_2022_PROGRAM_ID)).isFrozen).to.equal(true);
+  });
+
+  it("approve_holder allowlists and thaws in one step", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    await approveHolder(allowlister, alice.publicKey, aliceATA);
+    await approveHolder(allowlister, bob.publicKey, bobATA);
+
+    expect((await getAccount(connection, aliceATA, "confirmed", TOKEN_2022_PROGRAM_ID)).isFrozen).to.equal(false);
+    const [aliceEntry] = findAllowlistPDA(stablecoinPDA, alice.publicKey);
+    expect(await connection.getAccountInfo(aliceEntry)).to.not.be.null;
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, aliceATA, 10_000n)),
+      [authority]
+    );
+    await transfer(alice, aliceATA, bobATA, 1_000n);
+    expect((await connection.getTokenAccountBalance(bobATA)).value.amount).to.equal("1000");
+  });
+
+  it("rejects transfers to a thawed holder without an allowlist entry", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, carolATA)),
+      [authority]
+    );
+
+    try {
+      await transfer(alice, aliceATA, carolATA, 100n);
+      expect.fail("Transfer to a non-allowlisted holder should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NotAllowlisted|custom program error|0x/i);
+    }
+  });
+
+  it("rejects transfers from a holder removed from the allowlist", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [allowlisterRole] = findRolePDA(stablecoinPDA, allowlister.publicKey);
+    const [bobEntry] = findAllowlistPDA(stablecoinPDA, bob.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildRemoveFromAllowlistIx(allowlister.publicKey, stablecoinPDA, allowlisterRole, bobEntry, bob.publicKey)),
+      [allowlister]
+    );
+
+    try {
+      await transfer(bob, bobATA, aliceATA, 100n);
+      expect.fail("Transfer from a removed holder should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NotAllowlisted|custom program error|0x/i);
+    }
+  });
+
+  it("rejects approve_holder from a non-allowlister", async () => {
+    try {
+      await approveHolder(carol, carol.publicKey, carolATA);
+      expect.fail("Non-allowlister should not approve holders");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Unauthorized|AccountNotInitialized|Simulation failed|custom program error|0x/i);
+    }
+  });
+});
@@ -1,7 +1,10 @@
 import { Keypair, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
 import { expect } from "chai";
 import {
+  buildAcceptAuthorityIx,
+  buildCancelAuthorityTransferIx,
   buildInitializeIx,
+  buildNominateAuthorityIx,
   buildPauseIx,
   buildTransferAuthorityIx,
   buildUnpauseIx,
@@ -65,18 +68,31 @@ describe("Authority Transfer", () => {
     await sendAndConfirmAndLog(
       connection,
       new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
-      [authority],
+      [authority, newAuthorityKeypair],
       "Transfer authority"
     );
 
     await sendAndConfirmAndLog(
       connection,
       new Transaction().add(buildTransferAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA, authority.publicKey)),
-      [newAuthorityKeypair],
+      [newAuthorityKeypair, authority],
       "Transfer authority back"
     );
   });
 
+  it("rejects a single-step transfer the new authority did not sign", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const ix = buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, Keypair.generate().publicKey);
+    ix.keys[2].isSigner = false;
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+      expect.fail("Transfer to an unsigned key should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AccountNotSigner|Simulation failed|custom program error|0x|3010/i);
+    }
+  });
+
   it("old authority cannot pause after transfer", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
@@ -111,7 +127,7 @@ describe("Authority Transfer", () => {
     await sendAndConfirmTransaction(
       connection,
       new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
-      [authority]
+      [authority, newAuthorityKeypair]
     );
 
     try {
@@ -129,7 +145,7 @@ describe("Authority Transfer", () => {
     await sendAndConfirmTransaction(
       connection,
       new Transaction().add(buildTransferAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA, authority.publicKey)),
-      [newAuthorityKeypair]
+      [newAuthorityKeypair, authority]
     );
   });
 
@@ -156,7 +172,7 @@ describe("Authority Transfer", () => {
     await sendAndConfirmTransaction(
       connection,
       new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
-      [authority]
+      [authority, newAuthorityKeypair]
     );
 
     await sendAndConfirmTransaction(
@@ -189,7 +205,111 @@ describe("Authority Transfer", () => {
     await sendAndConfirmTransaction(
       connection,
       new Transaction().add(buildTransferAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA, authority.publicKey)),
+      [newAuthorityKeypair, authority]
+    );
+  });
+
+  it("nominee must accept before authority changes", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildNominateAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
+      [authority],
+      "Nominate authority"
+    );
+
+    // Nomination alone does not hand over control: current authority can still update roles.
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, authorityRole, authority.publicKey, {
+          isMinter: false,
+          isBurner: false,
+          isPauser: true,
+          isFreezer: false,
+          isBlacklister: false,
+          isSeizer: false,
+        })
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildAcceptAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA)),
+      [newAuthorityKeypair],
+      "Accept authority"
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildNominateAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA, authority.publicKey)),
       [newAuthorityKeypair]
     );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildAcceptAuthorityIx(authority.publicKey, stablecoinPDA)),
+      [authority]
+    );
+  });
+
+  it("only the nominee can accept", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const stranger = Keypair.generate();
+    await fundKeypairs(provider, [stranger]);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildNominateAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
+      [authority]
+    );
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAuthorityIx(stranger.publicKey, stablecoinPDA)),
+        [stranger]
+      );
+      expect.fail("Non-nominee should not be able to accept");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Unauthorized|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildCancelAuthorityTransferIx(authority.publicKey, stablecoinPDA)),
+      [authority]
+    );
+  });
+
+  it("cancelled nomination cannot be accepted", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildNominateAuthorityIx(authority.publicKey, stablecoinPDA, newAuthorityKeypair.publicKey)),
+      [authority]
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildCancelAuthorityTransferIx(authority.publicKey, stablecoinPDA)),
+      [authority],
+      "Cancel authority transfer"
+    );
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildAcceptAuthorityIx(newAuthorityKeypair.publicKey, stablecoinPDA)),
+        [newAuthorityKeypair]
+      );
+      expect.fail("Cancelled nomination should not be acceptable");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NoPendingAuthority|Simulation failed|custom program error|0x/i);
+    }
   });
 });
@@ -0,0 +1,170 @@
+import { getAccount } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  BlacklistReason,
+  buildBatchAddToBlacklistIx,
+  buildBatchFreezeAccountsIx,
+  buildBatchRemoveFromBlacklistIx,
+  buildFreezeAccountIx,
+  buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
+  createTokenAccount,
+  findBlacklistPDA,
+  findExtraAccountMetasPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { getProvider } from "./testSetup";
+
+describe("Batch Blacklist and Freeze", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let stablecoinPDA: PublicKey;
+  let authorityRole: PublicKey;
+  const sanctioned = Array.from({ length: 3 }, () => Keypair.generate().publicKey);
+
+  async function expectFailure(ix: TransactionInstruction, pattern: RegExp) {
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+      expect.fail("Transaction should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(pattern);
+    }
+  }
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Batch USD",
+          symbol: "BUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize SSS-2"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildInitializeTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas)
+      ),
+      [authority]
+    );
+  });
+
+  it("batch_add_to_blacklist creates an entry per address and skips active ones", async () => {
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBatchAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, sanctioned.slice(0, 1), "SDN update", {
+          reasonCode: BlacklistReason.Ofac,
+        })
+      ),
+      [authority]
+    );
+
+    // The first address is already blacklisted and is skipped rather than failing the batch.
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBatchAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, sanctioned, "SDN update", {
+          reasonCode: BlacklistReason.Ofac,
+        })
+      ),
+      [authority]
+    );
+
+    for (const address of sanctioned) {
+      const [entry] = findBlacklistPDA(stablecoinPDA, address);
+      const info = await connection.getAccountInfo(entry);
+      expect(info).to.not.be.null;
+      // BlacklistEntry: discriminator(8) + stablecoin(32) + address(32) + expires_at(8) + reason_code(1)
+      expect(new PublicKey(info!.data.subarray(40, 72)).toBase58()).to.equal(address.toBase58());
+      expect(info!.data.readBigInt64LE(72)).to.equal(0n);
+      expect(info!.data[80]).to.equal(BlacklistReason.Ofac);
+    }
+  });
+
+  it("rejects an empty or oversized batch", async () => {
+    await expectFailure(
+      buildBatchAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, [], "empty"),
+      /InvalidBatchSize|custom program error|0x/i
+    );
+    const tooMany = Array.from({ length: 11 }, () => Keypair.generate().publicKey);
+    await expectFailure(
+      buildBatchAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, tooMany, "too many"),
+      /InvalidBatchSize|custom program error|0x/i
+    );
+  });
+
+  it("rejects a blacklist entry that is not the address's PDA", async () => {
+    const address = Keypair.generate().publicKey;
+    const ix = buildBatchAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, [address], "mismatch");
+    // Swap the entry for another address's blacklist PDA
+    ix.keys[5].pubkey = findBlacklistPDA(stablecoinPDA, sanctioned[0])[0];
+    await expectFailure(ix, /InvalidBatchAccount|custom program error|0x/i);
+  });
+
+  it("batch_remove_from_blacklist closes entries and skips unknown addresses", async () => {
+    const unknown = Keypair.generate().publicKey;
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBatchRemoveFromBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, [...sanctioned, unknown])
+      ),
+      [authority]
+    );
+
+    for (const address of sanctioned) {
+      const [entry] = findBlacklistPDA(stablecoinPDA, address);
+      expect(await connection.getAccountInfo(entry)).to.be.null;
+    }
+  });
+
+  it("batch_freeze_accounts freezes every token account and skips frozen ones", async () => {
+    const owners = Array.from({ length: 3 }, () => Keypair.generate().publicKey);
+    const tokenAccounts: PublicKey[] = [];
+    for (const owner of owners) {
+      tokenAccounts.push(await createTokenAccount(connection, authority, mintKeypair.publicKey, owner));
+    }
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildFreezeAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, tokenAccounts[0])
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBatchFreezeAccountsIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, tokenAccounts)
+      ),
+      [authority]
+    );
+
+    for (const tokenAccount of tokenAccounts) {
+      const account = await getAccount(connection, tokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(account.isFrozen).to.equal(true);
+    }
+  });
+});
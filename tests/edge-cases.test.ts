@@ -9,6 +9,7 @@ import {
   buildUpdateMinterIx,
   buildUpdateRolesIx,
   buildUpdateSupplyCapIx,
+  buildUpdateSupplyCapModeIx,
   createTokenAccount,
   findMinterPDA,
   findRolePDA,
@@ -16,7 +17,9 @@ import {
   findSupplyCapPDA,
   getTokenAccountAddress,
   sendAndConfirmAndLog,
-  SSS_HOOK_PROGRAM_ID
+  SSS_HOOK_PROGRAM_ID,
+  SSS_TOKEN_PROGRAM_ID,
+  SupplyCapMode
 } from "./helpers";
 import { fundKeypairs, getProvider } from "./testSetup";
 
@@ -190,6 +193,35 @@ describe("Edge Cases", () => {
     }
   });
 
+  it("rejects the program ID in place of an existing supply cap", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const recipientATA = getTokenAccountAddress(mintKeypair.publicKey, recipientKeypair.publicKey);
+
+    // The cap set above exists; leaving it out must not skip the check.
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(
+            minterKeypair.publicKey,
+            stablecoinPDA,
+            findRolePDA(stablecoinPDA, minterKeypair.publicKey)[0],
+            findMinterPDA(stablecoinPDA, minterKeypair.publicKey)[0],
+            mintKeypair.publicKey,
+            recipientATA,
+            BigInt(100),
+            SSS_TOKEN_PROGRAM_ID
+          )
+        ),
+        [minterKeypair]
+      );
+      expect.fail("Should reject mint without the supply cap PDA");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/ConstraintSeeds|Simulation failed|custom program error|0x/i);
+    }
+  });
+
   it("rejects mint when paused", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
@@ -264,4 +296,85 @@ describe("Edge Cases", () => {
       [authority]
     );
   });
+
+  it("circulating cap mode frees capacity after burns", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [supplyCapPDA] = findSupplyCapPDA(stablecoinPDA);
+    const [burnerRole] = findRolePDA(stablecoinPDA, burnerKeypair.publicKey);
+    const burnerATA = getTokenAccountAddress(mintKeypair.publicKey, burnerKeypair.publicKey);
+    const mintIx = (amount: bigint) =>
+      buildMintTokensIx(
+        minterKeypair.publicKey,
+        stablecoinPDA,
+        findRolePDA(stablecoinPDA, minterKeypair.publicKey)[0],
+        findMinterPDA(stablecoinPDA, minterKeypair.publicKey)[0],
+        mintKeypair.publicKey,
+        burnerATA,
+        amount,
+        supplyCapPDA
+      );
+    const expectCapExceeded = async (amount: bigint) => {
+      try {
+        await sendAndConfirmTransaction(connection, new Transaction().add(mintIx(amount)), [minterKeypair]);
+        expect.fail("Mint above the cap should fail");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/SupplyCapExceeded|Simulation failed|custom program error|0x/i);
+      }
+    };
+
+    // 1000 minted so far; cap it there in the default lifetime-minted mode.
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildUpdateSupplyCapIx(authority.publicKey, stablecoinPDA, supplyCapPDA, BigInt(1000))),
+      [authority]
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildBurnTokensIx(burnerKeypair.publicKey, stablecoinPDA, burnerRole, mintKeypair.publicKey, burnerATA, BigInt(400))
+      ),
+      [burnerKeypair]
+    );
+    await expectCapExceeded(BigInt(1));
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildUpdateSupplyCapModeIx(authority.publicKey, stablecoinPDA, supplyCapPDA, SupplyCapMode.Circulating)
+      ),
+      [authority],
+      "Supply cap mode"
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(mintIx(BigInt(400))), [minterKeypair]);
+    await expectCapExceeded(BigInt(1));
+
+    // Validated against the chosen metric: circulating is back at 1000.
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateSupplyCapIx(authority.publicKey, stablecoinPDA, supplyCapPDA, BigInt(999))),
+        [authority]
+      );
+      expect.fail("Cap below circulating supply should be rejected");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/SupplyCapExceeded|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateSupplyCapModeIx(
+          authority.publicKey,
+          stablecoinPDA,
+          supplyCapPDA,
+          SupplyCapMode.MintSupply,
+          mintKeypair.publicKey
+        )
+      ),
+      [authority]
+    );
+    await expectCapExceeded(BigInt(1));
+  });
 });
@@ -10,6 +10,7 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import * as crypto from "crypto";
+import { encodeUpdateMetadataArgs, type UpdateMetadataParams } from "@stbr/sss-token";
 import idlSssToken from "../sdk/core/src/idl/solana_stablecoin_standard.json";
 import idlSssHook from "../sdk/core/src/idl/sss_transfer_hook.json";
 
@@ -61,6 +62,67 @@ export function findBlacklistPDA(
   );
 }
 
+export function findAllowlistPDA(
+  stablecoin: PublicKey,
+  address: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("allowlist"), stablecoin.toBuffer(), address.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findVelocityConfigPDA(stablecoin: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("velocity_config"), stablecoin.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findVelocityExemptionPDA(
+  stablecoin: PublicKey,
+  wallet: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("velocity_exempt"), stablecoin.toBuffer(), wallet.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+/** Per-wallet usage PDA owned by the transfer hook program. */
+export function findVelocityUsagePDA(mint: PublicKey, owner: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("velocity_usage"), mint.toBuffer(), owner.toBuffer()],
+    SSS_HOOK_PROGRAM_ID
+  );
+}
+
+export interface VelocityAccounts {
+  config: PublicKey;
+  exemption: PublicKey;
+  usage: PublicKey;
+}
+
+/** The velocity hook accounts ([12]..[14]) for transfers out of `sourceOwner`'s account. */
+export function findVelocityAccounts(
+  stablecoin: PublicKey,
+  mint: PublicKey,
+  sourceOwner: PublicKey
+): VelocityAccounts {
+  return {
+    config: findVelocityConfigPDA(stablecoin)[0],
+    exemption: findVelocityExemptionPDA(stablecoin, sourceOwner)[0],
+    usage: findVelocityUsagePDA(mint, sourceOwner)[0],
+  };
+}
+
+export function findReportingConfigPDA(stablecoin: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reporting_config"), stablecoin.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
 export function findSupplyCapPDA(stablecoin: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("supply_cap"), stablecoin.toBuffer()],
@@ -68,6 +130,71 @@ export function findSupplyCapPDA(stablecoin: PublicKey): [PublicKey, number] {
   );
 }
 
+export function findMultisigPDA(stablecoin: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("multisig"), stablecoin.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findProposalPDA(
+  multisig: PublicKey,
+  id: bigint
+): [PublicKey, number] {
+  const idBuf = Buffer.alloc(8);
+  idBuf.writeBigUInt64LE(id);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("proposal"), multisig.toBuffer(), idBuf],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findQueuedChangePDA(
+  stablecoin: PublicKey,
+  id: bigint
+): [PublicKey, number] {
+  const idBuf = Buffer.alloc(8);
+  idBuf.writeBigUInt64LE(id);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("queued_change"), stablecoin.toBuffer(), idBuf],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findRedemptionRequestPDA(
+  stablecoin: PublicKey,
+  requester: PublicKey,
+  referenceHash: Buffer
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("redemption"), stablecoin.toBuffer(), requester.toBuffer(), referenceHash],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+/** sha256 of an off-chain case reference (e.g. court order number), as stored on SeizureRecord. */
+export function caseReferenceHash(reference: string): Buffer {
+  return crypto.createHash("sha256").update(reference).digest();
+}
+
+export function findSeizureRecordPDA(
+  stablecoin: PublicKey,
+  sourceTokenAccount: PublicKey,
+  caseReferenceHash: Buffer
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("seizure"), stablecoin.toBuffer(), sourceTokenAccount.toBuffer(), caseReferenceHash],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
+export function findRedemptionEscrowPDA(request: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("redemption_escrow"), request.toBuffer()],
+    SSS_TOKEN_PROGRAM_ID
+  );
+}
+
 export function findExtraAccountMetasPDA(
   mint: PublicKey,
   hookProgramId: PublicKey
@@ -91,6 +218,8 @@ export interface InitializeParams {
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
+  /** Allowlist (KYC-gated) mode; SSS-2 only. Defaults to false. */
+  enableAllowlist?: boolean;
 }
 
 export function serializeInitializeParams(params: InitializeParams): Buffer {
@@ -108,6 +237,7 @@ export function serializeInitializeParams(params: InitializeParams): Buffer {
     Buffer.from([params.enablePermanentDelegate ? 1 : 0]),
     Buffer.from([params.enableTransferHook ? 1 : 0]),
     Buffer.from([params.defaultAccountFrozen ? 1 : 0]),
+    Buffer.from([params.enableAllowlist ? 1 : 0]),
   ]);
 }
 
@@ -146,6 +276,7 @@ export interface RoleFlags {
   isFreezer: boolean;
   isBlacklister: boolean;
   isSeizer: boolean;
+  isAllowlister?: boolean;
 }
 
 export function serializeRoleFlags(roles: RoleFlags): Buffer {
@@ -156,6 +287,7 @@ export function serializeRoleFlags(roles: RoleFlags): Buffer {
     roles.isFreezer ? 1 : 0,
     roles.isBlacklister ? 1 : 0,
     roles.isSeizer ? 1 : 0,
+    roles.isAllowlister ? 1 : 0,
   ]);
 }
 
@@ -209,11 +341,73 @@ export function buildUpdateMinterIx(
   });
 }
 
+export function buildRemoveMinterIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  minterInfo: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: minterInfo, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("remove_minter"),
+  });
+}
+
+export function buildRevokeRoleAccountIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("revoke_role_account"),
+  });
+}
+
+/** windowSeconds = 0 (with windowLimit = 0) restores lifetime quota mode. */
+export function buildUpdateMinterAllowanceIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  minterInfo: PublicKey,
+  windowSeconds: bigint,
+  windowLimit: bigint
+): TransactionInstruction {
+  const windowBuf = Buffer.alloc(8);
+  windowBuf.writeBigInt64LE(windowSeconds);
+  const limitBuf = Buffer.alloc(8);
+  limitBuf.writeBigUInt64LE(windowLimit);
+  const data = Buffer.concat([
+    anchorDiscriminator("update_minter_allowance"),
+    windowBuf,
+    limitBuf,
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: minterInfo, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+/** Pass `mint` when the cap is enforced in MintSupply mode. */
 export function buildUpdateSupplyCapIx(
   authority: PublicKey,
   stablecoin: PublicKey,
   supplyCap: PublicKey,
-  cap: bigint
+  cap: bigint,
+  mint?: PublicKey
 ): TransactionInstruction {
   const capBuf = Buffer.alloc(8);
   capBuf.writeBigUInt64LE(cap);
@@ -227,6 +421,38 @@ export function buildUpdateSupplyCapIx(
       { pubkey: stablecoin, isSigner: false, isWritable: false },
       { pubkey: supplyCap, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...(mint ? [{ pubkey: mint, isSigner: false, isWritable: false }] : []),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+/** On-chain `SupplyCapMode` variant index. */
+export enum SupplyCapMode {
+  LifetimeMinted = 0,
+  Circulating = 1,
+  MintSupply = 2,
+}
+
+/** Pass `mint` when switching to MintSupply. */
+export function buildUpdateSupplyCapModeIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  supplyCap: PublicKey,
+  mode: SupplyCapMode,
+  mint?: PublicKey
+): TransactionInstruction {
+  const data = Buffer.concat([
+    anchorDiscriminator("update_supply_cap_mode"),
+    Buffer.from([mode]),
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: supplyCap, isSigner: false, isWritable: true },
+      readonlyOptionalAccount(mint),
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
     data,
@@ -241,7 +467,7 @@ export function buildMintTokensIx(
   mint: PublicKey,
   recipientTokenAccount: PublicKey,
   amount: bigint,
-  supplyCap: PublicKey = SSS_TOKEN_PROGRAM_ID
+  supplyCap: PublicKey = findSupplyCapPDA(stablecoin)[0]
 ): TransactionInstruction {
   const amountBuf = Buffer.alloc(8);
   amountBuf.writeBigUInt64LE(amount);
@@ -293,145 +519,847 @@ export function buildBurnTokensIx(
   });
 }
 
-export function buildPauseIx(
-  authority: PublicKey,
+export function buildBurnFromIx(
+  burner: PublicKey,
   stablecoin: PublicKey,
-  role: PublicKey
+  role: PublicKey,
+  mint: PublicKey,
+  sourceTokenAccount: PublicKey,
+  amount: bigint,
+  reference: string
 ): TransactionInstruction {
+  const amountBuf = Buffer.alloc(8);
+  amountBuf.writeBigUInt64LE(amount);
+  const referenceBytes = Buffer.from(reference, "utf-8");
+  const data = Buffer.concat([
+    anchorDiscriminator("burn_from"),
+    amountBuf,
+    Buffer.from(new Uint32Array([referenceBytes.length]).buffer),
+    referenceBytes,
+  ]);
   return new TransactionInstruction({
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: burner, isSigner: true, isWritable: false },
       { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("pause"),
+    data,
   });
 }
 
-export function buildUnpauseIx(
-  authority: PublicKey,
+export function buildRequestRedemptionIx(
+  requester: PublicKey,
   stablecoin: PublicKey,
-  role: PublicKey
+  mint: PublicKey,
+  sourceTokenAccount: PublicKey,
+  redemptionRequest: PublicKey,
+  escrowTokenAccount: PublicKey,
+  amount: bigint,
+  referenceHash: Buffer,
+  hookAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] = []
 ): TransactionInstruction {
+  const amountBuf = Buffer.alloc(8);
+  amountBuf.writeBigUInt64LE(amount);
   return new TransactionInstruction({
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
-      { pubkey: stablecoin, isSigner: false, isWritable: true },
-      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: requester, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: redemptionRequest, isSigner: false, isWritable: true },
+      { pubkey: escrowTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...hookAccounts,
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("unpause"),
+    data: Buffer.concat([anchorDiscriminator("request_redemption"), amountBuf, referenceHash]),
   });
 }
 
-export function buildFreezeAccountIx(
-  authority: PublicKey,
+export function buildFulfillRedemptionIx(
+  burner: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
   mint: PublicKey,
-  targetTokenAccount: PublicKey
+  redemptionRequest: PublicKey,
+  escrowTokenAccount: PublicKey,
+  requester: PublicKey
 ): TransactionInstruction {
   return new TransactionInstruction({
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
-      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: burner, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
       { pubkey: role, isSigner: false, isWritable: false },
-      { pubkey: mint, isSigner: false, isWritable: false },
-      { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: redemptionRequest, isSigner: false, isWritable: true },
+      { pubkey: escrowTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: requester, isSigner: false, isWritable: true },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("freeze_account"),
+    data: anchorDiscriminator("fulfill_redemption"),
   });
 }
 
-export function buildThawAccountIx(
-  authority: PublicKey,
+export function buildRejectRedemptionIx(
+  burner: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
   mint: PublicKey,
-  targetTokenAccount: PublicKey
+  redemptionRequest: PublicKey,
+  escrowTokenAccount: PublicKey,
+  sourceTokenAccount: PublicKey,
+  requester: PublicKey,
+  hookAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] = []
 ): TransactionInstruction {
   return new TransactionInstruction({
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: burner, isSigner: true, isWritable: false },
       { pubkey: stablecoin, isSigner: false, isWritable: false },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: mint, isSigner: false, isWritable: false },
-      { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: redemptionRequest, isSigner: false, isWritable: true },
+      { pubkey: escrowTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: sourceTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: requester, isSigner: false, isWritable: true },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      ...hookAccounts,
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("thaw_account"),
+    data: anchorDiscriminator("reject_redemption"),
   });
 }
 
-export function buildTransferAuthorityIx(
+export function buildPauseIx(
   authority: PublicKey,
   stablecoin: PublicKey,
-  newAuthority: PublicKey
+  role: PublicKey
 ): TransactionInstruction {
   return new TransactionInstruction({
     keys: [
       { pubkey: authority, isSigner: true, isWritable: false },
       { pubkey: stablecoin, isSigner: false, isWritable: true },
-      { pubkey: newAuthority, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("transfer_authority"),
+    data: anchorDiscriminator("pause"),
   });
 }
 
-export function getTokenAccountAddress(mint: PublicKey, owner: PublicKey): PublicKey {
-  return getAssociatedTokenAddressSync(
-    mint,
-    owner,
-    false,
-    TOKEN_2022_PROGRAM_ID
-  );
+export function buildUnpauseIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("unpause"),
+  });
 }
 
-export async function createTokenAccount(
-  connection: Connection,
-  payer: Keypair,
-  mint: PublicKey,
-  owner: PublicKey
-): Promise<PublicKey> {
-  const {
-    createAssociatedTokenAccountInstruction,
-    getAssociatedTokenAddressSync,
-  } = await import("@solana/spl-token");
-  const ata = getAssociatedTokenAddressSync(
-    mint,
-    owner,
-    false,
-    TOKEN_2022_PROGRAM_ID
-  );
-  const ix = createAssociatedTokenAccountInstruction(
-    payer.publicKey,
-    ata,
-    owner,
-    mint,
-    TOKEN_2022_PROGRAM_ID
-  );
-  await sendAndConfirmTransaction(connection, new Transaction().add(ix), [payer]);
-  return ata;
-}
+/** `StablecoinState.pause_flags` bits. */
+export const PauseScope = {
+  Mint: 1 << 0,
+  Burn: 1 << 1,
+  Transfer: 1 << 2,
+  Freeze: 1 << 3,
+} as const;
 
-export function buildAddToBlacklistIx(
-  blacklister: PublicKey,
+function buildPauseScopesIx(
+  name: "pause_scoped" | "unpause_scoped",
+  authority: PublicKey,
   stablecoin: PublicKey,
   role: PublicKey,
-  blacklistEntry: PublicKey,
+  scopes: number
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: role, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator(name), Buffer.from([scopes])]),
+  });
+}
+
+export function buildPauseScopedIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  scopes: number
+): TransactionInstruction {
+  return buildPauseScopesIx("pause_scoped", authority, stablecoin, role, scopes);
+}
+
+export function buildUnpauseScopedIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  scopes: number
+): TransactionInstruction {
+  return buildPauseScopesIx("unpause_scoped", authority, stablecoin, role, scopes);
+}
+
+/** Direct hook call; only succeeds when `authority` is the mint's TransferHook authority. */
+export function buildUpdateExtraAccountMetaListIx(
+  authority: PublicKey,
+  payer: PublicKey,
+  extraAccountMetaList: PublicKey,
+  mint: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_HOOK_PROGRAM_ID,
+    data: anchorDiscriminator("update_extra_account_meta_list"),
+  });
+}
+
+export function buildInitializeTransferHookAccountsIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  mint: PublicKey,
+  extraAccountMetaList: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+      { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("initialize_transfer_hook_accounts"),
+  });
+}
+
+export function buildUpdateTransferHookAccountsIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  mint: PublicKey,
+  extraAccountMetaList: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
+      { pubkey: SSS_HOOK_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("update_transfer_hook_accounts"),
+  });
+}
+
+/** Guardian callers omit `role`; pausers pass their role PDA. */
+export function buildEmergencyPauseIx(
+  caller: PublicKey,
+  stablecoin: PublicKey,
+  durationSeconds: bigint,
+  role?: PublicKey
+): TransactionInstruction {
+  const durationBuf = Buffer.alloc(8);
+  durationBuf.writeBigInt64LE(durationSeconds);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: caller, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      readonlyOptionalAccount(role),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("emergency_pause"), durationBuf]),
+  });
+}
+
+export function buildExtendEmergencyPauseIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  durationSeconds: bigint
+): TransactionInstruction {
+  const durationBuf = Buffer.alloc(8);
+  durationBuf.writeBigInt64LE(durationSeconds);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("extend_emergency_pause"), durationBuf]),
+  });
+}
+
+export function buildFreezeAccountIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  targetTokenAccount: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("freeze_account"),
+  });
+}
+
+export function buildBatchFreezeAccountsIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  targetTokenAccounts: PublicKey[]
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      ...targetTokenAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("batch_freeze_accounts"),
+  });
+}
+
+export function buildThawAccountIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  targetTokenAccount: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: targetTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("thaw_account"),
+  });
+}
+
+export function buildTransferAuthorityIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  newAuthority: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: newAuthority, isSigner: true, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("transfer_authority"),
+  });
+}
+
+export function buildNominateAuthorityIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  newAuthority: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: newAuthority, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("nominate_authority"),
+  });
+}
+
+export function buildAcceptAuthorityIx(
+  newAuthority: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: newAuthority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("accept_authority"),
+  });
+}
+
+export function buildCancelAuthorityTransferIx(
+  authority: PublicKey,
+  stablecoin: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("cancel_authority_transfer"),
+  });
+}
+
+/** Borsh layout of the on-chain `AdminAction` enum (variant index + fields). */
+export type AdminAction =
+  | { kind: "updateRoles"; holder: PublicKey; roles: RoleFlags }
+  | { kind: "updateMinter"; minter: PublicKey; quota: bigint }
+  | { kind: "updateSupplyCap"; cap: bigint }
+  | { kind: "nominateAuthority"; newAuthority: PublicKey }
+  | { kind: "acceptAuthority" }
+  | { kind: "cancelAuthorityTransfer" }
+  | { kind: "updateMultisig"; members: PublicKey[]; threshold: number }
+  | { kind: "updateTimelock"; delay: bigint; guardian: PublicKey }
+  | { kind: "updateMinterAllowance"; minter: PublicKey; windowSeconds: bigint; windowLimit: bigint }
+  | { kind: "removeMinter"; minter: PublicKey }
+  | { kind: "revokeRole"; holder: PublicKey }
+  | { kind: "updateSupplyCapMode"; mode: SupplyCapMode }
+  | { kind: "updateTreasury"; treasury: PublicKey }
+  | { kind: "updateMetadata"; params: UpdateMetadataParams }
+  | { kind: "linkSuccessorMint"; successorMint: PublicKey }
+  | { kind: "initializeTransferHookAccounts" }
+  | { kind: "updateTransferHookAccounts" }
+  | { kind: "extendEmergencyPause"; duration: bigint }
+  | { kind: "cancelChange"; change: PublicKey };
+
+function u64Le(value: bigint): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(value);
+  return buf;
+}
+
+function serializePubkeyVec(keys: PublicKey[]): Buffer {
+  return Buffer.concat([
+    Buffer.from(new Uint32Array([keys.length]).buffer),
+    ...keys.map((k) => k.toBuffer()),
+  ]);
+}
+
+export function serializeAdminAction(action: AdminAction): Buffer {
+  switch (action.kind) {
+    case "updateRoles":
+      return Buffer.concat([Buffer.from([0]), action.holder.toBuffer(), serializeRoleFlags(action.roles)]);
+    case "updateMinter":
+      return Buffer.concat([Buffer.from([1]), action.minter.toBuffer(), u64Le(action.quota)]);
+    case "updateSupplyCap":
+      return Buffer.concat([Buffer.from([2]), u64Le(action.cap)]);
+    case "nominateAuthority":
+      return Buffer.concat([Buffer.from([3]), action.newAuthority.toBuffer()]);
+    case "acceptAuthority":
+      return Buffer.from([4]);
+    case "cancelAuthorityTransfer":
+      return Buffer.from([5]);
+    case "updateMultisig":
+      return Buffer.concat([Buffer.from([6]), serializePubkeyVec(action.members), Buffer.from([action.threshold])]);
+    case "updateTimelock": {
+      const delayBuf = Buffer.alloc(8);
+      delayBuf.writeBigInt64LE(action.delay);
+      return Buffer.concat([Buffer.from([7]), delayBuf, action.guardian.toBuffer()]);
+    }
+    case "updateMinterAllowance": {
+      const windowBuf = Buffer.alloc(8);
+      windowBuf.writeBigInt64LE(action.windowSeconds);
+      return Buffer.concat([Buffer.from([8]), action.minter.toBuffer(), windowBuf, u64Le(action.windowLimit)]);
+    }
+    case "removeMinter":
+      return Buffer.concat([Buffer.from([9]), action.minter.toBuffer()]);
+    case "revokeRole":
+      return Buffer.concat([Buffer.from([10]), action.holder.toBuffer()]);
+    case "updateSupplyCapMode":
+      return Buffer.from([11, action.mode]);
+    case "updateTreasury":
+      return Buffer.concat([Buffer.from([12]), action.treasury.toBuffer()]);
+    case "updateMetadata":
+      return Buffer.concat([Buffer.from([13]), encodeUpdateMetadataArgs(action.params)]);
+    case "linkSuccessorMint":
+      return Buffer.concat([Buffer.from([14]), action.successorMint.toBuffer()]);
+    case "initializeTransferHookAccounts":
+      return Buffer.from([15]);
+    case "updateTransferHookAccounts":
+      return Buffer.from([16]);
+    case "extendEmergencyPause": {
+      const durationBuf = Buffer.alloc(8);
+      durationBuf.writeBigInt64LE(action.duration);
+      return Buffer.concat([Buffer.from([17]), durationBuf]);
+    }
+    case "cancelChange":
+      return Buffer.concat([Buffer.from([18]), action.change.toBuffer()]);
+  }
+}
+
+export function buildCreateMultisigIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  multisig: PublicKey,
+  members: PublicKey[],
+  threshold: number
+): TransactionInstruction {
+  const data = Buffer.concat([
+    anchorDiscriminator("create_multisig"),
+    serializePubkeyVec(members),
+    Buffer.from([threshold]),
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: multisig, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildProposeAdminActionIx(
+  proposer: PublicKey,
+  stablecoin: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  action: AdminAction,
+  ttlSeconds: bigint
+): TransactionInstruction {
+  const ttlBuf = Buffer.alloc(8);
+  ttlBuf.writeBigInt64LE(ttlSeconds);
+  const data = Buffer.concat([
+    anchorDiscriminator("propose_admin_action"),
+    serializeAdminAction(action),
+    ttlBuf,
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: proposer, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: multisig, isSigner: false, isWritable: true },
+      { pubkey: proposal, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildApproveAdminActionIx(
+  member: PublicKey,
+  stablecoin: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: member, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: multisig, isSigner: false, isWritable: false },
+      { pubkey: proposal, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("approve_admin_action"),
+  });
+}
+
+function optionalAccount(key?: PublicKey) {
+  return {
+    pubkey: key ?? SSS_TOKEN_PROGRAM_ID,
+    isSigner: false,
+    isWritable: key !== undefined,
+  };
+}
+
+function readonlyOptionalAccount(key?: PublicKey) {
+  return { pubkey: key ?? SSS_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false };
+}
+
+/**
+ * Optional accounts (role / minterInfo / supplyCap / queuedChange / ...) default to the program ID,
+ * Anchor's "None". Pass queuedChange when a sensitive change executes against a timelocked stablecoin.
+ * `hookAccounts` adds the extra-account-metas PDA and hook program for the hook account actions.
+ */
+export function buildExecuteAdminActionIx(
+  executor: PublicKey,
+  stablecoin: PublicKey,
+  multisig: PublicKey,
+  proposal: PublicKey,
+  opts: {
+    role?: PublicKey;
+    minterInfo?: PublicKey;
+    supplyCap?: PublicKey;
+    queuedChange?: PublicKey;
+    mint?: PublicKey;
+    tokenProgram?: PublicKey;
+    successorStablecoin?: PublicKey;
+    hookAccounts?: boolean;
+    cancelledChange?: PublicKey;
+    rentPayer?: PublicKey;
+    treasuryAccount?: PublicKey;
+  } = {}
+): TransactionInstruction {
+  const extraAccountMetas =
+    opts.hookAccounts && opts.mint ? findExtraAccountMetasPDA(opts.mint, SSS_HOOK_PROGRAM_ID)[0] : undefined;
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: executor, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: multisig, isSigner: false, isWritable: true },
+      { pubkey: proposal, isSigner: false, isWritable: true },
+      optionalAccount(opts.role),
+      optionalAccount(opts.minterInfo),
+      optionalAccount(opts.supplyCap),
+      optionalAccount(opts.queuedChange),
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      optionalAccount(opts.mint),
+      readonlyOptionalAccount(opts.tokenProgram),
+      optionalAccount(opts.successorStablecoin),
+      optionalAccount(extraAccountMetas),
+      readonlyOptionalAccount(opts.hookAccounts ? SSS_HOOK_PROGRAM_ID : undefined),
+      optionalAccount(opts.cancelledChange),
+      optionalAccount(opts.rentPayer),
+      readonlyOptionalAccount(opts.treasuryAccount),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("execute_admin_action"),
+  });
+}
+
+export function buildUpdateTreasuryIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  treasury: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      // Clearing the treasury (PublicKey.default) needs no account
+      readonlyOptionalAccount(treasury.equals(PublicKey.default) ? undefined : treasury),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("update_treasury"), treasury.toBuffer()]),
+  });
+}
+
+export function buildUpdateMetadataIx(
+  authority: PublicKey,
+  mint: PublicKey,
+  params: UpdateMetadataParams
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: findStablecoinPDA(mint)[0], isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("update_metadata"), encodeUpdateMetadataArgs(params)]),
+  });
+}
+
+export function buildUpdateTimelockIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  delay: bigint,
+  guardian: PublicKey
+): TransactionInstruction {
+  const delayBuf = Buffer.alloc(8);
+  delayBuf.writeBigInt64LE(delay);
+  const data = Buffer.concat([
+    anchorDiscriminator("update_timelock"),
+    delayBuf,
+    guardian.toBuffer(),
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildQueueChangeIx(
+  authority: PublicKey,
+  stablecoin: PublicKey,
+  queuedChange: PublicKey,
+  action: AdminAction
+): TransactionInstruction {
+  const data = Buffer.concat([
+    anchorDiscriminator("queue_change"),
+    serializeAdminAction(action),
+  ]);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: queuedChange, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+/** Optional accounts (role / minterInfo / supplyCap) default to the program ID, Anchor's "None". */
+export function buildExecuteChangeIx(
+  executor: PublicKey,
+  stablecoin: PublicKey,
+  queuedChange: PublicKey,
+  rentPayer: PublicKey,
+  opts: {
+    role?: PublicKey;
+    minterInfo?: PublicKey;
+    supplyCap?: PublicKey;
+    mint?: PublicKey;
+    treasuryAccount?: PublicKey;
+  } = {}
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: executor, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: true },
+      { pubkey: queuedChange, isSigner: false, isWritable: true },
+      { pubkey: rentPayer, isSigner: false, isWritable: true },
+      optionalAccount(opts.role),
+      optionalAccount(opts.minterInfo),
+      optionalAccount(opts.supplyCap),
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      readonlyOptionalAccount(opts.mint),
+      readonlyOptionalAccount(opts.treasuryAccount),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("execute_change"),
+  });
+}
+
+export function buildCancelChangeIx(
+  canceller: PublicKey,
+  stablecoin: PublicKey,
+  queuedChange: PublicKey,
+  rentPayer: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: canceller, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: queuedChange, isSigner: false, isWritable: true },
+      { pubkey: rentPayer, isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("cancel_change"),
+  });
+}
+
+export function getTokenAccountAddress(mint: PublicKey, owner: PublicKey): PublicKey {
+  return getAssociatedTokenAddressSync(
+    mint,
+    owner,
+    false,
+    TOKEN_2022_PROGRAM_ID
+  );
+}
+
+export async function createTokenAccount(
+  connection: Connection,
+  payer: Keypair,
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const {
+    createAssociatedTokenAccountInstruction,
+    getAssociatedTokenAddressSync,
+  } = await import("@solana/spl-token");
+  const ata = getAssociatedTokenAddressSync(
+    mint,
+    owner,
+    false,
+    TOKEN_2022_PROGRAM_ID
+  );
+  const ix = createAssociatedTokenAccountInstruction(
+    payer.publicKey,
+    ata,
+    owner,
+    mint,
+    TOKEN_2022_PROGRAM_ID
+  );
+  await sendAndConfirmTransaction(connection, new Transaction().add(ix), [payer]);
+  return ata;
+}
+
+/** Borsh variant indices of the on-chain `BlacklistReason` enum. */
+export const BlacklistReason = {
+  Ofac: 0,
+  CourtOrder: 1,
+  Fraud: 2,
+  Internal: 3,
+} as const;
+
+export interface BlacklistEntryOptions {
+  /** Defaults to `BlacklistReason.Internal`. */
+  reasonCode?: number;
+  /** Unix seconds after which the entry stops blocking transfers; permanent when omitted. */
+  expiresAt?: bigint;
+}
+
+function serializeBlacklistEntryArgs(reason: string, options: BlacklistEntryOptions): Buffer {
+  const reasonBytes = Buffer.from(reason, "utf-8");
+  let expiresAt = Buffer.from([0]);
+  if (options.expiresAt !== undefined) {
+    expiresAt = Buffer.alloc(9);
+    expiresAt.writeUInt8(1, 0);
+    expiresAt.writeBigInt64LE(options.expiresAt, 1);
+  }
+  return Buffer.concat([
+    Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
+    reasonBytes,
+    Buffer.from([options.reasonCode ?? BlacklistReason.Internal]),
+    expiresAt,
+  ]);
+}
+
+export function buildAddToBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  blacklistEntry: PublicKey,
   address: PublicKey,
-  reason: string
+  reason: string,
+  options: BlacklistEntryOptions = {}
 ): TransactionInstruction {
-  const reasonBytes = Buffer.from(reason, "utf-8");
   const data = Buffer.concat([
     anchorDiscriminator("add_to_blacklist"),
-    Buffer.from(new Uint32Array([reasonBytes.length]).buffer),
-    reasonBytes,
+    serializeBlacklistEntryArgs(reason, options),
   ]);
 
   return new TransactionInstruction({
@@ -448,6 +1376,31 @@ export function buildAddToBlacklistIx(
   });
 }
 
+export function buildUpdateBlacklistEntryIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  blacklistEntry: PublicKey,
+  address: PublicKey,
+  reason: string,
+  options: BlacklistEntryOptions = {}
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: false },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: blacklistEntry, isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([
+      anchorDiscriminator("update_blacklist_entry"),
+      serializeBlacklistEntryArgs(reason, options),
+    ]),
+  });
+}
+
 export function buildRemoveFromBlacklistIx(
   blacklister: PublicKey,
   stablecoin: PublicKey,
@@ -468,11 +1421,257 @@ export function buildRemoveFromBlacklistIx(
   });
 }
 
+/** remaining_accounts for batch blacklist instructions: `[address, blacklist PDA]` pairs. */
+function blacklistBatchAccounts(stablecoin: PublicKey, addresses: PublicKey[]) {
+  return addresses.flatMap((address) => [
+    { pubkey: address, isSigner: false, isWritable: false },
+    { pubkey: findBlacklistPDA(stablecoin, address)[0], isSigner: false, isWritable: true },
+  ]);
+}
+
+export function buildBatchAddToBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  addresses: PublicKey[],
+  reason: string,
+  options: BlacklistEntryOptions = {}
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...blacklistBatchAccounts(stablecoin, addresses),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([
+      anchorDiscriminator("batch_add_to_blacklist"),
+      serializeBlacklistEntryArgs(reason, options),
+    ]),
+  });
+}
+
+export function buildBatchRemoveFromBlacklistIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  addresses: PublicKey[]
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      ...blacklistBatchAccounts(stablecoin, addresses),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("batch_remove_from_blacklist"),
+  });
+}
+
+export function buildSetVelocityLimitsIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  maxPerTransfer: bigint,
+  maxPerWindow: bigint
+): TransactionInstruction {
+  const data = Buffer.alloc(24);
+  anchorDiscriminator("set_velocity_limits").copy(data, 0);
+  data.writeBigUInt64LE(maxPerTransfer, 8);
+  data.writeBigUInt64LE(maxPerWindow, 16);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findVelocityConfigPDA(stablecoin)[0], isSigner: false, isWritable: true },
+      { pubkey: findExtraAccountMetasPDA(mint, SSS_HOOK_PROGRAM_ID)[0], isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildSetReportingThresholdIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  threshold: bigint
+): TransactionInstruction {
+  const data = Buffer.alloc(16);
+  anchorDiscriminator("set_reporting_threshold").copy(data, 0);
+  data.writeBigUInt64LE(threshold, 8);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findReportingConfigPDA(stablecoin)[0], isSigner: false, isWritable: true },
+      { pubkey: findExtraAccountMetasPDA(mint, SSS_HOOK_PROGRAM_ID)[0], isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data,
+  });
+}
+
+export function buildAddVelocityExemptionIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  wallet: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findVelocityExemptionPDA(stablecoin, wallet)[0], isSigner: false, isWritable: true },
+      { pubkey: wallet, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("add_velocity_exemption"),
+  });
+}
+
+export function buildRemoveVelocityExemptionIx(
+  blacklister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  wallet: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: blacklister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: findVelocityExemptionPDA(stablecoin, wallet)[0], isSigner: false, isWritable: true },
+      { pubkey: wallet, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("remove_velocity_exemption"),
+  });
+}
+
+/** Hook instruction: create `owner`'s velocity usage PDA (permissionless). */
+export function buildInitializeVelocityUsageIx(
+  payer: PublicKey,
+  mint: PublicKey,
+  owner: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: findVelocityUsagePDA(mint, owner)[0], isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_HOOK_PROGRAM_ID,
+    data: anchorDiscriminator("initialize_velocity_usage"),
+  });
+}
+
+/**
+ * The hook's Execute instruction, called directly instead of through Token-2022, built from the
+ * accounts Token-2022 would pass for `transferIx` (a resolved transfer_checked instruction).
+ */
+export function buildDirectExecuteIx(transferIx: TransactionInstruction, amount: bigint): TransactionInstruction {
+  const keys = transferIx.keys.map((k) => ({ ...k, isSigner: false }));
+  // transfer_checked: source, mint, destination, authority, extra metas..., hook program, validation
+  const validation = keys[keys.length - 1];
+  const amountBuf = Buffer.alloc(8);
+  amountBuf.writeBigUInt64LE(amount);
+  return new TransactionInstruction({
+    keys: [keys[0], keys[1], keys[2], keys[3], validation, ...keys.slice(4, keys.length - 2)],
+    programId: SSS_HOOK_PROGRAM_ID,
+    // sha256("spl-transfer-hook-interface:execute")[..8]
+    data: Buffer.concat([Buffer.from([105, 37, 101, 197, 75, 251, 102, 26]), amountBuf]),
+  });
+}
+
+export function buildAddToAllowlistIx(
+  allowlister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  allowlistEntry: PublicKey,
+  address: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: allowlister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: allowlistEntry, isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("add_to_allowlist"),
+  });
+}
+
+export function buildRemoveFromAllowlistIx(
+  allowlister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  allowlistEntry: PublicKey,
+  address: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: allowlister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: allowlistEntry, isSigner: false, isWritable: true },
+      { pubkey: address, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("remove_from_allowlist"),
+  });
+}
+
+/** Allowlists `holder` and thaws `tokenAccount` (which must be the holder's account for `mint`). */
+export function buildApproveHolderIx(
+  allowlister: PublicKey,
+  stablecoin: PublicKey,
+  role: PublicKey,
+  mint: PublicKey,
+  holder: PublicKey,
+  tokenAccount: PublicKey,
+  allowlistEntry: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: allowlister, isSigner: true, isWritable: true },
+      { pubkey: stablecoin, isSigner: false, isWritable: false },
+      { pubkey: role, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: holder, isSigner: false, isWritable: false },
+      { pubkey: tokenAccount, isSigner: false, isWritable: true },
+      { pubkey: allowlistEntry, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("approve_holder"),
+  });
+}
+
+/** Direct hook call; only succeeds when `transferHookAuthority` is the mint's hook authority. */
 export function buildInitializeExtraAccountMetaListIx(
-  authority: PublicKey,
+  payer: PublicKey,
   extraAccountMetaList: PublicKey,
   mint: PublicKey,
-  sssTokenProgramId: PublicKey
+  sssTokenProgramId: PublicKey,
+  transferHookAuthority: PublicKey
 ): TransactionInstruction {
   const data = Buffer.concat([
     anchorDiscriminator("initialize_extra_account_meta_list"),
@@ -480,10 +1679,11 @@ export function buildInitializeExtraAccountMetaListIx(
   ]);
   return new TransactionInstruction({
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: payer, isSigner: true, isWritable: true },
       { pubkey: extraAccountMetaList, isSigner: false, isWritable: true },
       { pubkey: mint, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: transferHookAuthority, isSigner: true, isWritable: false },
     ],
     programId: SSS_HOOK_PROGRAM_ID,
     data,
@@ -501,11 +1701,23 @@ export function buildSeizeIx(
   extraAccountMetas: PublicKey,
   sssTokenProgram: PublicKey,
   sourceBlacklist: PublicKey,
-  destBlacklist: PublicKey
+  destBlacklist: PublicKey,
+  seizure: { caseReferenceHash: Buffer; amount?: bigint },
+  authorityBlacklist?: PublicKey,
+  allowlists?: { source: PublicKey; dest: PublicKey },
+  velocity?: VelocityAccounts,
+  reportingConfig?: PublicKey
 ): TransactionInstruction {
+  const [seizureRecord] = findSeizureRecordPDA(stablecoin, sourceTokenAccount, seizure.caseReferenceHash);
+  let amountArg = Buffer.from([0]);
+  if (seizure.amount !== undefined) {
+    amountArg = Buffer.alloc(9);
+    amountArg.writeUInt8(1, 0);
+    amountArg.writeBigUInt64LE(seizure.amount, 1);
+  }
   return new TransactionInstruction({
     keys: [
-      { pubkey: seizer, isSigner: true, isWritable: false },
+      { pubkey: seizer, isSigner: true, isWritable: true },
       { pubkey: stablecoin, isSigner: false, isWritable: false },
       { pubkey: role, isSigner: false, isWritable: false },
       { pubkey: mint, isSigner: false, isWritable: false },
@@ -517,9 +1729,98 @@ export function buildSeizeIx(
       { pubkey: sourceBlacklist, isSigner: false, isWritable: false },
       { pubkey: destBlacklist, isSigner: false, isWritable: false },
       { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: seizureRecord, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...(authorityBlacklist ? [{ pubkey: authorityBlacklist, isSigner: false, isWritable: false }] : []),
+      ...(allowlists
+        ? [
+            { pubkey: allowlists.source, isSigner: false, isWritable: false },
+            { pubkey: allowlists.dest, isSigner: false, isWritable: false },
+          ]
+        : []),
+      ...(velocity
+        ? [
+            { pubkey: velocity.config, isSigner: false, isWritable: false },
+            { pubkey: velocity.exemption, isSigner: false, isWritable: false },
+            { pubkey: velocity.usage, isSigner: false, isWritable: true },
+          ]
+        : []),
+      ...(reportingConfig ? [{ pubkey: reportingConfig, isSigner: false, isWritable: false }] : []),
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: Buffer.concat([anchorDiscriminator("seize"), amountArg, seizure.caseReferenceHash]),
+  });
+}
+
+export function buildMigrateStablecoinStateIx(payer: PublicKey, mint: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: findStablecoinPDA(mint)[0], isSigner: false, isWritable: true },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_stablecoin_state"),
+  });
+}
+
+export function buildMigrateAccountIx(payer: PublicKey, account: PublicKey): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: account, isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("migrate_account"),
+  });
+}
+
+export function buildLinkSuccessorMintIx(
+  authority: PublicKey,
+  legacyMint: PublicKey,
+  successorMint: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: findStablecoinPDA(legacyMint)[0], isSigner: false, isWritable: true },
+      { pubkey: findStablecoinPDA(successorMint)[0], isSigner: false, isWritable: true },
+    ],
+    programId: SSS_TOKEN_PROGRAM_ID,
+    data: anchorDiscriminator("link_successor_mint"),
+  });
+}
+
+export function buildSwapLegacyIx(
+  holder: PublicKey,
+  legacyMint: PublicKey,
+  legacyTokenAccount: PublicKey,
+  successorMint: PublicKey,
+  successorTokenAccount: PublicKey,
+  amount: bigint,
+  recipientOwner: PublicKey = holder
+): TransactionInstruction {
+  const [successorStablecoin] = findStablecoinPDA(successorMint);
+  const data = Buffer.alloc(16);
+  anchorDiscriminator("swap_legacy").copy(data, 0);
+  data.writeBigUInt64LE(amount, 8);
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: holder, isSigner: true, isWritable: false },
+      { pubkey: findStablecoinPDA(legacyMint)[0], isSigner: false, isWritable: true },
+      { pubkey: legacyMint, isSigner: false, isWritable: true },
+      { pubkey: legacyTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: successorStablecoin, isSigner: false, isWritable: true },
+      { pubkey: successorMint, isSigner: false, isWritable: true },
+      { pubkey: successorTokenAccount, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_2022_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: findBlacklistPDA(successorStablecoin, recipientOwner)[0], isSigner: false, isWritable: false },
+      { pubkey: findAllowlistPDA(successorStablecoin, recipientOwner)[0], isSigner: false, isWritable: false },
     ],
     programId: SSS_TOKEN_PROGRAM_ID,
-    data: anchorDiscriminator("seize"),
+    data,
   });
 }
 
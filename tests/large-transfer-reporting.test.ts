@@ -0,0 +1,177 @@
+import { createTransferCheckedWithTransferHookInstruction } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
+import { parseLargeTransferEvents } from "@stbr/sss-token";
+import { expect } from "chai";
+import {
+  buildDirectExecuteIx,
+  buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
+  buildMintTokensIx,
+  buildSetReportingThresholdIx,
+  buildUpdateMinterIx,
+  createTokenAccount,
+  findExtraAccountMetasPDA,
+  findMinterPDA,
+  findReportingConfigPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+describe("Large Transfer Reporting", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let stablecoinPDA: PublicKey;
+  let authorityRole: PublicKey;
+  let alice: Keypair;
+  let bob: Keypair;
+  let aliceATA: PublicKey;
+  let bobATA: PublicKey;
+
+  function transferIx(amount: bigint) {
+    return createTransferCheckedWithTransferHookInstruction(
+      connection,
+      aliceATA,
+      mintKeypair.publicKey,
+      bobATA,
+      alice.publicKey,
+      amount,
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  /** Transfer and return the LargeTransferObserved events from the transaction logs. */
+  async function transfer(amount: bigint) {
+    const ix = await transferIx(amount);
+    const sig = await sendAndConfirmTransaction(connection, new Transaction().add(ix), [alice], {
+      commitment: "confirmed",
+    });
+    const tx = await connection.getTransaction(sig, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+    return parseLargeTransferEvents(tx?.meta?.logMessages ?? []);
+  }
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    alice = Keypair.generate();
+    bob = Keypair.generate();
+    await fundKeypairs(provider, [alice, bob]);
+
+    [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Reported USD",
+          symbol: "RUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize SSS-2"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildInitializeTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas))
+        .add(buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, 1_000_000n)),
+      [authority]
+    );
+
+    aliceATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, alice.publicKey);
+    bobATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, bob.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, aliceATA, 100_000n)
+      ),
+      [authority]
+    );
+  });
+
+  it("emits nothing before a threshold is configured", async () => {
+    expect(await transfer(50_000n)).to.be.empty;
+  });
+
+  it("rejects set_reporting_threshold from a non-blacklister", async () => {
+    const [aliceRole] = findRolePDA(stablecoinPDA, alice.publicKey);
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSetReportingThresholdIx(alice.publicKey, stablecoinPDA, aliceRole, mintKeypair.publicKey, 1n)
+        ),
+        [alice]
+      );
+      expect.fail("Non-blacklister should not set the reporting threshold");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AccountNotInitialized|Unauthorized|custom program error|0x/i);
+    }
+  });
+
+  it("emits LargeTransferObserved only for transfers above the threshold", async () => {
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildSetReportingThresholdIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, 10_000n)
+      ),
+      [authority],
+      "Set reporting threshold"
+    );
+    const info = await connection.getAccountInfo(findReportingConfigPDA(stablecoinPDA)[0]);
+    // ReportingConfig: discriminator(8) + stablecoin(32) + threshold(8)
+    expect(info!.data.readBigUInt64LE(40)).to.equal(10_000n);
+
+    expect(await transfer(10_000n)).to.be.empty;
+
+    const events = await transfer(10_001n);
+    expect(events).to.have.length(1);
+    expect(events[0].mint.toBase58()).to.equal(mintKeypair.publicKey.toBase58());
+    expect(events[0].sourceOwner.toBase58()).to.equal(alice.publicKey.toBase58());
+    expect(events[0].destinationOwner.toBase58()).to.equal(bob.publicKey.toBase58());
+    expect(events[0].amount).to.equal(10_001n);
+    expect(events[0].slot > 0n).to.equal(true);
+  });
+
+  it("rejects the hook called directly, so events cannot be forged", async () => {
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildDirectExecuteIx(await transferIx(50_000n), 50_000n)),
+        [authority]
+      );
+      expect.fail("Direct Execute should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NotTransferring|custom program error|0x/i);
+    }
+  });
+
+  it("threshold 0 turns reporting off", async () => {
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildSetReportingThresholdIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, 0n)
+      ),
+      [authority]
+    );
+    expect(await transfer(20_000n)).to.be.empty;
+  });
+});
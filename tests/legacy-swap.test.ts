@@ -0,0 +1,335 @@
+import { getAccount } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildAddToBlacklistIx,
+  buildInitializeIx,
+  buildLinkSuccessorMintIx,
+  buildMintTokensIx,
+  buildSwapLegacyIx,
+  buildUpdateMinterIx,
+  buildUpdateRolesIx,
+  createTokenAccount,
+  findBlacklistPDA,
+  findMinterPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+/** Link fields of a StablecoinState; they follow the strings, so the offset depends on them. */
+function readLink(data: Buffer) {
+  // Header (22) + authority + mint, then name, symbol, uri (u32 length prefix each)
+  let offset = 22 + 32 + 32;
+  for (let i = 0; i < 3; i++) offset += 4 + data.readUInt32LE(offset);
+  // decimals, default_account_frozen
+  offset += 2;
+  const totalMinted = data.readBigUInt64LE(offset);
+  const totalBurned = data.readBigUInt64LE(offset + 8);
+  // total_minted, total_burned, pending_authority, timelock_delay, guardian, queued_change_count,
+  // minter_epoch, treasury, bump
+  offset += 8 + 8 + 32 + 8 + 32 + 8 + 8 + 32 + 1;
+  return {
+    totalMinted,
+    totalBurned,
+    successorMint: new PublicKey(data.subarray(offset, offset + 32)),
+    legacyMint: new PublicKey(data.subarray(offset + 32, offset + 64)),
+    legacyOutstanding: data.readBigUInt64LE(offset + 64),
+  };
+}
+
+describe("Legacy Swap (SSS-1 to SSS-2)", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let legacyMint: Keypair;
+  let successorMint: Keypair;
+  let legacyStablecoin: PublicKey;
+  let successorStablecoin: PublicKey;
+  let legacyAuthorityRole: PublicKey;
+  let legacyMinterInfo: PublicKey;
+  let alice: Keypair;
+  let aliceLegacyATA: PublicKey;
+  let aliceSuccessorATA: PublicKey;
+
+  async function readState(stablecoin: PublicKey) {
+    const info = await connection.getAccountInfo(stablecoin);
+    return readLink(info!.data);
+  }
+
+  async function expectFailure(ix: TransactionInstruction, signer: Keypair, pattern: RegExp) {
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), [signer]);
+      expect.fail("Instruction should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(pattern);
+    }
+  }
+
+  const swapIx = (amount: bigint) =>
+    buildSwapLegacyIx(
+      alice.publicKey,
+      legacyMint.publicKey,
+      aliceLegacyATA,
+      successorMint.publicKey,
+      aliceSuccessorATA,
+      amount
+    );
+
+  before(async () => {
+    legacyMint = Keypair.generate();
+    successorMint = Keypair.generate();
+    alice = Keypair.generate();
+    await fundKeypairs(provider, [alice]);
+
+    [legacyStablecoin] = findStablecoinPDA(legacyMint.publicKey);
+    [successorStablecoin] = findStablecoinPDA(successorMint.publicKey);
+    [legacyAuthorityRole] = findRolePDA(legacyStablecoin, authority.publicKey);
+    [legacyMinterInfo] = findMinterPDA(legacyStablecoin, authority.publicKey);
+    const [successorAuthorityRole] = findRolePDA(successorStablecoin, authority.publicKey);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, legacyStablecoin, legacyMint.publicKey, legacyAuthorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Legacy USD",
+          symbol: "LUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, legacyMint],
+      "Initialize SSS-1"
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, successorStablecoin, successorMint.publicKey, successorAuthorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Compliant USD",
+          symbol: "CUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, successorMint],
+      "Initialize SSS-2"
+    );
+
+    aliceLegacyATA = await createTokenAccount(connection, authority, legacyMint.publicKey, alice.publicKey);
+    aliceSuccessorATA = await createTokenAccount(connection, authority, successorMint.publicKey, alice.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildUpdateMinterIx(authority.publicKey, legacyStablecoin, legacyMinterInfo, authority.publicKey, 1_000_000n))
+        .add(
+          buildMintTokensIx(authority.publicKey, legacyStablecoin, legacyAuthorityRole, legacyMinterInfo, legacyMint.publicKey, aliceLegacyATA, 10_000n)
+        ),
+      [authority]
+    );
+  });
+
+  it("rejects swap_legacy before the mints are linked", async () => {
+    await expectFailure(swapIx(1_000n), alice, /MintNotLinked|custom program error|0x/i);
+  });
+
+  it("rejects linking an SSS-2 stablecoin as the legacy side", async () => {
+    await expectFailure(
+      buildLinkSuccessorMintIx(authority.publicKey, successorMint.publicKey, legacyMint.publicKey),
+      authority,
+      /InvalidMigrationTarget|custom program error|0x/i
+    );
+  });
+
+  it("rejects link_successor_mint from a non-authority", async () => {
+    await expectFailure(
+      buildLinkSuccessorMintIx(alice.publicKey, legacyMint.publicKey, successorMint.publicKey),
+      alice,
+      /Unauthorized|custom program error|0x/i
+    );
+  });
+
+  it("links the mints and carries the legacy supply over", async () => {
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildLinkSuccessorMintIx(authority.publicKey, legacyMint.publicKey, successorMint.publicKey)),
+      [authority],
+      "Link successor mint"
+    );
+
+    const legacy = await readState(legacyStablecoin);
+    expect(legacy.successorMint.toBase58()).to.equal(successorMint.publicKey.toBase58());
+    const successor = await readState(successorStablecoin);
+    expect(successor.legacyMint.toBase58()).to.equal(legacyMint.publicKey.toBase58());
+    expect(successor.legacyOutstanding).to.equal(10_000n);
+
+    await expectFailure(
+      buildLinkSuccessorMintIx(authority.publicKey, legacyMint.publicKey, successorMint.publicKey),
+      authority,
+      /MigrationAlreadyLinked|custom program error|0x/i
+    );
+  });
+
+  it("legacy minting is paused once linked", async () => {
+    await expectFailure(
+      buildMintTokensIx(authority.publicKey, legacyStablecoin, legacyAuthorityRole, legacyMinterInfo, legacyMint.publicKey, aliceLegacyATA, 1n),
+      authority,
+      /Paused|custom program error|0x/i
+    );
+  });
+
+  it("swap_legacy burns legacy tokens and mints the successor 1:1", async () => {
+    await sendAndConfirmAndLog(connection, new Transaction().add(swapIx(4_000n)), [alice], "Swap legacy");
+
+    const legacyAccount = await getAccount(connection, aliceLegacyATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const successorAccount = await getAccount(connection, aliceSuccessorATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(legacyAccount.amount).to.equal(6_000n);
+    expect(successorAccount.amount).to.equal(4_000n);
+
+    const legacy = await readState(legacyStablecoin);
+    expect(legacy.totalBurned).to.equal(4_000n);
+    const successor = await readState(successorStablecoin);
+    expect(successor.totalMinted).to.equal(4_000n);
+    expect(successor.legacyOutstanding).to.equal(6_000n);
+  });
+
+  it("rejects a swap to a recipient blacklisted on the successor", async () => {
+    const blacklister = Keypair.generate();
+    const bob = Keypair.generate();
+    await fundKeypairs(provider, [blacklister]);
+    const [blacklisterRole] = findRolePDA(successorStablecoin, blacklister.publicKey);
+    const [bobBlacklist] = findBlacklistPDA(successorStablecoin, bob.publicKey);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, successorStablecoin, blacklisterRole, blacklister.publicKey, {
+          isMinter: false,
+          isBurner: false,
+          isPauser: false,
+          isFreezer: false,
+          isBlacklister: true,
+          isSeizer: false,
+        })
+      ),
+      [authority],
+      "Successor blacklister role"
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildAddToBlacklistIx(blacklister.publicKey, successorStablecoin, blacklisterRole, bobBlacklist, bob.publicKey, "Sanctioned")
+      ),
+      [blacklister],
+      "Blacklist bob on the successor"
+    );
+    const bobSuccessorATA = await createTokenAccount(connection, authority, successorMint.publicKey, bob.publicKey);
+
+    await expectFailure(
+      buildSwapLegacyIx(
+        alice.publicKey,
+        legacyMint.publicKey,
+        aliceLegacyATA,
+        successorMint.publicKey,
+        bobSuccessorATA,
+        1_000n,
+        bob.publicKey
+      ),
+      alice,
+      /Blacklisted|custom program error|0x/i
+    );
+    // Blacklist PDA of a different owner
+    await expectFailure(
+      buildSwapLegacyIx(
+        alice.publicKey,
+        legacyMint.publicKey,
+        aliceLegacyATA,
+        successorMint.publicKey,
+        bobSuccessorATA,
+        1_000n
+      ),
+      alice,
+      /Unauthorized|custom program error|0x/i
+    );
+    const legacyAccount = await getAccount(connection, aliceLegacyATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(legacyAccount.amount).to.equal(6_000n);
+  });
+
+  it("rejects a swap to a recipient not allowlisted on an allowlist-mode successor", async () => {
+    const kycLegacyMint = Keypair.generate();
+    const kycMint = Keypair.generate();
+    const [kycLegacyStablecoin] = findStablecoinPDA(kycLegacyMint.publicKey);
+    const [kycStablecoin] = findStablecoinPDA(kycMint.publicKey);
+    const [kycLegacyRole] = findRolePDA(kycLegacyStablecoin, authority.publicKey);
+    const [kycLegacyMinter] = findMinterPDA(kycLegacyStablecoin, authority.publicKey);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction()
+        .add(
+          buildInitializeIx(authority.publicKey, kycLegacyStablecoin, kycLegacyMint.publicKey, kycLegacyRole, SSS_HOOK_PROGRAM_ID, {
+            name: "Legacy KYC USD",
+            symbol: "LKUSD",
+            uri: "",
+            decimals: 6,
+            enablePermanentDelegate: false,
+            enableTransferHook: false,
+            defaultAccountFrozen: false,
+          })
+        )
+        .add(
+          buildInitializeIx(
+            authority.publicKey,
+            kycStablecoin,
+            kycMint.publicKey,
+            findRolePDA(kycStablecoin, authority.publicKey)[0],
+            SSS_HOOK_PROGRAM_ID,
+            {
+              name: "KYC USD",
+              symbol: "KUSD",
+              uri: "",
+              decimals: 6,
+              enablePermanentDelegate: true,
+              enableTransferHook: true,
+              defaultAccountFrozen: false,
+              enableAllowlist: true,
+            }
+          )
+        ),
+      [authority, kycLegacyMint, kycMint],
+      "Initialize allowlist-mode pair"
+    );
+    const aliceKycLegacyATA = await createTokenAccount(connection, authority, kycLegacyMint.publicKey, alice.publicKey);
+    const aliceKycATA = await createTokenAccount(connection, authority, kycMint.publicKey, alice.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildUpdateMinterIx(authority.publicKey, kycLegacyStablecoin, kycLegacyMinter, authority.publicKey, 1_000n))
+        .add(
+          buildMintTokensIx(authority.publicKey, kycLegacyStablecoin, kycLegacyRole, kycLegacyMinter, kycLegacyMint.publicKey, aliceKycLegacyATA, 1_000n)
+        )
+        .add(buildLinkSuccessorMintIx(authority.publicKey, kycLegacyMint.publicKey, kycMint.publicKey)),
+      [authority]
+    );
+
+    await expectFailure(
+      buildSwapLegacyIx(alice.publicKey, kycLegacyMint.publicKey, aliceKycLegacyATA, kycMint.publicKey, aliceKycATA, 500n),
+      alice,
+      /NotAllowlisted|custom program error|0x/i
+    );
+  });
+
+  it("rejects zero and over-balance swaps", async () => {
+    await expectFailure(swapIx(0n), alice, /ZeroAmount|custom program error|0x/i);
+    await expectFailure(swapIx(6_001n), alice, /insufficient|custom program error|0x/i);
+  });
+});
@@ -0,0 +1,277 @@
+import { getTokenMetadata } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildApproveAdminActionIx,
+  buildCreateMultisigIx,
+  buildEmergencyPauseIx,
+  buildExecuteAdminActionIx,
+  buildExtendEmergencyPauseIx,
+  buildInitializeIx,
+  buildNominateAuthorityIx,
+  buildProposeAdminActionIx,
+  buildUpdateSupplyCapIx,
+  findMultisigPDA,
+  findProposalPDA,
+  findQueuedChangePDA,
+  findRolePDA,
+  findStablecoinPDA,
+  findSupplyCapPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+describe("Multisig Authority", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let members: Keypair[];
+  let nextProposalId = 0n;
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    members = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    await fundKeypairs(provider, members);
+  });
+
+  async function propose(proposer: Keypair, action: Parameters<typeof buildProposeAdminActionIx>[4]) {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [multisigPDA] = findMultisigPDA(stablecoinPDA);
+    const [proposalPDA] = findProposalPDA(multisigPDA, nextProposalId++);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildProposeAdminActionIx(proposer.publicKey, stablecoinPDA, multisigPDA, proposalPDA, action, 3600n)
+      ),
+      [proposer]
+    );
+    return proposalPDA;
+  }
+
+  it("creates stablecoin and a 2-of-3 multisig", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [multisigPDA] = findMultisigPDA(stablecoinPDA);
+
+    const ix = buildInitializeIx(
+      authority.publicKey,
+      stablecoinPDA,
+      mintKeypair.publicKey,
+      authorityRole,
+      SSS_HOOK_PROGRAM_ID,
+      { name: "Multisig USD", symbol: "MUSD", uri: "", decimals: 6, enablePermanentDelegate: false, enableTransferHook: false, defaultAccountFrozen: false }
+    );
+    await sendAndConfirmAndLog(connection, new Transaction().add(ix), [authority, mintKeypair], "Initialize");
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildCreateMultisigIx(
+          authority.publicKey,
+          stablecoinPDA,
+          multisigPDA,
+          members.map((m) => m.publicKey),
+          2
+        )
+      ),
+      [authority],
+      "Create multisig"
+    );
+  });
+
+  it("multisig accepts authority after reaching threshold", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [multisigPDA] = findMultisigPDA(stablecoinPDA);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildNominateAuthorityIx(authority.publicKey, stablecoinPDA, multisigPDA)),
+      [authority]
+    );
+
+    const proposalPDA = await propose(members[0], { kind: "acceptAuthority" });
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildExecuteAdminActionIx(members[0].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+        [members[0]]
+      );
+      expect.fail("Execution below threshold should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/ThresholdNotMet|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildApproveAdminActionIx(members[1].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+      [members[1]]
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildExecuteAdminActionIx(members[1].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+      [members[1]],
+      "Execute accept authority"
+    );
+
+    // Replay of an executed proposal is rejected.
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildExecuteAdminActionIx(members[2].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+        [members[2]]
+      );
+      expect.fail("Executed proposal should not execute twice");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/ProposalAlreadyExecuted|Simulation failed|custom program error|0x/i);
+    }
+  });
+
+  it("single key can no longer perform admin actions", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [supplyCapPDA] = findSupplyCapPDA(stablecoinPDA);
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateSupplyCapIx(authority.publicKey, stablecoinPDA, supplyCapPDA, 1_000_000n)),
+        [authority]
+      );
+      expect.fail("Former authority should not update supply cap");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/constraint|Simulation failed|custom program error|0x|2003/i);
+    }
+  });
+
+  it("multisig updates supply cap through a proposal", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [multisigPDA] = findMultisigPDA(stablecoinPDA);
+    const [supplyCapPDA] = findSupplyCapPDA(stablecoinPDA);
+
+    const proposalPDA = await propose(members[2], { kind: "updateSupplyCap", cap: 1_000_000n });
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildApproveAdminActionIx(members[0].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+      [members[0]]
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildExecuteAdminActionIx(members[0].publicKey, stablecoinPDA, multisigPDA, proposalPDA, {
+          supplyCap: supplyCapPDA,
+        })
+      ),
+      [members[0]],
+      "Execute supply cap update"
+    );
+
+    const info = await connection.getAccountInfo(supplyCapPDA);
+    expect(info).to.not.be.null;
+    expect(info!.data.readBigUInt64LE(8)).to.equal(1_000_000n);
+  });
+
+  async function approveAndExecute(
+    proposalPDA: PublicKey,
+    opts: Parameters<typeof buildExecuteAdminActionIx>[4] = {}
+  ) {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [multisigPDA] = findMultisigPDA(stablecoinPDA);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildApproveAdminActionIx(members[1].publicKey, stablecoinPDA, multisigPDA, proposalPDA)),
+      [members[1]]
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildExecuteAdminActionIx(members[1].publicKey, stablecoinPDA, multisigPDA, proposalPDA, opts)),
+      [members[1]]
+    );
+  }
+
+  it("multisig updates metadata through a proposal", async () => {
+    const proposalPDA = await propose(members[0], {
+      kind: "updateMetadata",
+      params: { name: "Multisig Dollar", fields: { issuer: "Example Trust Co." } },
+    });
+    await approveAndExecute(proposalPDA, { mint: mintKeypair.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID });
+
+    const metadata = await getTokenMetadata(connection, mintKeypair.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(metadata!.name).to.equal("Multisig Dollar");
+    expect(metadata!.additionalMetadata).to.deep.equal([["issuer", "Example Trust Co."]]);
+  });
+
+  it("multisig extends an emergency pause through a proposal", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    // The former authority kept its pauser role
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildEmergencyPauseIx(authority.publicKey, stablecoinPDA, 3600n, authorityRole)),
+      [authority]
+    );
+    const pausedUntil = async () => (await connection.getAccountInfo(stablecoinPDA))!.data.readBigInt64LE(14);
+    const before = await pausedUntil();
+
+    // The direct path is closed to the former authority
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildExtendEmergencyPauseIx(authority.publicKey, stablecoinPDA, 600n)),
+        [authority]
+      );
+      expect.fail("Former authority should not extend the pause");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/constraint|Simulation failed|custom program error|0x|2003/i);
+    }
+
+    const proposalPDA = await propose(members[2], { kind: "extendEmergencyPause", duration: 600n });
+    await approveAndExecute(proposalPDA);
+    expect(await pausedUntil()).to.equal(before + 600n);
+  });
+
+  it("multisig cancels a change it queued through a proposal", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [supplyCapPDA] = findSupplyCapPDA(stablecoinPDA);
+    const [queuedChange] = findQueuedChangePDA(stablecoinPDA, 0n);
+
+    const enableTimelock = await propose(members[0], {
+      kind: "updateTimelock",
+      delay: 3600n,
+      guardian: PublicKey.default,
+    });
+    await approveAndExecute(enableTimelock);
+
+    // Raising the cap is sensitive, so executing the proposal only queues it
+    const raiseCap = await propose(members[0], { kind: "updateSupplyCap", cap: 2_000_000n });
+    await approveAndExecute(raiseCap, { supplyCap: supplyCapPDA, queuedChange });
+    expect(await connection.getAccountInfo(queuedChange)).to.not.be.null;
+
+    const cancel = await propose(members[2], { kind: "cancelChange", change: queuedChange });
+    await approveAndExecute(cancel, { cancelledChange: queuedChange, rentPayer: members[1].publicKey });
+
+    expect(await connection.getAccountInfo(queuedChange)).to.be.null;
+    const info = await connection.getAccountInfo(supplyCapPDA);
+    expect(info!.data.readBigUInt64LE(8)).to.equal(1_000_000n);
+  });
+
+  it("non-members cannot propose", async () => {
+    const outsider = Keypair.generate();
+    await fundKeypairs(provider, [outsider]);
+    try {
+      await propose(outsider, { kind: "updateSupplyCap", cap: 0n });
+      expect.fail("Non-member proposal should fail");
+    } catch (err: unknown) {
+      nextProposalId--;
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NotMultisigMember|Simulation failed|custom program error|0x/i);
+    }
+  });
+});
@@ -0,0 +1,208 @@
+import { createTransferCheckedInstruction } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import * as crypto from "crypto";
+import {
+  buildFulfillRedemptionIx,
+  buildInitializeIx,
+  buildMintTokensIx,
+  buildRejectRedemptionIx,
+  buildRequestRedemptionIx,
+  buildUpdateMinterIx,
+  buildUpdateRolesIx,
+  createTokenAccount,
+  findMinterPDA,
+  findRedemptionEscrowPDA,
+  findRedemptionRequestPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+const referenceHash = (reference: string) => crypto.createHash("sha256").update(reference).digest();
+
+describe("Redemption Queue", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let holder: Keypair;
+  let holderATA: PublicKey;
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    holder = Keypair.generate();
+    await fundKeypairs(provider, [holder]);
+  });
+
+  async function requestRedemption(reference: string, amount: bigint) {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [request] = findRedemptionRequestPDA(stablecoinPDA, holder.publicKey, referenceHash(reference));
+    const [escrow] = findRedemptionEscrowPDA(request);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildRequestRedemptionIx(holder.publicKey, stablecoinPDA, mintKeypair.publicKey, holderATA, request, escrow, amount, referenceHash(reference))
+      ),
+      [holder],
+      `Request redemption ${reference}`
+    );
+    return { request, escrow };
+  }
+
+  it("creates stablecoin and funds the holder", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Redeem USD",
+          symbol: "RUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(
+          buildUpdateRolesIx(authority.publicKey, stablecoinPDA, authorityRole, authority.publicKey, { isMinter: true, isBurner: true, isPauser: true, isFreezer: true, isBlacklister: false, isSeizer: false })
+        )
+        .add(buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, 1_000_000n)),
+      [authority]
+    );
+
+    holderATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, holder.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, holderATA, 1_000n)),
+      [authority]
+    );
+  });
+
+  it("escrows tokens on request and burns them on fulfillment", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const { request, escrow } = await requestRedemption("WIRE-001", 400n);
+
+    expect((await connection.getTokenAccountBalance(holderATA)).value.amount).to.equal("600");
+    expect((await connection.getTokenAccountBalance(escrow)).value.amount).to.equal("400");
+
+    const supplyBefore = BigInt((await connection.getTokenSupply(mintKeypair.publicKey)).value.amount);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildFulfillRedemptionIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, request, escrow, holder.publicKey)
+      ),
+      [authority],
+      "Fulfill redemption"
+    );
+    const supplyAfter = BigInt((await connection.getTokenSupply(mintKeypair.publicKey)).value.amount);
+    expect(supplyBefore - supplyAfter).to.equal(400n);
+    expect(await connection.getAccountInfo(request)).to.be.null;
+    expect(await connection.getAccountInfo(escrow)).to.be.null;
+  });
+
+  it("refunds the holder on rejection", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const { request, escrow } = await requestRedemption("WIRE-002", 250n);
+    expect((await connection.getTokenAccountBalance(holderATA)).value.amount).to.equal("350");
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildRejectRedemptionIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, request, escrow, holderATA, holder.publicKey)
+      ),
+      [authority],
+      "Reject redemption"
+    );
+    expect((await connection.getTokenAccountBalance(holderATA)).value.amount).to.equal("600");
+    expect(await connection.getAccountInfo(request)).to.be.null;
+  });
+
+  it("fulfills and rejects requests whose escrow received extra tokens", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const griefer = Keypair.generate();
+    await fundKeypairs(provider, [griefer]);
+    const grieferATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, griefer.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, grieferATA, 10n)),
+      [authority]
+    );
+    const sendDust = (escrow: PublicKey) =>
+      sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          createTransferCheckedInstruction(grieferATA, mintKeypair.publicKey, escrow, griefer.publicKey, 1n, 6, [], TOKEN_2022_PROGRAM_ID)
+        ),
+        [griefer]
+      );
+
+    const fulfilled = await requestRedemption("WIRE-004", 100n);
+    await sendDust(fulfilled.escrow);
+    const supplyBefore = BigInt((await connection.getTokenSupply(mintKeypair.publicKey)).value.amount);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildFulfillRedemptionIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, fulfilled.request, fulfilled.escrow, holder.publicKey)
+      ),
+      [authority],
+      "Fulfill redemption with dust"
+    );
+    const supplyAfter = BigInt((await connection.getTokenSupply(mintKeypair.publicKey)).value.amount);
+    expect(supplyBefore - supplyAfter).to.equal(101n);
+    expect(await connection.getAccountInfo(fulfilled.escrow)).to.be.null;
+
+    const rejected = await requestRedemption("WIRE-005", 100n);
+    await sendDust(rejected.escrow);
+    const holderBefore = BigInt((await connection.getTokenAccountBalance(holderATA)).value.amount);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildRejectRedemptionIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, rejected.request, rejected.escrow, holderATA, holder.publicKey)
+      ),
+      [authority],
+      "Reject redemption with dust"
+    );
+    const holderAfter = BigInt((await connection.getTokenAccountBalance(holderATA)).value.amount);
+    expect(holderAfter - holderBefore).to.equal(101n);
+    expect(await connection.getAccountInfo(rejected.escrow)).to.be.null;
+  });
+
+  it("rejects fulfillment by a non-burner", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [holderRole] = findRolePDA(stablecoinPDA, holder.publicKey);
+    const { request, escrow } = await requestRedemption("WIRE-003", 100n);
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildFulfillRedemptionIx(holder.publicKey, stablecoinPDA, holderRole, mintKeypair.publicKey, request, escrow, holder.publicKey)
+        ),
+        [holder]
+      );
+      expect.fail("Non-burner should not fulfill a redemption");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Unauthorized|AccountNotInitialized|Simulation failed|custom program error|0x/i);
+    }
+  });
+});
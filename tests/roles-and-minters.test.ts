@@ -5,6 +5,9 @@ import {
   buildBurnTokensIx,
   buildInitializeIx,
   buildMintTokensIx,
+  buildRemoveMinterIx,
+  buildRevokeRoleAccountIx,
+  buildUpdateMinterAllowanceIx,
   buildUpdateMinterIx,
   buildUpdateRolesIx,
   createTokenAccount,
@@ -237,6 +240,112 @@ describe("Roles and Minters", () => {
     }
   });
 
+  it("periodic allowance resets each window and keeps the lifetime counter", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [quotaTestMinterRole] = findRolePDA(stablecoinPDA, quotaTestMinter.publicKey);
+    const [quotaTestMinterInfo] = findMinterPDA(stablecoinPDA, quotaTestMinter.publicKey);
+    const recipientATA = getTokenAccountAddress(mintKeypair.publicKey, recipientKeypair.publicKey);
+    const mintIx = (amount: bigint) =>
+      buildMintTokensIx(
+        quotaTestMinter.publicKey,
+        stablecoinPDA,
+        quotaTestMinterRole,
+        quotaTestMinterInfo,
+        mintKeypair.publicKey,
+        recipientATA,
+        amount
+      );
+
+    // Lifetime quota is 100; switch to 500 per 3-second window.
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildUpdateMinterAllowanceIx(authority.publicKey, stablecoinPDA, quotaTestMinterInfo, 3n, 500n)),
+      [authority],
+      "Minter allowance"
+    );
+    await sendAndConfirmTransaction(connection, new Transaction().add(mintIx(500n)), [quotaTestMinter]);
+
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(mintIx(1n)), [quotaTestMinter]);
+      expect.fail("Should reject mint beyond the window allowance");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AllowanceExceeded|Simulation failed|custom program error|0x/i);
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+    await sendAndConfirmTransaction(connection, new Transaction().add(mintIx(500n)), [quotaTestMinter]);
+
+    const info = await connection.getAccountInfo(quotaTestMinterInfo);
+    expect(info!.data.readBigUInt64LE(8 + 32 + 32 + 8)).to.equal(1000n);
+  });
+
+  it("removed minter cannot mint and is re-added with fresh counters in a new epoch", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [quotaTestMinterRole] = findRolePDA(stablecoinPDA, quotaTestMinter.publicKey);
+    const [quotaTestMinterInfo] = findMinterPDA(stablecoinPDA, quotaTestMinter.publicKey);
+    const recipientATA = getTokenAccountAddress(mintKeypair.publicKey, recipientKeypair.publicKey);
+    const mintedOffset = 8 + 32 + 32 + 8;
+    const epochOffset = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8;
+    const mintIx = () =>
+      buildMintTokensIx(
+        quotaTestMinter.publicKey,
+        stablecoinPDA,
+        quotaTestMinterRole,
+        quotaTestMinterInfo,
+        mintKeypair.publicKey,
+        recipientATA,
+        BigInt(10)
+      );
+
+    const before = await connection.getAccountInfo(quotaTestMinterInfo);
+    const previousEpoch = before!.data.readBigUInt64LE(epochOffset);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildRemoveMinterIx(authority.publicKey, stablecoinPDA, quotaTestMinterInfo)),
+      [authority],
+      "Remove minter"
+    );
+    expect(await connection.getAccountInfo(quotaTestMinterInfo)).to.be.null;
+
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(mintIx()), [quotaTestMinter]);
+      expect.fail("Removed minter should not mint");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AccountNotInitialized|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, quotaTestMinterInfo, quotaTestMinter.publicKey, BigInt(100))
+      ),
+      [authority]
+    );
+    const after = await connection.getAccountInfo(quotaTestMinterInfo);
+    expect(after!.data.readBigUInt64LE(mintedOffset)).to.equal(0n);
+    expect(after!.data.readBigUInt64LE(epochOffset) > previousEpoch).to.equal(true);
+    await sendAndConfirmTransaction(connection, new Transaction().add(mintIx()), [quotaTestMinter]);
+
+    // Revoking the role account closes it; minting then fails on the missing role.
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildRevokeRoleAccountIx(authority.publicKey, stablecoinPDA, quotaTestMinterRole)),
+      [authority],
+      "Revoke role"
+    );
+    expect(await connection.getAccountInfo(quotaTestMinterRole)).to.be.null;
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(mintIx()), [quotaTestMinter]);
+      expect.fail("Revoked minter should not mint");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AccountNotInitialized|Simulation failed|custom program error|0x/i);
+    }
+  });
+
   it("rejects add_to_blacklist from non-blacklister", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
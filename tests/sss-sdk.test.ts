@@ -19,6 +19,7 @@ import {
   buildUpdateMinterIx,
   buildUpdateRolesIx,
   createTokenAccount,
+  findExtraAccountMetasPDA,
 } from "./helpers";
 
 describe("SDK integration (SSS-1 flow)", () => {
@@ -259,6 +260,13 @@ describe("SDK integration (SSS-1 flow)", () => {
       authority
     );
     expect(stable.isSSS2()).to.be.true;
+
+    // Rewriting a current hook account list through the migration path keeps it in place
+    const [extraMetas] = findExtraAccountMetasPDA(stable.mintAddress, SSS_HOOK_PROGRAM_ID);
+    const before = await connection.getAccountInfo(extraMetas);
+    await stable.migrateHookAccounts(authority.publicKey);
+    const after = await connection.getAccountInfo(extraMetas);
+    expect(after!.data.equals(before!.data)).to.be.true;
   });
 
   it("create SSS-1 and assert !isSSS2()", async () => {
@@ -326,7 +326,7 @@ describe("Simple Stablecoin Lifecycle", () => {
         stablecoinPDA,
         newAuthority.publicKey
       );
-      await sendAndConfirmAndLog(connection, new Transaction().add(ix), [authority], "Transfer authority");
+      await sendAndConfirmAndLog(connection, new Transaction().add(ix), [authority, newAuthority], "Transfer authority");
       console.log("  Authority transferred to new key");
 
       const ix2 = buildTransferAuthorityIx(
@@ -334,7 +334,7 @@ describe("Simple Stablecoin Lifecycle", () => {
         stablecoinPDA,
         authority.publicKey
       );
-      await sendAndConfirmAndLog(connection, new Transaction().add(ix2), [newAuthority], "Transfer authority back");
+      await sendAndConfirmAndLog(connection, new Transaction().add(ix2), [newAuthority, authority], "Transfer authority back");
       console.log("  Authority transferred back");
     });
   });
@@ -1114,7 +1114,7 @@ describe("Simple Stablecoin Lifecycle", () => {
       await sendAndConfirmTransaction(
         connection,
         new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthority.publicKey)),
-        [authority]
+        [authority, newAuthority]
       );
       try {
         await sendAndConfirmTransaction(
@@ -1130,7 +1130,7 @@ describe("Simple Stablecoin Lifecycle", () => {
       await sendAndConfirmTransaction(
         connection,
         new Transaction().add(buildTransferAuthorityIx(newAuthority.publicKey, stablecoinPDA, authority.publicKey)),
-        [newAuthority]
+        [newAuthority, authority]
       );
     });
 
@@ -1154,7 +1154,7 @@ describe("Simple Stablecoin Lifecycle", () => {
       await sendAndConfirmTransaction(
         connection,
         new Transaction().add(buildTransferAuthorityIx(authority.publicKey, stablecoinPDA, newAuthority.publicKey)),
-        [authority]
+        [authority, newAuthority]
       );
       await sendAndConfirmTransaction(
         connection,
@@ -1183,7 +1183,7 @@ describe("Simple Stablecoin Lifecycle", () => {
       await sendAndConfirmTransaction(
         connection,
         new Transaction().add(buildTransferAuthorityIx(newAuthority.publicKey, stablecoinPDA, authority.publicKey)),
-        [newAuthority]
+        [newAuthority, authority]
       );
     });
   });
@@ -1,28 +1,48 @@
 import * as anchor from "@coral-xyz/anchor";
-import { createTransferCheckedWithTransferHookInstruction } from "@solana/spl-token";
+import {
+  createApproveCheckedInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAccount,
+} from "@solana/spl-token";
 import {
   Keypair,
   sendAndConfirmTransaction,
   SystemProgram,
-  Transaction
+  Transaction,
+  TransactionInstruction
 } from "@solana/web3.js";
 import { expect } from "chai";
 import {
+  anchorDiscriminator,
+  BlacklistReason,
   buildAddToBlacklistIx,
   buildInitializeExtraAccountMetaListIx,
+  buildFreezeAccountIx,
   buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
   buildMintTokensIx,
+  buildPauseIx,
   buildRemoveFromBlacklistIx,
   buildSeizeIx,
   buildThawAccountIx,
+  buildUnpauseIx,
+  buildUpdateBlacklistEntryIx,
+  buildUpdateExtraAccountMetaListIx,
   buildUpdateMinterIx,
   buildUpdateRolesIx,
+  buildUpdateTransferHookAccountsIx,
+  buildUpdateTreasuryIx,
+  caseReferenceHash,
   createTokenAccount,
+  findAllowlistPDA,
   findBlacklistPDA,
   findExtraAccountMetasPDA,
   findMinterPDA,
+  findReportingConfigPDA,
   findRolePDA,
+  findSeizureRecordPDA,
   findStablecoinPDA,
+  findVelocityAccounts,
   getTokenAccountAddress,
   sendAndConfirmAndLog,
   SSS_HOOK_PROGRAM_ID,
@@ -202,22 +222,146 @@ describe("Stablecoin with Transfer Hook", () => {
       console.log("  Roles and minter quota set");
     });
 
+    it("rejects extra-account-metas initialization not signed by the transfer hook authority", async () => {
+      const [extraAccountMetasPDA] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            buildInitializeExtraAccountMetaListIx(
+              badActorKeypair.publicKey,
+              extraAccountMetasPDA,
+              mintKeypair.publicKey,
+              SSS_TOKEN_PROGRAM_ID,
+              badActorKeypair.publicKey
+            )
+          ),
+          [badActorKeypair]
+        );
+        expect.fail("Front-run initialization should be rejected");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/Unauthorized|Simulation failed|custom program error|0x/i);
+      }
+      expect(await connection.getAccountInfo(extraAccountMetasPDA)).to.be.null;
+    });
+
+    it("rejects extra-account-metas pointing at a foreign sss-token program", async () => {
+      const [extraAccountMetasPDA] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            buildInitializeExtraAccountMetaListIx(
+              badActorKeypair.publicKey,
+              extraAccountMetasPDA,
+              mintKeypair.publicKey,
+              Keypair.generate().publicKey,
+              badActorKeypair.publicKey
+            )
+          ),
+          [badActorKeypair]
+        );
+        expect.fail("Malicious sss-token program id should be rejected");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/InvalidSssTokenProgram|Simulation failed|custom program error|0x/i);
+      }
+      expect(await connection.getAccountInfo(extraAccountMetasPDA)).to.be.null;
+    });
+
     it("initializes transfer hook extra-account-metas for mint", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
       const [extraAccountMetasPDA] = findExtraAccountMetasPDA(
         mintKeypair.publicKey,
         SSS_HOOK_PROGRAM_ID
       );
-      const initExtraIx = buildInitializeExtraAccountMetaListIx(
+      const initExtraIx = buildInitializeTransferHookAccountsIx(
         authority.publicKey,
-        extraAccountMetasPDA,
+        stablecoinPDA,
         mintKeypair.publicKey,
-        SSS_TOKEN_PROGRAM_ID
+        extraAccountMetasPDA
       );
       await sendAndConfirmAndLog(connection, new Transaction().add(initExtraIx), [authority], "Extra-account-metas");
       console.log("  Extra-account-metas PDA:", extraAccountMetasPDA.toBase58());
     });
   });
 
+  describe("Hook Accounts Update", () => {
+    it("authority rewrites the extra-account-metas through the stablecoin PDA", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(
+          buildUpdateTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas)
+        ),
+        [authority],
+        "Update transfer hook accounts"
+      );
+      const info = await connection.getAccountInfo(extraAccountMetas);
+      expect(info!.owner.equals(SSS_HOOK_PROGRAM_ID)).to.be.true;
+    });
+
+    it("rejects a direct hook update not signed by the transfer hook authority", async () => {
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            buildUpdateExtraAccountMetaListIx(authority.publicKey, authority.publicKey, extraAccountMetas, mintKeypair.publicKey)
+          ),
+          [authority]
+        );
+        expect.fail("Only the stablecoin PDA may update the extra-account-metas");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/Unauthorized|Simulation failed|custom program error|0x/i);
+      }
+    });
+
+    it("rejects a hook accounts rewrite from a non-authority", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      const mallory = Keypair.generate();
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: mallory.publicKey, lamports: 100_000_000 })
+        ),
+        [payer]
+      );
+      const before = await connection.getAccountInfo(extraAccountMetas);
+
+      const attempts = [
+        buildUpdateTransferHookAccountsIx(mallory.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas),
+        buildUpdateExtraAccountMetaListIx(mallory.publicKey, mallory.publicKey, extraAccountMetas, mintKeypair.publicKey),
+        // The former permissionless migrate_extra_account_meta_list no longer exists
+        new TransactionInstruction({
+          keys: [
+            { pubkey: mallory.publicKey, isSigner: true, isWritable: true },
+            { pubkey: extraAccountMetas, isSigner: false, isWritable: true },
+            { pubkey: mintKeypair.publicKey, isSigner: false, isWritable: false },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          ],
+          programId: SSS_HOOK_PROGRAM_ID,
+          data: anchorDiscriminator("migrate_extra_account_meta_list"),
+        }),
+      ];
+      for (const ix of attempts) {
+        try {
+          await sendAndConfirmTransaction(connection, new Transaction().add(ix), [mallory]);
+          expect.fail("Only the stablecoin authority may rewrite the extra-account-metas");
+        } catch (err: unknown) {
+          const msg = err instanceof Error ? err.message : String(err);
+          expect(msg).to.match(/Unauthorized|ConstraintRaw|InvalidInstruction|Simulation failed|custom program error|0x/i);
+        }
+      }
+      const after = await connection.getAccountInfo(extraAccountMetas);
+      expect(after!.data.equals(before!.data)).to.be.true;
+    });
+  });
+
   describe("User Lifecycle", () => {
     it("thaws user ATA (KYC), mints tokens, balance matches", async () => {
       const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
@@ -537,6 +681,121 @@ describe("Stablecoin with Transfer Hook", () => {
       expect(Number(bobBalance.value.amount)).to.equal(10_000);
     });
 
+    it("checks the source owner and the delegate, not just the signing authority", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+      const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+      const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+      const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      const ownerKeypair = Keypair.generate();
+      const delegateKeypair = Keypair.generate();
+      const destKeypair = Keypair.generate();
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: ownerKeypair.publicKey, lamports: 100_000_000 }),
+          SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: delegateKeypair.publicKey, lamports: 100_000_000 })
+        ),
+        [payer]
+      );
+
+      // Rewriting an already-current list is a no-op for transfers
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(
+          buildUpdateTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas)
+        ),
+        [authority],
+        "Rewrite extra account metas"
+      );
+
+      const ownerATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, ownerKeypair.publicKey);
+      const destATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, destKeypair.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction()
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, ownerATA))
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, destATA)),
+        [authority]
+      );
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, ownerATA, BigInt(1_000))
+        ),
+        [minterKeypair]
+      );
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          createApproveCheckedInstruction(
+            ownerATA,
+            mintKeypair.publicKey,
+            delegateKeypair.publicKey,
+            ownerKeypair.publicKey,
+            BigInt(1_000),
+            6,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [ownerKeypair]
+      );
+
+      const delegateTransfer = () =>
+        createTransferCheckedWithTransferHookInstruction(
+          connection,
+          ownerATA,
+          mintKeypair.publicKey,
+          destATA,
+          delegateKeypair.publicKey,
+          BigInt(1),
+          6,
+          [],
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID
+        );
+      const expectBlocked = async (reason: string) => {
+        try {
+          await sendAndConfirmTransaction(connection, new Transaction().add(await delegateTransfer()), [delegateKeypair]);
+          expect.fail(reason);
+        } catch (err: unknown) {
+          const msg = err instanceof Error ? err.message : String(err);
+          expect(msg).to.match(/Blacklisted|Simulation failed|custom program error|0x/i);
+        }
+      };
+
+      await sendAndConfirmTransaction(connection, new Transaction().add(await delegateTransfer()), [delegateKeypair]);
+
+      const [delegateEntry] = findBlacklistPDA(stablecoinPDA, delegateKeypair.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildAddToBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, delegateEntry, delegateKeypair.publicKey, "Blacklisted delegate")
+        ),
+        [blacklisterKeypair]
+      );
+      await expectBlocked("Blacklisted delegate should not move a clean owner's funds");
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildRemoveFromBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, delegateEntry, delegateKeypair.publicKey)
+        ),
+        [blacklisterKeypair]
+      );
+
+      const [ownerEntry] = findBlacklistPDA(stablecoinPDA, ownerKeypair.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildAddToBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, ownerEntry, ownerKeypair.publicKey, "Blacklisted owner")
+        ),
+        [blacklisterKeypair]
+      );
+      await expectBlocked("Blacklisted owner should not move funds through a clean delegate");
+    });
+
     it("after blacklist add, transfer fails with hook error", async () => {
       const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
       const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
@@ -603,6 +862,104 @@ describe("Stablecoin with Transfer Hook", () => {
         expect(msg).to.match(/Blacklisted|Simulation failed|custom program error|0x/i);
       }
     });
+
+    it("expired blacklist entries stop blocking; update_blacklist_entry reactivates them", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+      const [blRole] = findRolePDA(stablecoinPDA, blacklisterKeypair.publicKey);
+      const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+      const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+      const sender = Keypair.generate();
+      const recipient = Keypair.generate();
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: sender.publicKey, lamports: 100_000_000 })
+        ),
+        [payer]
+      );
+      const senderATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, sender.publicKey);
+      const recipientATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, recipient.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction()
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, senderATA))
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, recipientATA))
+          .add(buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, senderATA, BigInt(1_000))),
+        [authority, minterKeypair]
+      );
+      const sendOne = async () =>
+        sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            await createTransferCheckedWithTransferHookInstruction(
+              connection,
+              senderATA,
+              mintKeypair.publicKey,
+              recipientATA,
+              sender.publicKey,
+              BigInt(1),
+              6,
+              [],
+              "confirmed",
+              TOKEN_2022_PROGRAM_ID
+            )
+          ),
+          [sender]
+        );
+      const chainTime = async () => BigInt((await connection.getBlockTime(await connection.getSlot()))!);
+
+      const [entry] = findBlacklistPDA(stablecoinPDA, recipient.publicKey);
+      const expiresAt = (await chainTime()) + 3n;
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(
+          buildAddToBlacklistIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, entry, recipient.publicKey, "Temporary hold", {
+            reasonCode: BlacklistReason.Internal,
+            expiresAt,
+          })
+        ),
+        [blacklisterKeypair],
+        "Blacklist add with expiry"
+      );
+      try {
+        await sendOne();
+        expect.fail("Transfer to an active blacklist entry should fail");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/Blacklisted|Simulation failed|custom program error|0x/i);
+      }
+
+      while ((await chainTime()) < expiresAt) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+      await sendOne();
+      expect((await connection.getTokenAccountBalance(recipientATA)).value.amount).to.equal("1");
+
+      // BlacklistEntry: discriminator(8) + stablecoin(32) + address(32) + expires_at(8) + reason_code(1)
+      const farFuture = (await chainTime()) + 86_400n;
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(
+          buildUpdateBlacklistEntryIx(blacklisterKeypair.publicKey, stablecoinPDA, blRole, entry, recipient.publicKey, "Fraud investigation", {
+            reasonCode: BlacklistReason.Fraud,
+            expiresAt: farFuture,
+          })
+        ),
+        [blacklisterKeypair],
+        "Update blacklist entry"
+      );
+      const data = (await connection.getAccountInfo(entry))!.data;
+      expect(data.readBigInt64LE(72)).to.equal(farFuture);
+      expect(data[80]).to.equal(BlacklistReason.Fraud);
+      try {
+        await sendOne();
+        expect.fail("Transfer after reactivation should fail");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/Blacklisted|Simulation failed|custom program error|0x/i);
+      }
+    });
   });
 
   describe("Seize", () => {
@@ -641,12 +998,18 @@ describe("Stablecoin with Transfer Hook", () => {
         treasuryATA
       );
       await sendAndConfirmAndLog(connection, new Transaction().add(thawTreasuryIx), [authority], "Thaw treasury");
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(buildUpdateTreasuryIx(authority.publicKey, stablecoinPDA, treasuryATA)),
+        [authority],
+        "Register treasury"
+      );
 
       const [extraAccountMetas] = findExtraAccountMetasPDA(
         mintKeypair.publicKey,
         SSS_HOOK_PROGRAM_ID
       );
-      const [sourceBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
       const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
 
       const seizeIx = buildSeizeIx(
@@ -659,8 +1022,16 @@ describe("Stablecoin with Transfer Hook", () => {
         SSS_HOOK_PROGRAM_ID,
         extraAccountMetas,
         SSS_TOKEN_PROGRAM_ID,
-        sourceBlacklist,
-        destBlacklist
+        findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+        destBlacklist,
+        { caseReferenceHash: caseReferenceHash("CASE-HOOK-001") },
+        authorityBlacklist,
+        {
+          source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+          dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+        },
+        findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+        findReportingConfigPDA(stablecoinPDA)[0]
       );
       await sendAndConfirmAndLog(connection, new Transaction().add(seizeIx), [seizerKeypair], "Seize");
 
@@ -672,12 +1043,65 @@ describe("Stablecoin with Transfer Hook", () => {
       console.log("  Seized to treasury:", treasuryBalance.value.amount);
     });
 
+    it("rejects seizing to a destination other than the registered treasury", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+      const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+      const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+
+      const badActorATA = getTokenAccountAddress(mintKeypair.publicKey, badActorKeypair.publicKey);
+      const seizerATA = await createTokenAccount(connection, payer, mintKeypair.publicKey, seizerKeypair.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, badActorATA, BigInt(1_000))
+        ),
+        [minterKeypair]
+      );
+
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(
+            buildSeizeIx(
+              seizerKeypair.publicKey,
+              stablecoinPDA,
+              szRole,
+              mintKeypair.publicKey,
+              badActorATA,
+              seizerATA,
+              SSS_HOOK_PROGRAM_ID,
+              extraAccountMetas,
+              SSS_TOKEN_PROGRAM_ID,
+              findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+              findBlacklistPDA(stablecoinPDA, seizerKeypair.publicKey)[0],
+              { caseReferenceHash: caseReferenceHash("CASE-HOOK-REDIRECT") },
+              authorityBlacklist,
+              {
+                source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+                dest: findAllowlistPDA(stablecoinPDA, seizerKeypair.publicKey)[0],
+              },
+              findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+              findReportingConfigPDA(stablecoinPDA)[0]
+            )
+          ),
+          [seizerKeypair]
+        );
+        expect.fail("Seize to an unregistered destination should be rejected");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/InvalidTreasury|Simulation failed|custom program error|0x/i);
+      }
+    });
+
     it("seize with zero balance (no-op or error)", async () => {
       const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
       const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
       const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
       const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
-      const [sourceBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
       const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
 
       const zeroBalanceKeypair = Keypair.generate();
@@ -734,8 +1158,16 @@ describe("Stablecoin with Transfer Hook", () => {
         SSS_HOOK_PROGRAM_ID,
         extraAccountMetas,
         SSS_TOKEN_PROGRAM_ID,
-        sourceBlacklist,
-        destBlacklist
+        findBlacklistPDA(stablecoinPDA, zeroBalanceKeypair.publicKey)[0],
+        destBlacklist,
+        { caseReferenceHash: caseReferenceHash("CASE-HOOK-ZERO") },
+        authorityBlacklist,
+        {
+          source: findAllowlistPDA(stablecoinPDA, zeroBalanceKeypair.publicKey)[0],
+          dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+        },
+        findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, zeroBalanceKeypair.publicKey),
+        findReportingConfigPDA(stablecoinPDA)[0]
       );
       try {
         await sendAndConfirmTransaction(
@@ -756,7 +1188,7 @@ describe("Stablecoin with Transfer Hook", () => {
       const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
       const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
       const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
-      const [sourceBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
       const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
 
       const badActorATA = getTokenAccountAddress(mintKeypair.publicKey, badActorKeypair.publicKey);
@@ -783,11 +1215,180 @@ describe("Stablecoin with Transfer Hook", () => {
         SSS_HOOK_PROGRAM_ID,
         extraAccountMetas,
         SSS_TOKEN_PROGRAM_ID,
-        sourceBlacklist,
-        destBlacklist
+        findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+        destBlacklist,
+        { caseReferenceHash: caseReferenceHash("CASE-HOOK-002") },
+        authorityBlacklist,
+        {
+          source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+          dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+        },
+        findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+        findReportingConfigPDA(stablecoinPDA)[0]
       );
       await sendAndConfirmTransaction(connection, new Transaction().add(seizeIx), [seizerKeypair]);
     });
+
+    it("seizes from a blacklisted account while the token is paused", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+      const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+      const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
+
+      const badActorATA = getTokenAccountAddress(mintKeypair.publicKey, badActorKeypair.publicKey);
+      const treasuryATA = getTokenAccountAddress(mintKeypair.publicKey, authority.publicKey);
+
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction()
+          .add(buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, badActorATA, BigInt(50_000)))
+          .add(
+            buildUpdateRolesIx(authority.publicKey, stablecoinPDA, szRole, seizerKeypair.publicKey, {
+              isMinter: false,
+              isBurner: false,
+              isPauser: true,
+              isFreezer: false,
+              isBlacklister: false,
+              isSeizer: true,
+            })
+          ),
+        [minterKeypair, authority]
+      );
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(buildPauseIx(seizerKeypair.publicKey, stablecoinPDA, szRole)),
+        [seizerKeypair],
+        "Pause"
+      );
+
+      try {
+        const treasuryBefore = BigInt((await connection.getTokenAccountBalance(treasuryATA)).value.amount);
+        await sendAndConfirmAndLog(
+          connection,
+          new Transaction().add(
+            buildSeizeIx(
+              seizerKeypair.publicKey,
+              stablecoinPDA,
+              szRole,
+              mintKeypair.publicKey,
+              badActorATA,
+              treasuryATA,
+              SSS_HOOK_PROGRAM_ID,
+              extraAccountMetas,
+              SSS_TOKEN_PROGRAM_ID,
+              findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+              destBlacklist,
+              { caseReferenceHash: caseReferenceHash("CASE-HOOK-PAUSED") },
+              authorityBlacklist,
+              {
+                source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+                dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+              },
+              findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+              findReportingConfigPDA(stablecoinPDA)[0]
+            )
+          ),
+          [seizerKeypair],
+          "Seize while paused"
+        );
+        const treasuryAfter = BigInt((await connection.getTokenAccountBalance(treasuryATA)).value.amount);
+        expect(treasuryAfter - treasuryBefore).to.equal(50_000n);
+        expect((await connection.getTokenAccountBalance(badActorATA)).value.amount).to.equal("0");
+      } finally {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(buildUnpauseIx(seizerKeypair.publicKey, stablecoinPDA, szRole)),
+          [seizerKeypair]
+        );
+      }
+    });
+
+    it("seizes only from blacklisted or frozen accounts, partially and with a case record", async () => {
+      const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+      const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+      const [szRole] = findRolePDA(stablecoinPDA, seizerKeypair.publicKey);
+      const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+      const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+      const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
+      const treasuryATA = getTokenAccountAddress(mintKeypair.publicKey, authority.publicKey);
+
+      const holder = Keypair.generate();
+      const holderATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, holder.publicKey);
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction()
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, holderATA))
+          .add(buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, holderATA, BigInt(10_000))),
+        [authority, minterKeypair]
+      );
+
+      const seizeFromHolder = (reference: string, amount?: bigint) =>
+        buildSeizeIx(
+          seizerKeypair.publicKey,
+          stablecoinPDA,
+          szRole,
+          mintKeypair.publicKey,
+          holderATA,
+          treasuryATA,
+          SSS_HOOK_PROGRAM_ID,
+          extraAccountMetas,
+          SSS_TOKEN_PROGRAM_ID,
+          findBlacklistPDA(stablecoinPDA, holder.publicKey)[0],
+          destBlacklist,
+          { caseReferenceHash: caseReferenceHash(reference), amount },
+          authorityBlacklist,
+          {
+            source: findAllowlistPDA(stablecoinPDA, holder.publicKey)[0],
+            dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+          },
+          findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, holder.publicKey),
+          findReportingConfigPDA(stablecoinPDA)[0]
+        );
+
+      try {
+        await sendAndConfirmTransaction(connection, new Transaction().add(seizeFromHolder("CASE-CLEAN")), [seizerKeypair]);
+        expect.fail("Seize from a clean, unfrozen account should be rejected");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/SeizureNotPermitted|Simulation failed|custom program error|0x/i);
+      }
+
+      // Frozen (not blacklisted): partial seizure thaws, transfers and refreezes. The seizer
+      // holds the pauser role from the previous test, which also allows freezing.
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildFreezeAccountIx(seizerKeypair.publicKey, stablecoinPDA, szRole, mintKeypair.publicKey, holderATA)),
+        [seizerKeypair]
+      );
+      await sendAndConfirmAndLog(
+        connection,
+        new Transaction().add(seizeFromHolder("CASE-FROZEN-001", 4_000n)),
+        [seizerKeypair],
+        "Partial seize from frozen account"
+      );
+      const holderAccount = await getAccount(connection, holderATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(holderAccount.amount).to.equal(6_000n);
+      expect(holderAccount.isFrozen).to.be.true;
+      const [record] = findSeizureRecordPDA(stablecoinPDA, holderATA, caseReferenceHash("CASE-FROZEN-001"));
+      expect(await connection.getAccountInfo(record)).to.not.be.null;
+
+      try {
+        await sendAndConfirmTransaction(
+          connection,
+          new Transaction().add(seizeFromHolder("CASE-FROZEN-002", 1_000_000n)),
+          [seizerKeypair]
+        );
+        expect.fail("Seizing more than the balance should be rejected");
+      } catch (err: unknown) {
+        const msg = err instanceof Error ? err.message : String(err);
+        expect(msg).to.match(/SeizureAmountExceedsBalance|Simulation failed|custom program error|0x/i);
+      }
+    });
   });
 
   describe("Error Cases", () => {
@@ -798,7 +1399,7 @@ describe("Stablecoin with Transfer Hook", () => {
         mintKeypair.publicKey,
         SSS_HOOK_PROGRAM_ID
       );
-      const [sourceBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+      const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
       const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
 
       const badActorATA = getTokenAccountAddress(mintKeypair.publicKey, badActorKeypair.publicKey);
@@ -818,8 +1419,16 @@ describe("Stablecoin with Transfer Hook", () => {
               SSS_HOOK_PROGRAM_ID,
               extraAccountMetas,
               SSS_TOKEN_PROGRAM_ID,
-              sourceBlacklist,
-              destBlacklist
+              findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+              destBlacklist,
+              { caseReferenceHash: caseReferenceHash("CASE-HOOK-NONSEIZER") },
+              authorityBlacklist,
+              {
+                source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+                dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+              },
+              findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+              findReportingConfigPDA(stablecoinPDA)[0]
             )
           ),
           [authority]
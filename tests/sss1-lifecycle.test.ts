@@ -1,22 +1,29 @@
-import { Keypair, Transaction } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Keypair, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
 import { expect } from "chai";
 import {
   buildBurnTokensIx,
+  buildEmergencyPauseIx,
+  buildExtendEmergencyPauseIx,
   buildFreezeAccountIx,
   buildInitializeIx,
   buildMintTokensIx,
   buildPauseIx,
+  buildPauseScopedIx,
   buildThawAccountIx,
   buildUnpauseIx,
+  buildUnpauseScopedIx,
   buildUpdateMinterIx,
   buildUpdateRolesIx,
   createTokenAccount,
   findMinterPDA,
   findRolePDA,
   findStablecoinPDA,
+  PauseScope,
   sendAndConfirmAndLog,
   SSS_HOOK_PROGRAM_ID,
   SSS_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
 } from "./helpers";
 import { fundKeypairs, getProvider } from "./testSetup";
 
@@ -132,6 +139,122 @@ describe("SSS-1 Lifecycle", () => {
     await sendAndConfirmAndLog(connection, new Transaction().add(buildUnpauseIx(authority.publicKey, stablecoinPDA, authorityRole)), [authority], "Unpause");
   });
 
+  it("pausing the mint scope blocks minting but not burning", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [burnerRole] = findRolePDA(stablecoinPDA, burnerKeypair.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const burnerATA = getAssociatedTokenAddressSync(mintKeypair.publicKey, burnerKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildPauseScopedIx(authority.publicKey, stablecoinPDA, authorityRole, PauseScope.Mint)),
+      [authority],
+      "Pause mint scope"
+    );
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, burnerATA, BigInt(1))),
+        [minterKeypair]
+      );
+      expect.fail("Mint should fail while the mint scope is paused");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Paused|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildBurnTokensIx(burnerKeypair.publicKey, stablecoinPDA, burnerRole, mintKeypair.publicKey, burnerATA, BigInt(1))),
+      [burnerKeypair],
+      "Burn while mint paused"
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildUnpauseScopedIx(authority.publicKey, stablecoinPDA, authorityRole, PauseScope.Mint)),
+      [authority],
+      "Unpause mint scope"
+    );
+  });
+
+  it("emergency pause blocks minting until it expires", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterRole] = findRolePDA(stablecoinPDA, minterKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minterKeypair.publicKey);
+    const burnerATA = getAssociatedTokenAddressSync(mintKeypair.publicKey, burnerKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const mintIx = () =>
+      buildMintTokensIx(minterKeypair.publicKey, stablecoinPDA, minterRole, minterInfo, mintKeypair.publicKey, burnerATA, BigInt(1));
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildEmergencyPauseIx(authority.publicKey, stablecoinPDA, 2n, authorityRole)),
+      [authority],
+      "Emergency pause"
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildExtendEmergencyPauseIx(authority.publicKey, stablecoinPDA, 1n)),
+      [authority],
+      "Extend emergency pause"
+    );
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(mintIx()), [minterKeypair]);
+      expect.fail("Mint should fail during an emergency pause");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Paused|Simulation failed|custom program error|0x/i);
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+    await sendAndConfirmAndLog(connection, new Transaction().add(mintIx()), [minterKeypair], "Mint after expiry");
+  });
+
+  it("only the authority can re-trigger an emergency pause during the cooldown", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const pauser = Keypair.generate();
+    await fundKeypairs(provider, [pauser]);
+    const [pauserRole] = findRolePDA(stablecoinPDA, pauser.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, pauserRole, pauser.publicKey, { isMinter: false, isBurner: false, isPauser: true, isFreezer: false, isBlacklister: false, isSeizer: false })
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildEmergencyPauseIx(pauser.publicKey, stablecoinPDA, 1n, pauserRole)),
+      [pauser],
+      "Emergency pause by pauser"
+    );
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildEmergencyPauseIx(pauser.publicKey, stablecoinPDA, 1n, pauserRole)),
+        [pauser]
+      );
+      expect.fail("Pauser should not re-trigger during the cooldown");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/EmergencyPauseCooldown|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildEmergencyPauseIx(authority.publicKey, stablecoinPDA, 1n, authorityRole)),
+      [authority],
+      "Emergency pause by authority during cooldown"
+    );
+    // Unpausing lifts the pause and clears the cooldown
+    await sendAndConfirmAndLog(connection, new Transaction().add(buildUnpauseIx(authority.publicKey, stablecoinPDA, authorityRole)), [authority], "Unpause");
+  });
+
   it("freezes then thaws account", async () => {
     const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
     const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
@@ -149,4 +272,57 @@ describe("SSS-1 Lifecycle", () => {
       "Thaw"
     );
   });
+
+  it("freezes and thaws during the global and emergency pauses", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const otherATA = getAssociatedTokenAddressSync(mintKeypair.publicKey, otherKeypair.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const freezeAndThaw = (label: string) =>
+      sendAndConfirmAndLog(
+        connection,
+        new Transaction()
+          .add(buildFreezeAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, otherATA))
+          .add(buildThawAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, otherATA)),
+        [authority],
+        label
+      );
+
+    await sendAndConfirmAndLog(connection, new Transaction().add(buildPauseIx(authority.publicKey, stablecoinPDA, authorityRole)), [authority], "Pause");
+    await freezeAndThaw("Freeze and thaw while paused");
+    await sendAndConfirmAndLog(connection, new Transaction().add(buildUnpauseIx(authority.publicKey, stablecoinPDA, authorityRole)), [authority], "Unpause");
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildEmergencyPauseIx(authority.publicKey, stablecoinPDA, 60n, authorityRole)),
+      [authority],
+      "Emergency pause"
+    );
+    await freezeAndThaw("Freeze and thaw during an emergency pause");
+    // Unpausing also lifts the emergency pause
+    await sendAndConfirmAndLog(connection, new Transaction().add(buildUnpauseIx(authority.publicKey, stablecoinPDA, authorityRole)), [authority], "Unpause");
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildPauseScopedIx(authority.publicKey, stablecoinPDA, authorityRole, PauseScope.Freeze)),
+      [authority],
+      "Pause freeze scope"
+    );
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildFreezeAccountIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, otherATA)),
+        [authority]
+      );
+      expect.fail("Freeze should fail while the freeze scope is paused");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Paused|Simulation failed|custom program error|0x/i);
+    }
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildUnpauseScopedIx(authority.publicKey, stablecoinPDA, authorityRole, PauseScope.Freeze)),
+      [authority],
+      "Unpause freeze scope"
+    );
+  });
 });
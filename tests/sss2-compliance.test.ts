@@ -3,20 +3,26 @@ import { Keypair, sendAndConfirmTransaction, Transaction } from "@solana/web3.js
 import { expect } from "chai";
 import {
   buildAddToBlacklistIx,
-  buildInitializeExtraAccountMetaListIx,
+  buildBurnFromIx,
   buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
   buildMintTokensIx,
   buildRemoveFromBlacklistIx,
   buildSeizeIx,
   buildThawAccountIx,
   buildUpdateMinterIx,
   buildUpdateRolesIx,
+  buildUpdateTreasuryIx,
+  caseReferenceHash,
   createTokenAccount,
+  findAllowlistPDA,
   findBlacklistPDA,
   findExtraAccountMetasPDA,
   findMinterPDA,
+  findReportingConfigPDA,
   findRolePDA,
   findStablecoinPDA,
+  findVelocityAccounts,
   getTokenAccountAddress,
   sendAndConfirmAndLog,
   SSS_HOOK_PROGRAM_ID,
@@ -122,11 +128,11 @@ describe("SSS-2 Compliance", () => {
     );
 
     const [extraAccountMetasPDA] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
-    const initExtraIx = buildInitializeExtraAccountMetaListIx(
+    const initExtraIx = buildInitializeTransferHookAccountsIx(
       authority.publicKey,
-      extraAccountMetasPDA,
+      stablecoinPDA,
       mintKeypair.publicKey,
-      SSS_TOKEN_PROGRAM_ID
+      extraAccountMetasPDA
     );
     await sendAndConfirmAndLog(connection, new Transaction().add(initExtraIx), [authority], "Extra-account-metas");
   });
@@ -324,9 +330,15 @@ describe("SSS-2 Compliance", () => {
       [authority],
       "Thaw treasury"
     );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildUpdateTreasuryIx(authority.publicKey, stablecoinPDA, treasuryATA)),
+      [authority],
+      "Register treasury"
+    );
 
     const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
-    const [sourceBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
+    const [authorityBlacklist] = findBlacklistPDA(stablecoinPDA, stablecoinPDA);
     const [destBlacklist] = findBlacklistPDA(stablecoinPDA, authority.publicKey);
 
     const seizeIx = buildSeizeIx(
@@ -339,8 +351,16 @@ describe("SSS-2 Compliance", () => {
       SSS_HOOK_PROGRAM_ID,
       extraAccountMetas,
       SSS_TOKEN_PROGRAM_ID,
-      sourceBlacklist,
-      destBlacklist
+      findBlacklistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+      destBlacklist,
+      { caseReferenceHash: caseReferenceHash("CASE-COMPLIANCE-001") },
+      authorityBlacklist,
+      {
+        source: findAllowlistPDA(stablecoinPDA, badActorKeypair.publicKey)[0],
+        dest: findAllowlistPDA(stablecoinPDA, authority.publicKey)[0],
+      },
+      findVelocityAccounts(stablecoinPDA, mintKeypair.publicKey, badActorKeypair.publicKey),
+      findReportingConfigPDA(stablecoinPDA)[0]
     );
     await sendAndConfirmAndLog(connection, new Transaction().add(seizeIx), [seizerKeypair], "Seize");
 
@@ -350,4 +370,53 @@ describe("SSS-2 Compliance", () => {
     const treasuryBalance = await connection.getTokenAccountBalance(treasuryATA);
     expect(Number(treasuryBalance.value.amount)).to.be.greaterThan(0);
   });
+
+  it("redemption desk burns from a holder account via permanent delegate", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const redeemer = Keypair.generate();
+    await fundKeypairs(provider, [redeemer]);
+    const [redeemerRole] = findRolePDA(stablecoinPDA, redeemer.publicKey);
+    const userATA = getTokenAccountAddress(mintKeypair.publicKey, userKeypair.publicKey);
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildBurnFromIx(minterKeypair.publicKey, stablecoinPDA, findRolePDA(stablecoinPDA, minterKeypair.publicKey)[0], mintKeypair.publicKey, userATA, BigInt(100), "RED-0")
+        ),
+        [minterKeypair]
+      );
+      expect.fail("Non-burner should not burn_from");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/Unauthorized|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateRolesIx(authority.publicKey, stablecoinPDA, redeemerRole, redeemer.publicKey, {
+          isMinter: false,
+          isBurner: true,
+          isPauser: false,
+          isFreezer: false,
+          isBlacklister: false,
+          isSeizer: false,
+        })
+      ),
+      [authority]
+    );
+
+    const before = BigInt((await connection.getTokenAccountBalance(userATA)).value.amount);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildBurnFromIx(redeemer.publicKey, stablecoinPDA, redeemerRole, mintKeypair.publicKey, userATA, BigInt(100), "RED-1")
+      ),
+      [redeemer],
+      "Burn from holder"
+    );
+    const after = BigInt((await connection.getTokenAccountBalance(userATA)).value.amount);
+    expect(before - after).to.equal(BigInt(100));
+  });
 });
@@ -0,0 +1,129 @@
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import * as crypto from "crypto";
+import {
+  buildAddToBlacklistIx,
+  buildInitializeIx,
+  buildMigrateAccountIx,
+  buildMigrateStablecoinStateIx,
+  buildUpdateMinterIx,
+  findBlacklistPDA,
+  findMinterPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+} from "./helpers";
+import { getProvider } from "./testSetup";
+
+describe("Account Versioning", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let stablecoinPDA: PublicKey;
+  let authorityRole: PublicKey;
+  let minterInfo: PublicKey;
+  let blacklistEntry: PublicKey;
+
+  async function expectFailure(ix: TransactionInstruction, pattern: RegExp) {
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+      expect.fail("Migration should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(pattern);
+    }
+  }
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const sanctioned = Keypair.generate().publicKey;
+    [blacklistEntry] = findBlacklistPDA(stablecoinPDA, sanctioned);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Versioned USD",
+          symbol: "VRUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize SSS-2"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, 1_000n))
+        .add(buildAddToBlacklistIx(authority.publicKey, stablecoinPDA, authorityRole, blacklistEntry, sanctioned, "test")),
+      [authority]
+    );
+  });
+
+  it("new stablecoins are created with the versioned fixed-offset header", async () => {
+    const info = await connection.getAccountInfo(stablecoinPDA);
+    const discriminator = crypto.createHash("sha256").update("account:StablecoinStateV1").digest().subarray(0, 8);
+    expect(info!.data.subarray(0, 8).equals(discriminator)).to.equal(true);
+    // Header: version(8) paused(9) pause_flags(10) permanent_delegate(11) transfer_hook(12) allowlist(13) paused_until(14..22)
+    expect(info!.data[8]).to.equal(3);
+    expect(info!.data[9]).to.equal(0);
+    expect(info!.data[10]).to.equal(0);
+    expect(info!.data[11]).to.equal(1);
+    expect(info!.data[12]).to.equal(1);
+    expect(info!.data[13]).to.equal(0);
+    expect(info!.data.readBigInt64LE(14)).to.equal(0n);
+    // authority follows the header
+    expect(new PublicKey(info!.data.subarray(22, 54)).toBase58()).to.equal(authority.publicKey.toBase58());
+  });
+
+  it("role, minter and blacklist accounts carry a version byte and reserved space", async () => {
+    // RoleAccount: discriminator(8) + stablecoin(32) + holder(32) + roles(7) + bump(1) + version(1) + reserved(32)
+    const role = await connection.getAccountInfo(authorityRole);
+    expect(role!.data.length).to.equal(113);
+    expect(role!.data[80]).to.equal(1);
+    expect(role!.data.subarray(81).every((b) => b === 0)).to.equal(true);
+
+    // MinterInfo: discriminator(8) + 2 pubkeys(64) + 7 u64/i64(56) + bump(1) + version(1) + reserved(64)
+    const minter = await connection.getAccountInfo(minterInfo);
+    expect(minter!.data.length).to.equal(194);
+    expect(minter!.data[129]).to.equal(1);
+
+    // BlacklistEntry: the version follows the reason ("test"): 8 + 32 + 32 + 8 + 1 + (4 + 4) + 8 + 32 + bump(1)
+    const entry = await connection.getAccountInfo(blacklistEntry);
+    expect(entry!.data[130]).to.equal(1);
+  });
+
+  it("rejects migrating accounts that are already in the current layout", async () => {
+    await expectFailure(
+      buildMigrateStablecoinStateIx(authority.publicKey, mintKeypair.publicKey),
+      /AccountAlreadyMigrated|custom program error|0x/i
+    );
+    for (const account of [stablecoinPDA, authorityRole, minterInfo, blacklistEntry]) {
+      await expectFailure(
+        buildMigrateAccountIx(authority.publicKey, account),
+        /AccountAlreadyMigrated|custom program error|0x/i
+      );
+    }
+  });
+
+  it("rejects migrating accounts the program does not own", async () => {
+    await expectFailure(
+      buildMigrateStablecoinStateIx(authority.publicKey, Keypair.generate().publicKey),
+      /AccountOwnedByWrongProgram|custom program error|0x/i
+    );
+    await expectFailure(
+      buildMigrateAccountIx(authority.publicKey, mintKeypair.publicKey),
+      /AccountOwnedByWrongProgram|custom program error|0x/i
+    );
+  });
+});
@@ -0,0 +1,225 @@
+import { Keypair, sendAndConfirmTransaction, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildCancelChangeIx,
+  buildExecuteChangeIx,
+  buildInitializeIx,
+  buildQueueChangeIx,
+  buildUpdateMinterIx,
+  buildUpdateTimelockIx,
+  buildUpdateTreasuryIx,
+  createTokenAccount,
+  findMinterPDA,
+  findQueuedChangePDA,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+const DELAY_SECONDS = 3n;
+const MINTER_QUOTA_OFFSET = 8 + 32 + 32;
+
+describe("Timelock", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let guardian: Keypair;
+  let minter: Keypair;
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    guardian = Keypair.generate();
+    minter = Keypair.generate();
+    await fundKeypairs(provider, [guardian]);
+  });
+
+  async function readQuota(): Promise<bigint> {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minter.publicKey);
+    const info = await connection.getAccountInfo(minterInfo);
+    return info!.data.readBigUInt64LE(MINTER_QUOTA_OFFSET);
+  }
+
+  it("creates stablecoin, a minter, and enables the timelock", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minter.publicKey);
+
+    const ix = buildInitializeIx(
+      authority.publicKey,
+      stablecoinPDA,
+      mintKeypair.publicKey,
+      authorityRole,
+      SSS_HOOK_PROGRAM_ID,
+      { name: "Timelock USD", symbol: "TLUSD", uri: "", decimals: 6, enablePermanentDelegate: false, enableTransferHook: false, defaultAccountFrozen: false }
+    );
+    await sendAndConfirmAndLog(connection, new Transaction().add(ix), [authority, mintKeypair], "Initialize");
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, minter.publicKey, 1_000n)
+      ),
+      [authority]
+    );
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildUpdateTimelockIx(authority.publicKey, stablecoinPDA, DELAY_SECONDS, guardian.publicKey)
+      ),
+      [authority],
+      "Enable timelock"
+    );
+  });
+
+  it("rejects a direct quota increase but allows a decrease", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minter.publicKey);
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, minter.publicKey, 5_000n)
+        ),
+        [authority]
+      );
+      expect.fail("Quota increase should require the timelock");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/TimelockRequired|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, minter.publicKey, 500n)
+      ),
+      [authority]
+    );
+    expect(await readQuota()).to.equal(500n);
+  });
+
+  it("guardian cancels a queued change", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [queuedChange] = findQueuedChangePDA(stablecoinPDA, 0n);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildQueueChangeIx(authority.publicKey, stablecoinPDA, queuedChange, {
+          kind: "updateMinter",
+          minter: minter.publicKey,
+          quota: 1_000_000n,
+        })
+      ),
+      [authority]
+    );
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildCancelChangeIx(guardian.publicKey, stablecoinPDA, queuedChange, authority.publicKey)),
+      [guardian],
+      "Guardian cancel"
+    );
+    expect(await connection.getAccountInfo(queuedChange)).to.be.null;
+  });
+
+  it("executes a queued change only after the delay", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, minter.publicKey);
+    const [queuedChange] = findQueuedChangePDA(stablecoinPDA, 1n);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildQueueChangeIx(authority.publicKey, stablecoinPDA, queuedChange, {
+          kind: "updateMinter",
+          minter: minter.publicKey,
+          quota: 5_000n,
+        })
+      ),
+      [authority]
+    );
+
+    const executeIx = () =>
+      buildExecuteChangeIx(guardian.publicKey, stablecoinPDA, queuedChange, authority.publicKey, { minterInfo });
+
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(executeIx()), [guardian]);
+      expect.fail("Execution before eta should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/TimelockNotReady|Simulation failed|custom program error|0x/i);
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, Number(DELAY_SECONDS + 2n) * 1000));
+    await sendAndConfirmAndLog(connection, new Transaction().add(executeIx()), [guardian], "Execute queued change");
+    expect(await readQuota()).to.equal(5_000n);
+  });
+
+  it("registers a treasury directly but queues a redirect", async () => {
+    const [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [queuedChange] = findQueuedChangePDA(stablecoinPDA, 2n);
+    const treasury = await createTokenAccount(connection, authority, mintKeypair.publicKey, Keypair.generate().publicKey);
+    const redirect = await createTokenAccount(connection, authority, mintKeypair.publicKey, Keypair.generate().publicKey);
+    const stablecoinData = async () => (await connection.getAccountInfo(stablecoinPDA))!.data;
+
+    // The treasury must be a token account of this mint
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateTreasuryIx(authority.publicKey, stablecoinPDA, Keypair.generate().publicKey)),
+        [authority]
+      );
+      expect.fail("A treasury that is not a token account of the mint should be rejected");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/InvalidTreasuryAccount|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildUpdateTreasuryIx(authority.publicKey, stablecoinPDA, treasury)),
+      [authority],
+      "Register treasury"
+    );
+    expect((await stablecoinData()).includes(treasury.toBuffer())).to.be.true;
+
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(buildUpdateTreasuryIx(authority.publicKey, stablecoinPDA, redirect)),
+        [authority]
+      );
+      expect.fail("Redirecting the treasury should require the timelock");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/TimelockRequired|Simulation failed|custom program error|0x/i);
+    }
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildQueueChangeIx(authority.publicKey, stablecoinPDA, queuedChange, { kind: "updateTreasury", treasury: redirect })
+      ),
+      [authority]
+    );
+    await new Promise((resolve) => setTimeout(resolve, Number(DELAY_SECONDS + 2n) * 1000));
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildExecuteChangeIx(guardian.publicKey, stablecoinPDA, queuedChange, authority.publicKey, {
+          treasuryAccount: redirect,
+        })
+      ),
+      [guardian],
+      "Execute treasury redirect"
+    );
+    expect((await stablecoinData()).includes(redirect.toBuffer())).to.be.true;
+  });
+});
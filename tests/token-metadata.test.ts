@@ -0,0 +1,135 @@
+import { getMetadataPointerState, getMint, getTokenMetadata } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildInitializeIx,
+  buildUpdateMetadataIx,
+  findRolePDA,
+  findStablecoinPDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+describe("Token Metadata", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let stablecoinPDA: PublicKey;
+
+  async function expectFailure(ix: TransactionInstruction, signer: Keypair, pattern: RegExp) {
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(ix), [signer]);
+      expect.fail("update_metadata should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(pattern);
+    }
+  }
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    const [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Metadata USD",
+          symbol: "MUSD",
+          uri: "https://example.com/musd.json",
+          decimals: 6,
+          enablePermanentDelegate: false,
+          enableTransferHook: false,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize with metadata"
+    );
+  });
+
+  it("initializes the mint with a metadata pointer to itself and on-mint metadata", async () => {
+    const mint = await getMint(connection, mintKeypair.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const pointer = getMetadataPointerState(mint);
+    expect(pointer!.metadataAddress!.toBase58()).to.equal(mintKeypair.publicKey.toBase58());
+    expect(pointer!.authority!.toBase58()).to.equal(stablecoinPDA.toBase58());
+
+    const metadata = await getTokenMetadata(connection, mintKeypair.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(metadata!.name).to.equal("Metadata USD");
+    expect(metadata!.symbol).to.equal("MUSD");
+    expect(metadata!.uri).to.equal("https://example.com/musd.json");
+    expect(metadata!.updateAuthority!.toBase58()).to.equal(stablecoinPDA.toBase58());
+    expect(metadata!.additionalMetadata).to.be.empty;
+  });
+
+  it("update_metadata updates the mint and the stablecoin state", async () => {
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildUpdateMetadataIx(authority.publicKey, mintKeypair.publicKey, {
+          name: "Metadata Dollar",
+          fields: { issuer: "Example Trust Co.", attestation_url: "https://example.com/attestations" },
+        })
+      ),
+      [authority],
+      "Update metadata"
+    );
+
+    const metadata = await getTokenMetadata(connection, mintKeypair.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(metadata!.name).to.equal("Metadata Dollar");
+    expect(metadata!.symbol).to.equal("MUSD");
+    expect(metadata!.additionalMetadata).to.deep.equal([
+      ["issuer", "Example Trust Co."],
+      ["attestation_url", "https://example.com/attestations"],
+    ]);
+
+    // StablecoinState: header (22) + authority + mint, then name
+    const state = await connection.getAccountInfo(stablecoinPDA);
+    const nameLen = state!.data.readUInt32LE(86);
+    expect(state!.data.subarray(90, 90 + nameLen).toString()).to.equal("Metadata Dollar");
+
+    // The mint stays rent exempt after growing
+    const mintInfo = await connection.getAccountInfo(mintKeypair.publicKey);
+    const minimum = await connection.getMinimumBalanceForRentExemption(mintInfo!.data.length);
+    expect(mintInfo!.lamports >= minimum).to.equal(true);
+  });
+
+  it("an empty value removes an additional field", async () => {
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        buildUpdateMetadataIx(authority.publicKey, mintKeypair.publicKey, { fields: { attestation_url: "" } })
+      ),
+      [authority]
+    );
+    const metadata = await getTokenMetadata(connection, mintKeypair.publicKey, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(metadata!.additionalMetadata).to.deep.equal([["issuer", "Example Trust Co."]]);
+  });
+
+  it("rejects update_metadata from a non-authority", async () => {
+    const mallory = Keypair.generate();
+    await fundKeypairs(provider, [mallory]);
+    await expectFailure(
+      buildUpdateMetadataIx(mallory.publicKey, mintKeypair.publicKey, { name: "Fake" }),
+      mallory,
+      /Unauthorized|custom program error|0x/i
+    );
+  });
+
+  it("rejects oversized names and empty keys", async () => {
+    await expectFailure(
+      buildUpdateMetadataIx(authority.publicKey, mintKeypair.publicKey, { name: "x".repeat(33) }),
+      authority,
+      /NameTooLong|custom program error|0x/i
+    );
+    await expectFailure(
+      buildUpdateMetadataIx(authority.publicKey, mintKeypair.publicKey, { fields: { "": "value" } }),
+      authority,
+      /InvalidMetadataField|custom program error|0x/i
+    );
+  });
+});
@@ -0,0 +1,223 @@
+import { createTransferCheckedWithTransferHookInstruction, getAccount } from "@solana/spl-token";
+import { Keypair, PublicKey, sendAndConfirmTransaction, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  buildAddVelocityExemptionIx,
+  buildDirectExecuteIx,
+  buildInitializeIx,
+  buildInitializeTransferHookAccountsIx,
+  buildInitializeVelocityUsageIx,
+  buildMintTokensIx,
+  buildRemoveVelocityExemptionIx,
+  buildSetVelocityLimitsIx,
+  buildUpdateMinterIx,
+  createTokenAccount,
+  findExtraAccountMetasPDA,
+  findMinterPDA,
+  findRolePDA,
+  findStablecoinPDA,
+  findVelocityConfigPDA,
+  findVelocityExemptionPDA,
+  findVelocityUsagePDA,
+  sendAndConfirmAndLog,
+  SSS_HOOK_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "./helpers";
+import { fundKeypairs, getProvider } from "./testSetup";
+
+describe("Velocity Limits", () => {
+  const provider = getProvider();
+  const connection = provider.connection;
+  const authority = provider.wallet.payer as Keypair;
+
+  let mintKeypair: Keypair;
+  let stablecoinPDA: PublicKey;
+  let authorityRole: PublicKey;
+  let alice: Keypair;
+  let bob: Keypair;
+  let carol: Keypair;
+  let aliceATA: PublicKey;
+  let bobATA: PublicKey;
+  let carolATA: PublicKey;
+
+  function transferIx(from: Keypair, source: PublicKey, destination: PublicKey, amount: bigint): Promise<TransactionInstruction> {
+    return createTransferCheckedWithTransferHookInstruction(
+      connection,
+      source,
+      mintKeypair.publicKey,
+      destination,
+      from.publicKey,
+      amount,
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+  }
+
+  async function transfer(from: Keypair, source: PublicKey, destination: PublicKey, amount: bigint) {
+    const ix = await transferIx(from, source, destination, amount);
+    return sendAndConfirmTransaction(connection, new Transaction().add(ix), [from]);
+  }
+
+  async function expectTransferFailure(from: Keypair, source: PublicKey, destination: PublicKey, amount: bigint, pattern: RegExp) {
+    try {
+      await transfer(from, source, destination, amount);
+      expect.fail("Transfer should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(pattern);
+    }
+  }
+
+  before(async () => {
+    mintKeypair = Keypair.generate();
+    alice = Keypair.generate();
+    bob = Keypair.generate();
+    carol = Keypair.generate();
+    await fundKeypairs(provider, [alice, bob, carol]);
+
+    [stablecoinPDA] = findStablecoinPDA(mintKeypair.publicKey);
+    [authorityRole] = findRolePDA(stablecoinPDA, authority.publicKey);
+    const [minterInfo] = findMinterPDA(stablecoinPDA, authority.publicKey);
+    const [extraAccountMetas] = findExtraAccountMetasPDA(mintKeypair.publicKey, SSS_HOOK_PROGRAM_ID);
+
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildInitializeIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, authorityRole, SSS_HOOK_PROGRAM_ID, {
+          name: "Velocity USD",
+          symbol: "VUSD",
+          uri: "",
+          decimals: 6,
+          enablePermanentDelegate: true,
+          enableTransferHook: true,
+          defaultAccountFrozen: false,
+        })
+      ),
+      [authority, mintKeypair],
+      "Initialize SSS-2"
+    );
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildInitializeTransferHookAccountsIx(authority.publicKey, stablecoinPDA, mintKeypair.publicKey, extraAccountMetas))
+        .add(buildUpdateMinterIx(authority.publicKey, stablecoinPDA, minterInfo, authority.publicKey, 1_000_000n)),
+      [authority]
+    );
+
+    aliceATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, alice.publicKey);
+    bobATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, bob.publicKey);
+    carolATA = await createTokenAccount(connection, authority, mintKeypair.publicKey, carol.publicKey);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction()
+        .add(buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, aliceATA, 10_000n))
+        .add(buildMintTokensIx(authority.publicKey, stablecoinPDA, authorityRole, minterInfo, mintKeypair.publicKey, carolATA, 10_000n)),
+      [authority]
+    );
+  });
+
+  it("transfers are unrestricted until limits are configured", async () => {
+    await transfer(alice, aliceATA, bobATA, 5_000n);
+    await transfer(bob, bobATA, aliceATA, 5_000n);
+  });
+
+  it("rejects set_velocity_limits from a non-blacklister", async () => {
+    const [aliceRole] = findRolePDA(stablecoinPDA, alice.publicKey);
+    try {
+      await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+          buildSetVelocityLimitsIx(alice.publicKey, stablecoinPDA, aliceRole, mintKeypair.publicKey, 1n, 1n)
+        ),
+        [alice]
+      );
+      expect.fail("Non-blacklister should not set velocity limits");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/AccountNotInitialized|Unauthorized|custom program error|0x/i);
+    }
+  });
+
+  it("set_velocity_limits stores the per-transfer and rolling-window limits", async () => {
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(
+        buildSetVelocityLimitsIx(authority.publicKey, stablecoinPDA, authorityRole, mintKeypair.publicKey, 1_000n, 2_500n)
+      ),
+      [authority],
+      "Set velocity limits"
+    );
+
+    const info = await connection.getAccountInfo(findVelocityConfigPDA(stablecoinPDA)[0]);
+    expect(info).to.not.be.null;
+    // VelocityConfig: discriminator(8) + stablecoin(32) + max_per_transfer(8) + max_per_window(8)
+    expect(info!.data.readBigUInt64LE(40)).to.equal(1_000n);
+    expect(info!.data.readBigUInt64LE(48)).to.equal(2_500n);
+  });
+
+  it("treats a sender without a usage account as having no usage", async () => {
+    const [usagePDA] = findVelocityUsagePDA(mintKeypair.publicKey, alice.publicKey);
+    await transfer(alice, aliceATA, bobATA, 100n);
+    // Nothing is recorded until the usage account exists
+    expect(await connection.getAccountInfo(usagePDA)).to.be.null;
+  });
+
+  it("rejects a transfer above the per-transfer limit", async () => {
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildInitializeVelocityUsageIx(authority.publicKey, mintKeypair.publicKey, alice.publicKey)),
+      [authority]
+    );
+    expect(await connection.getAccountInfo(findVelocityUsagePDA(mintKeypair.publicKey, alice.publicKey)[0])).to.not.be.null;
+
+    await expectTransferFailure(alice, aliceATA, bobATA, 1_001n, /TransferLimitExceeded|custom program error|0x/i);
+  });
+
+  it("rejects the hook called directly, so usage cannot be written outside a transfer", async () => {
+    const [usagePDA] = findVelocityUsagePDA(mintKeypair.publicKey, alice.publicKey);
+    const before = (await connection.getAccountInfo(usagePDA))!.data;
+    const execute = buildDirectExecuteIx(await transferIx(alice, aliceATA, bobATA, 1_000n), 1_000n);
+    try {
+      await sendAndConfirmTransaction(connection, new Transaction().add(execute), [authority]);
+      expect.fail("Direct Execute should fail");
+    } catch (err: unknown) {
+      const msg = err instanceof Error ? err.message : String(err);
+      expect(msg).to.match(/NotTransferring|custom program error|0x/i);
+    }
+    expect((await connection.getAccountInfo(usagePDA))!.data.equals(before)).to.equal(true);
+  });
+
+  it("rejects transfers that exceed the rolling window", async () => {
+    await transfer(alice, aliceATA, bobATA, 1_000n);
+    await transfer(alice, aliceATA, bobATA, 1_000n);
+    await expectTransferFailure(alice, aliceATA, bobATA, 501n, /VelocityLimitExceeded|custom program error|0x/i);
+
+    // Exactly reaching the window limit is allowed
+    await transfer(alice, aliceATA, bobATA, 500n);
+    const bobAccount = await getAccount(connection, bobATA, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(bobAccount.amount).to.equal(2_600n);
+  });
+
+  it("exempt wallets bypass both limits", async () => {
+    await sendAndConfirmAndLog(
+      connection,
+      new Transaction().add(buildAddVelocityExemptionIx(authority.publicKey, stablecoinPDA, authorityRole, carol.publicKey)),
+      [authority],
+      "Velocity exemption"
+    );
+    expect(await connection.getAccountInfo(findVelocityExemptionPDA(stablecoinPDA, carol.publicKey)[0])).to.not.be.null;
+
+    // Carol has no usage account and moves more than the per-transfer limit
+    await transfer(carol, carolATA, bobATA, 5_000n);
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(buildRemoveVelocityExemptionIx(authority.publicKey, stablecoinPDA, authorityRole, carol.publicKey)),
+      [authority]
+    );
+    expect(await connection.getAccountInfo(findVelocityExemptionPDA(stablecoinPDA, carol.publicKey)[0])).to.be.null;
+    await expectTransferFailure(carol, carolATA, bobATA, 1_001n, /TransferLimitExceeded|custom program error|0x/i);
+  });
+});
@@ -272,6 +272,8 @@ impl FuzzTest {
             &[b"minter", stablecoin_pda.as_ref(), authority.as_ref()],
             &program_id,
         );
+        let (supply_cap_pda, _) =
+            Pubkey::find_program_address(&[b"supply_cap", stablecoin_pda.as_ref()], &program_id);
         let system_program = pubkey!("11111111111111111111111111111111");
 
         let params = InitializeParams::new(
@@ -357,7 +359,7 @@ impl FuzzTest {
                 minter_info_pda,
                 mint,
                 recipient,
-                program_id,
+                supply_cap_pda,
             ),
         )
         .instruction();
@@ -484,6 +486,8 @@ impl FuzzTest {
             &[b"minter", stablecoin_pda.as_ref(), authority.as_ref()],
             &program_id,
         );
+        let (supply_cap_pda, _) =
+            Pubkey::find_program_address(&[b"supply_cap", stablecoin_pda.as_ref()], &program_id);
         let system_program = pubkey!("11111111111111111111111111111111");
 
         let params = InitializeParams::new(
@@ -572,7 +576,7 @@ impl FuzzTest {
                 minter_info_pda,
                 mint,
                 recipient_ata,
-                program_id,
+                supply_cap_pda,
             ),
         )
         .instruction();
@@ -589,7 +593,7 @@ impl FuzzTest {
                 minter_info_pda,
                 mint,
                 recipient_ata,
-                program_id,
+                supply_cap_pda,
             ),
         )
         .instruction();
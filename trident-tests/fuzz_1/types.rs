@@ -1479,7 +1479,7 @@ pub mod solana_stablecoin_standard {
 
             self.accounts.stablecoin = AccountMeta::new(accounts.stablecoin, false);
 
-            self.accounts.new_authority = AccountMeta::new_readonly(accounts.new_authority, false);
+            self.accounts.new_authority = AccountMeta::new_readonly(accounts.new_authority, true);
 
             self
         }